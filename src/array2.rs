@@ -1,9 +1,16 @@
 use std::{
+    cmp::Ordering,
+    hash::{BuildHasher, Hash, Hasher},
     iter::FusedIterator,
-    ops::{Index, IndexMut, RangeBounds},
+    ops::{
+        Bound, ControlFlow, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeFull,
+        RangeInclusive, RangeTo, RangeToInclusive,
+    },
     slice::SliceIndex,
 };
 
+use crate::{Array2View, Coord, OrdVec, OrdVecKey, OrdVecKeyFst, Shape};
+
 /// Fixed-size two-dimensional array stored as a flat boxed slice in row-major order.
 ///
 /// Comparison with existing libraries:
@@ -17,16 +24,115 @@ use std::{
 /// * A yet more feature-rich solution is [`TooDee`](https://docs.rs/toodee/0.5.0/toodee/struct.TooDee.html).
 ///   It offers growable 2D arrays, whereas [`Array2`] does not change its size once constructed.
 ///
-#[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// # Indexing
+///
+/// `a2[i]` returns the row at index `i` as a slice, and `a2[range]` returns the rows in the range
+/// as one flat slice. Use [`row_range`](struct.Array2.html#method.row_range) to borrow them
+/// as an [`Array2View`] instead.
+///
+/// ```
+/// # use contiguous_collections::Array2;
+/// let mut a2 = Array2::new_from_rows([[1, 2], [3, 4], [5, 6]]);
+/// assert_eq!(a2[1], [3, 4]);
+/// assert_eq!(a2[1..], [3, 4, 5, 6]);
+/// a2[..=1].fill(0);
+/// assert_eq!(a2, Array2::new_from_rows([[0, 0], [0, 0], [5, 6]]));
+/// ```
+///
+/// # Arithmetic
+///
+/// Numeric arrays support `+`, `-`, `*` and `/` elementwise with another array of the same shape,
+/// or with a scalar, as well as the corresponding assignment operators and negation.
+/// The operations panic if the arrays have different shapes.
+///
+/// ```
+/// # use contiguous_collections::Array2;
+/// let a = Array2::new_from_rows([[1, 2], [3, 4]]);
+/// let b = Array2::new_from_rows([[10, 20], [30, 40]]);
+/// assert_eq!(&a + &b, Array2::new_from_rows([[11, 22], [33, 44]]));
+/// assert_eq!(-(b - &a) * 2, Array2::new_from_rows([[-18, -36], [-54, -72]]));
+///
+/// let mut c = a.clone();
+/// c *= &a;
+/// c += 1;
+/// assert_eq!(c, Array2::new_from_rows([[2, 5], [10, 17]]));
+/// ```
+///
+/// ```should_panic
+/// # use contiguous_collections::Array2;
+/// let _ = Array2::new(2, 2, 0) + &Array2::new(1, 4, 0);
+/// ```
+///
+/// # Order of dimensions
+///
+/// Constructors of [`Array2`] take the number of columns before the number of rows, like the width
+/// and height of an image, while [`Shape`] and [`Coord`] list rows before columns, like the indexes
+/// `array[row][col]`. Use the named fields, e.g. `Shape { rows: 2, cols: 3 }`, or
+/// [`Shape::cols_rows`] where the order matters.
+///
+/// ```
+/// # use contiguous_collections::{Array2, Shape};
+/// let a2 = Array2::new(3, 2, 0);
+/// assert_eq!(a2.shape(), Shape { rows: 2, cols: 3 });
+/// assert_eq!(a2.shape(), Shape::cols_rows(3, 2));
+/// ```
+///
+/// # Memory layout
+///
+/// The elements are stored in a single contiguous buffer in row-major order, without padding
+/// between rows: the element at row `r` and column `c` is at index `r * num_cols + c`.
+/// This layout is part of the stable API, so the buffer can be passed to FFI or uploaded
+/// to the GPU as is, using [`as_parts`](struct.Array2.html#method.as_parts),
+/// [`into_raw_parts`](struct.Array2.html#method.into_raw_parts) and
+/// [`from_raw_parts`](struct.Array2.html#method.from_raw_parts).
+///
+/// # Serialization
+///
+/// With the `serde` feature enabled, [`Array2`] is serialized as a sequence of rows,
+/// each of which is a sequence of elements, e.g. `[[1, 2, 3], [4, 5, 6]]` in JSON.
+/// Deserialization fails if the rows have different lengths.
+///
+/// A compact representation as a flat sequence of elements with the number of columns
+/// is available in [`serde_helpers::array2_flat`](serde_helpers/array2_flat/index.html).
+///
+/// # Comparison
+///
+/// Arrays are equal if they have the same shape and elements, and can be hashed,
+/// e.g. to use them as [`HashMap`](std::collections::HashMap) keys.
+/// Arrays are ordered by comparing their elements lexicographically in row-major order,
+/// then by the number of columns, so arrays with the same elements but different shapes
+/// are ordered from narrow to wide.
+///
+/// ```
+/// # use contiguous_collections::Array2;
+/// let mut grids = vec![
+///     Array2::new_from_rows([[1, 2], [3, 5]]),
+///     Array2::new_from_rows([[1, 2, 3, 4]]),
+///     Array2::new_from_rows([[1, 2], [3, 4]]),
+/// ];
+/// grids.sort();
+/// assert_eq!(grids[0], Array2::new_from_rows([[1, 2], [3, 4]]));
+/// assert_eq!(grids[1], Array2::new_from_rows([[1, 2, 3, 4]]));
+///
+/// let mut seen = std::collections::HashSet::new();
+/// assert!(seen.insert(grids[0].clone()));
+/// assert!(!seen.insert(grids[0].clone()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Array2<T> {
-    data: Box<[T]>,
-    num_cols: usize,
+    pub(crate) data: Box<[T]>,
+    pub(crate) num_cols: usize,
 }
 
 impl<T> Array2<T> {
     /// Creates an [`Array2`] of the given dimensions with all elements set to the given value.
     ///
+    /// Note that the number of columns comes first, unlike in [`Shape::new`] and [`Coord::new`].
+    /// If `num_cols` is zero, the array is empty and has no rows regardless of `num_rows`.
+    /// See [`try_new`](struct.Array2.html#method.try_new) for a version that reports this and other invalid dimensions.
+    ///
+    /// Panics if the number of elements overflows `usize`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -41,13 +147,217 @@ impl<T> Array2<T> {
         T: Clone,
     {
         Array2 {
-            data: vec![init_value; num_cols * num_rows].into_boxed_slice(),
+            data: vec![init_value; num_elements_or_panic(num_cols, num_rows)].into_boxed_slice(),
+            num_cols,
+        }
+    }
+
+    /// Creates an [`Array2`] of the given dimensions by calling function `f` with the row and column index
+    /// of each element, in row-major order. Compare to [`std::array::from_fn`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::from_fn(3, 2, |row, col| row * 10 + col);
+    /// assert_eq!(a2, Array2::new_from_rows([[0, 1, 2], [10, 11, 12]]));
+    /// ```
+    pub fn from_fn(num_cols: usize, num_rows: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let mut data = Vec::with_capacity(num_cols * num_rows);
+        for row in 0..num_rows {
+            data.extend((0..num_cols).map(|col| f(row, col)));
+        }
+        Array2 {
+            data: data.into_boxed_slice(),
+            num_cols,
+        }
+    }
+
+    /// Creates an [`Array2`] of the given [`Shape`] by calling function `f` with the coordinates
+    /// of each element, in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Shape};
+    /// let a2 = Array2::from_shape_fn(Shape::new(2, 3), |c| c.row * 10 + c.col);
+    /// assert_eq!(a2, Array2::new_from_rows([[0, 1, 2], [10, 11, 12]]));
+    /// ```
+    pub fn from_shape_fn(shape: Shape, mut f: impl FnMut(Coord) -> T) -> Self {
+        Array2::from_fn(shape.cols, shape.rows, |row, col| f(Coord::new(row, col)))
+    }
+
+    /// Creates an [`Array2`] of the given dimensions with all elements set to the given value,
+    /// or returns an error if the dimensions are invalid:
+    /// * [`Array2Error::ZeroColumns`] if `num_cols` is zero but `num_rows` is not,
+    ///   since such an array cannot keep track of its number of rows,
+    /// * [`Array2Error::SizeOverflow`] if the number of elements overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// assert_eq!(Array2::try_new(2, 1, 0), Ok(Array2::new_from_rows([[0, 0]])));
+    /// assert_eq!(Array2::try_new(0, 3, 0), Err(Array2Error::ZeroColumns { num_rows: 3 }));
+    /// assert!(matches!(Array2::try_new(usize::MAX, 2, 0), Err(Array2Error::SizeOverflow { .. })));
+    /// ```
+    pub fn try_new(num_cols: usize, num_rows: usize, init_value: T) -> Result<Self, Array2Error>
+    where
+        T: Clone,
+    {
+        if num_cols == 0 && num_rows > 0 {
+            return Err(Array2Error::ZeroColumns { num_rows });
+        }
+        let num_elements = num_cols
+            .checked_mul(num_rows)
+            .ok_or(Array2Error::SizeOverflow { num_cols, num_rows })?;
+        Ok(Array2 {
+            data: vec![init_value; num_elements].into_boxed_slice(),
+            num_cols,
+        })
+    }
+
+    /// Creates an [`Array2`] of the given dimensions from exactly `num_cols * num_rows` elements
+    /// in row-major order, or returns an error:
+    /// * [`Array2Error::ZeroColumns`] or [`Array2Error::SizeOverflow`] if the dimensions are invalid,
+    ///   see [`try_new`](struct.Array2.html#method.try_new),
+    /// * [`Array2Error::NotEnoughElements`] if the iterator ends early,
+    /// * [`Array2Error::TooManyElements`] if the iterator yields more elements.
+    ///   At most one element past the end is consumed to detect this.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let a2 = Array2::from_iter_with_shape(3, 2, (1..=6).map(|x| x * x));
+    /// assert_eq!(a2, Ok(Array2::new_from_rows([[1, 4, 9], [16, 25, 36]])));
+    ///
+    /// assert_eq!(
+    ///     Array2::from_iter_with_shape(3, 2, 1..=5),
+    ///     Err(Array2Error::NotEnoughElements { expected: 6, found: 5 })
+    /// );
+    /// assert_eq!(
+    ///     Array2::from_iter_with_shape(3, 2, 1..),
+    ///     Err(Array2Error::TooManyElements { expected: 6 })
+    /// );
+    /// ```
+    pub fn from_iter_with_shape(
+        num_cols: usize,
+        num_rows: usize,
+        iter: impl IntoIterator<Item = T>,
+    ) -> Result<Self, Array2Error> {
+        if num_cols == 0 && num_rows > 0 {
+            return Err(Array2Error::ZeroColumns { num_rows });
+        }
+        let expected = num_cols
+            .checked_mul(num_rows)
+            .ok_or(Array2Error::SizeOverflow { num_cols, num_rows })?;
+        let mut iter = iter.into_iter();
+        let data: Vec<T> = iter.by_ref().take(expected).collect();
+        if data.len() < expected {
+            return Err(Array2Error::NotEnoughElements {
+                expected,
+                found: data.len(),
+            });
+        }
+        if iter.next().is_some() {
+            return Err(Array2Error::TooManyElements { expected });
+        }
+        Ok(Array2 {
+            data: data.into_boxed_slice(),
             num_cols,
+        })
+    }
+
+    /// Creates an [`Array2`] of the given dimensions with all elements set to `default`,
+    /// except for the elements at the given `(row, column)` positions.
+    /// If a position is listed more than once, the last value is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let map = Array2::from_sparse(4, 2, '.', [((0, 1), '#'), ((1, 3), '@'), ((0, 1), 'X')]);
+    /// assert_eq!(map, Array2::new_from_rows([['.', 'X', '.', '.'], ['.', '.', '.', '@']]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a position is out of bounds.
+    /// See [`try_from_sparse`](struct.Array2.html#method.try_from_sparse) for a non-panicking version
+    /// that also rejects duplicate positions.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::Array2;
+    /// Array2::from_sparse(2, 2, 0, [((2, 0), 1)]);
+    /// ```
+    pub fn from_sparse(
+        num_cols: usize,
+        num_rows: usize,
+        default: T,
+        entries: impl IntoIterator<Item = ((usize, usize), T)>,
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let mut a2 = Array2::new(num_cols, num_rows, default);
+        for ((row, col), value) in entries {
+            let index = a2.index_of(row, col);
+            a2.data[index] = value;
+        }
+        a2
+    }
+
+    /// Creates an [`Array2`] of the given dimensions with all elements set to `default`,
+    /// except for the elements at the given `(row, column)` positions, or returns an error:
+    /// * [`Array2Error::PositionOutOfBounds`] if a position is out of bounds,
+    /// * [`Array2Error::DuplicatePosition`] if a position is listed more than once,
+    /// * the errors of [`try_new`](struct.Array2.html#method.try_new) if the dimensions are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// assert_eq!(
+    ///     Array2::try_from_sparse(2, 2, 0, [((0, 0), 1), ((1, 1), 2)]),
+    ///     Ok(Array2::new_from_rows([[1, 0], [0, 2]]))
+    /// );
+    /// assert_eq!(
+    ///     Array2::try_from_sparse(2, 2, 0, [((0, 0), 1), ((0, 0), 2)]),
+    ///     Err(Array2Error::DuplicatePosition { row: 0, col: 0 })
+    /// );
+    /// assert_eq!(
+    ///     Array2::try_from_sparse(2, 2, 0, [((0, 2), 1)]),
+    ///     Err(Array2Error::PositionOutOfBounds { row: 0, col: 2 })
+    /// );
+    /// ```
+    pub fn try_from_sparse(
+        num_cols: usize,
+        num_rows: usize,
+        default: T,
+        entries: impl IntoIterator<Item = ((usize, usize), T)>,
+    ) -> Result<Self, Array2Error>
+    where
+        T: Clone,
+    {
+        let mut a2 = Array2::try_new(num_cols, num_rows, default)?;
+        let mut assigned = vec![false; a2.data.len()];
+        for ((row, col), value) in entries {
+            if row >= num_rows || col >= num_cols {
+                return Err(Array2Error::PositionOutOfBounds { row, col });
+            }
+            let index = row * num_cols + col;
+            if std::mem::replace(&mut assigned[index], true) {
+                return Err(Array2Error::DuplicatePosition { row, col });
+            }
+            a2.data[index] = value;
         }
+        Ok(a2)
     }
 
     /// Creates an [`Array2`] from the given row iterators.
-    /// All row iterators must produce the same number of elements.
+    /// All row iterators must produce the same number of elements;
+    /// see [`new_from_rows_padded`](struct.Array2.html#method.new_from_rows_padded) for ragged input.
     ///
     /// # Examples
     ///
@@ -64,6 +374,9 @@ impl<T> Array2<T> {
     ///
     /// # Panics
     ///
+    /// Panics if the rows have different lengths.
+    /// See [`try_new_from_rows`](struct.Array2.html#method.try_new_from_rows) for a non-panicking version.
+    ///
     /// ```should_panic
     /// # use contiguous_collections::Array2;
     /// let a2 = Array2::new_from_rows([vec![1, 2], vec![1, 2, 3]]);
@@ -90,6 +403,377 @@ impl<T> Array2<T> {
         Array2 { data, num_cols }
     }
 
+    /// Creates an [`Array2`] from the given row iterators, or returns an error if the rows are invalid:
+    /// * [`Array2Error::RowLengthMismatch`] if a row has a different length than the first row,
+    /// * [`Array2Error::ZeroColumns`] if the rows are empty,
+    ///   since such an array cannot keep track of its number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// assert_eq!(Array2::try_new_from_rows([[1, 2], [3, 4]]), Ok(Array2::new_from_rows([[1, 2], [3, 4]])));
+    /// assert_eq!(
+    ///     Array2::try_new_from_rows([vec![1, 2], vec![3]]),
+    ///     Err(Array2Error::RowLengthMismatch { row: 1, expected: 2, found: 1 })
+    /// );
+    /// assert_eq!(
+    ///     Array2::<u32>::try_new_from_rows([[], []]),
+    ///     Err(Array2Error::ZeroColumns { num_rows: 2 })
+    /// );
+    /// ```
+    pub fn try_new_from_rows(
+        rows: impl IntoIterator<
+            Item = impl IntoIterator<Item = T, IntoIter = impl ExactSizeIterator<Item = T>>,
+        >,
+    ) -> Result<Self, Array2Error> {
+        let mut num_cols = None;
+        let mut num_rows = 0;
+        let mut data = Vec::new();
+        for row in rows {
+            let row = row.into_iter();
+            let expected = *num_cols.get_or_insert(row.len());
+            if row.len() != expected {
+                return Err(Array2Error::RowLengthMismatch {
+                    row: num_rows,
+                    expected,
+                    found: row.len(),
+                });
+            }
+            data.extend(row);
+            num_rows += 1;
+        }
+        let num_cols = num_cols.unwrap_or(0);
+        if num_cols == 0 && num_rows > 0 {
+            return Err(Array2Error::ZeroColumns { num_rows });
+        }
+        Ok(Array2 {
+            data: data.into_boxed_slice(),
+            num_cols,
+        })
+    }
+
+    /// Creates an [`Array2`] from an iterator over rows of possibly different lengths,
+    /// padding each row shorter than the longest one with copies of `pad_value`.
+    ///
+    /// If all rows are empty, the result is an empty array with no rows,
+    /// since an array with no columns cannot have rows (see [`Array2Error::ZeroColumns`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows_padded([vec![1, 2], vec![3], vec![4, 5, 6]], 0);
+    /// assert_eq!(a2, Array2::new_from_rows([[1, 2, 0], [3, 0, 0], [4, 5, 6]]));
+    ///
+    /// let empty = Array2::new_from_rows_padded([Vec::new(), Vec::new()], 0);
+    /// assert_eq!((empty.num_cols(), empty.num_rows()), (0, 0));
+    /// ```
+    pub fn new_from_rows_padded(
+        rows: impl IntoIterator<
+            Item = impl IntoIterator<Item = T, IntoIter = impl ExactSizeIterator<Item = T>>,
+        >,
+        pad_value: T,
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let rows: Vec<_> = rows.into_iter().map(IntoIterator::into_iter).collect();
+        let num_cols = rows.iter().map(ExactSizeIterator::len).max().unwrap_or(0);
+        let mut data = Vec::with_capacity(num_cols * rows.len());
+        for row in rows {
+            let num_padded = num_cols - row.len();
+            data.extend(row);
+            data.extend(std::iter::repeat_n(pad_value.clone(), num_padded));
+        }
+        Array2 {
+            data: data.into_boxed_slice(),
+            num_cols,
+        }
+    }
+
+    /// Creates an [`Array2`] by stacking the given arrays vertically, moving their elements
+    /// into a single allocation. All arrays must have the same number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let a = Array2::new_from_rows([[1, 2]]);
+    /// let b = Array2::new_from_rows([[3, 4], [5, 6]]);
+    /// assert_eq!(Array2::stack_rows([a, b]), Ok(Array2::new_from_rows([[1, 2], [3, 4], [5, 6]])));
+    ///
+    /// let c = Array2::new_from_rows([[1, 2]]);
+    /// let d = Array2::new_from_rows([[3, 4, 5]]);
+    /// assert_eq!(
+    ///     Array2::stack_rows([c, d]),
+    ///     Err(Array2Error::NumColsMismatch { index: 1, expected: 2, found: 3 })
+    /// );
+    /// ```
+    ///
+    /// Combined with [`stack_cols`](struct.Array2.html#method.stack_cols), this assembles a grid from tiles.
+    /// See [`vstack`](struct.Array2.html#method.vstack) for a version that copies from borrowed arrays.
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let tiles = [
+    ///     [Array2::new(2, 1, 'a'), Array2::new(1, 1, 'b')],
+    ///     [Array2::new(2, 2, 'c'), Array2::new(1, 2, 'd')],
+    /// ];
+    /// let grid = Array2::stack_rows(
+    ///     tiles.into_iter().map(|row| Array2::stack_cols(row).unwrap())
+    /// ).unwrap();
+    /// assert_eq!(grid.to_string(), "a a b\nc c d\nc c d");
+    /// ```
+    #[doc(alias = "concat_rows")]
+    pub fn stack_rows(arrays: impl IntoIterator<Item = Array2<T>>) -> Result<Self, Array2Error> {
+        let arrays: Vec<Array2<T>> = arrays.into_iter().collect();
+        let num_cols = arrays.first().map_or(0, |a| a.num_cols);
+        if let Some((index, a)) = arrays
+            .iter()
+            .enumerate()
+            .find(|(_, a)| a.num_cols != num_cols)
+        {
+            return Err(Array2Error::NumColsMismatch {
+                index,
+                expected: num_cols,
+                found: a.num_cols,
+            });
+        }
+        let mut data = Vec::with_capacity(arrays.iter().map(|a| a.data.len()).sum());
+        for a in arrays {
+            data.extend(a.data.into_vec());
+        }
+        Ok(Array2 {
+            data: data.into_boxed_slice(),
+            num_cols,
+        })
+    }
+
+    /// Creates an [`Array2`] by stacking the given arrays horizontally, moving their elements
+    /// into a single allocation. All arrays must have the same number of rows.
+    /// See [`hstack`](struct.Array2.html#method.hstack) for a version that copies from borrowed arrays.
+    ///
+    /// An array with no columns has no rows, so it can only be stacked with other empty arrays,
+    /// and stacking it with arrays that have rows returns [`Array2Error::NumRowsMismatch`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let a = Array2::new_from_rows([[1], [4]]);
+    /// let b = Array2::new_from_rows([[2, 3], [5, 6]]);
+    /// assert_eq!(Array2::stack_cols([a, b]), Ok(Array2::new_from_rows([[1, 2, 3], [4, 5, 6]])));
+    ///
+    /// let c = Array2::new_from_rows([[1], [4]]);
+    /// let d = Array2::new_from_rows([[2, 3]]);
+    /// assert_eq!(
+    ///     Array2::stack_cols([c, d]),
+    ///     Err(Array2Error::NumRowsMismatch { index: 1, expected: 2, found: 1 })
+    /// );
+    ///
+    /// let e = Array2::new_from_rows([[1], [4]]);
+    /// let no_cols: Array2<i32> = Array2::new_from_rows(Vec::<[i32; 0]>::new());
+    /// assert_eq!(
+    ///     Array2::stack_cols([e, no_cols]),
+    ///     Err(Array2Error::NumRowsMismatch { index: 1, expected: 2, found: 0 })
+    /// );
+    /// ```
+    #[doc(alias = "concat_cols")]
+    pub fn stack_cols(arrays: impl IntoIterator<Item = Array2<T>>) -> Result<Self, Array2Error> {
+        let arrays: Vec<Array2<T>> = arrays.into_iter().collect();
+        let num_rows = arrays.first().map_or(0, |a| a.num_rows());
+        if let Some((index, a)) = arrays
+            .iter()
+            .enumerate()
+            .find(|(_, a)| a.num_rows() != num_rows)
+        {
+            return Err(Array2Error::NumRowsMismatch {
+                index,
+                expected: num_rows,
+                found: a.num_rows(),
+            });
+        }
+        let num_cols = arrays.iter().map(|a| a.num_cols).sum();
+        let mut data = Vec::with_capacity(num_cols * num_rows);
+        let mut sources: Vec<_> = arrays
+            .into_iter()
+            .map(|a| (a.num_cols, a.data.into_vec().into_iter()))
+            .collect();
+        for _ in 0..num_rows {
+            for (num_cols, elements) in &mut sources {
+                data.extend(elements.take(*num_cols));
+            }
+        }
+        Ok(Array2 {
+            data: data.into_boxed_slice(),
+            num_cols,
+        })
+    }
+
+    /// Creates an [`Array2`] by stacking copies of the given arrays vertically.
+    /// All arrays must have the same number of columns.
+    ///
+    /// This is like [`stack_rows`](struct.Array2.html#method.stack_rows), but leaves the arrays intact,
+    /// e.g. when the same tile is used more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let border = Array2::new(3, 1, '#');
+    /// let body = Array2::new_from_rows([['#', '.', '#']]);
+    /// let room = Array2::vstack(&[&border, &body, &body, &border]).unwrap();
+    /// assert_eq!(room.to_string(), "# # #\n# . #\n# . #\n# # #");
+    ///
+    /// let narrow = Array2::new(2, 1, '#');
+    /// assert_eq!(
+    ///     Array2::vstack(&[&border, &narrow]),
+    ///     Err(Array2Error::NumColsMismatch { index: 1, expected: 3, found: 2 })
+    /// );
+    /// ```
+    pub fn vstack(arrays: &[&Array2<T>]) -> Result<Self, Array2Error>
+    where
+        T: Clone,
+    {
+        let num_cols = arrays.first().map_or(0, |a| a.num_cols);
+        if let Some((index, a)) = arrays
+            .iter()
+            .enumerate()
+            .find(|(_, a)| a.num_cols != num_cols)
+        {
+            return Err(Array2Error::NumColsMismatch {
+                index,
+                expected: num_cols,
+                found: a.num_cols,
+            });
+        }
+        let mut data = Vec::with_capacity(arrays.iter().map(|a| a.data.len()).sum());
+        for a in arrays {
+            data.extend_from_slice(&a.data);
+        }
+        Ok(Array2 {
+            data: data.into_boxed_slice(),
+            num_cols,
+        })
+    }
+
+    /// Creates an [`Array2`] by stacking copies of the given arrays horizontally.
+    /// All arrays must have the same number of rows.
+    ///
+    /// This is like [`stack_cols`](struct.Array2.html#method.stack_cols), but leaves the arrays intact,
+    /// e.g. when the same tile is used more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let wall = Array2::new(1, 2, '|');
+    /// let floor = Array2::new(2, 2, '.');
+    /// let corridor = Array2::hstack(&[&wall, &floor, &wall]).unwrap();
+    /// assert_eq!(corridor.to_string(), "| . . |\n| . . |");
+    ///
+    /// let short = Array2::new(2, 1, '.');
+    /// assert_eq!(
+    ///     Array2::hstack(&[&wall, &short]),
+    ///     Err(Array2Error::NumRowsMismatch { index: 1, expected: 2, found: 1 })
+    /// );
+    /// ```
+    pub fn hstack(arrays: &[&Array2<T>]) -> Result<Self, Array2Error>
+    where
+        T: Clone,
+    {
+        let num_rows = arrays.first().map_or(0, |a| a.num_rows());
+        if let Some((index, a)) = arrays
+            .iter()
+            .enumerate()
+            .find(|(_, a)| a.num_rows() != num_rows)
+        {
+            return Err(Array2Error::NumRowsMismatch {
+                index,
+                expected: num_rows,
+                found: a.num_rows(),
+            });
+        }
+        let num_cols = arrays.iter().map(|a| a.num_cols).sum();
+        let mut data = Vec::with_capacity(num_cols * num_rows);
+        for row_index in 0..num_rows {
+            for a in arrays {
+                data.extend_from_slice(
+                    &a.data[row_index * a.num_cols..(row_index + 1) * a.num_cols],
+                );
+            }
+        }
+        Ok(Array2 {
+            data: data.into_boxed_slice(),
+            num_cols,
+        })
+    }
+
+    /// Creates an [`Array2`] from a grid of characters, with each line of the string becoming a row
+    /// and each character converted to an element by function `f`.
+    ///
+    /// Lines are split as in [`str::lines`], so both `\n` and `\r\n` line endings are accepted
+    /// and a trailing line ending is ignored. All lines must have the same number of characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, ParseGridError};
+    /// let grid = Array2::from_str_grid("#.#\n.#.\n", |c| match c {
+    ///     '#' => Ok(true),
+    ///     '.' => Ok(false),
+    ///     _ => Err(c),
+    /// });
+    /// assert_eq!(grid, Ok(Array2::new_from_rows([[true, false, true], [false, true, false]])));
+    ///
+    /// let digits = Array2::from_str_grid("12\n3x", |c| c.to_digit(10).ok_or(c));
+    /// assert_eq!(digits, Err(ParseGridError::InvalidChar { line: 2, column: 2, error: 'x' }));
+    ///
+    /// let ragged = Array2::from_str_grid("12\n345", |c| c.to_digit(10).ok_or(c));
+    /// assert_eq!(ragged, Err(ParseGridError::RaggedLine { line: 2, expected: 2, found: 3 }));
+    ///
+    /// let blank_first = Array2::from_str_grid("\n12", |c| c.to_digit(10).ok_or(c));
+    /// assert_eq!(blank_first, Err(ParseGridError::RaggedLine { line: 2, expected: 0, found: 2 }));
+    /// assert_eq!(Array2::from_str_grid("\n\n", |c| c.to_digit(10).ok_or(c)), Err(ParseGridError::Empty));
+    /// ```
+    pub fn from_str_grid<E>(
+        s: &str,
+        mut f: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseGridError<E>> {
+        let num_cols = match s.lines().next() {
+            Some(first) => first.chars().count(),
+            None => return Err(ParseGridError::Empty),
+        };
+        if num_cols == 0 && s.lines().all(str::is_empty) {
+            return Err(ParseGridError::Empty);
+        }
+        let mut data = Vec::with_capacity(s.len());
+        for (line_index, line) in s.lines().enumerate() {
+            let mut found = 0;
+            for (col_index, c) in line.chars().enumerate() {
+                let elt = f(c).map_err(|error| ParseGridError::InvalidChar {
+                    line: line_index + 1,
+                    column: col_index + 1,
+                    error,
+                })?;
+                data.push(elt);
+                found += 1;
+            }
+            if found != num_cols {
+                return Err(ParseGridError::RaggedLine {
+                    line: line_index + 1,
+                    expected: num_cols,
+                    found,
+                });
+            }
+        }
+        Ok(Array2 {
+            data: data.into_boxed_slice(),
+            num_cols,
+        })
+    }
+
     /// Returns the number of columns (elements per row).
     ///
     /// # Examples
@@ -103,7 +787,7 @@ impl<T> Array2<T> {
         self.num_cols
     }
 
-    /// Returns the number of rows.
+    /// Returns the number of rows, which is zero if there are no columns.
     ///
     /// # Examples
     ///
@@ -111,9 +795,13 @@ impl<T> Array2<T> {
     /// # use contiguous_collections::Array2;
     /// let a2 = Array2::new_from_rows([[1, 2, 3, 4], [5, 6, 7, 8]]);
     /// assert_eq!(a2.num_rows(), 2);
+    /// assert_eq!(Array2::new(0, 2, 0).num_rows(), 0);
     /// ```
     pub const fn num_rows(&self) -> usize {
-        self.data.len() / self.num_cols
+        match self.data.len().checked_div(self.num_cols) {
+            Some(num_rows) => num_rows,
+            None => 0,
+        }
     }
 
     /// Returns the number of elements across all rows.
@@ -129,6 +817,19 @@ impl<T> Array2<T> {
         self.data.len()
     }
 
+    /// Returns the number of rows and columns as a [`Shape`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Shape};
+    /// let a2 = Array2::new_from_rows([[1, 2, 3, 4], [5, 6, 7, 8]]);
+    /// assert_eq!(a2.shape(), Shape { rows: 2, cols: 4 });
+    /// ```
+    pub const fn shape(&self) -> Shape {
+        Shape::new(self.num_rows(), self.num_cols)
+    }
+
     /// Returns a slice of the underlying buffer (row-major order).
     pub const fn elements(&self) -> &[T] {
         &self.data
@@ -139,42 +840,288 @@ impl<T> Array2<T> {
         &mut self.data
     }
 
-    /// Returns a slice of the underlying buffer with elements of the row
-    /// at the given index, or None if the row index is out of bounds.
+    /// Returns a slice of the underlying buffer together with the number of columns.
+    /// See [Memory layout](struct.Array2.html#memory-layout) for the guarantees on the buffer.
     ///
     /// # Examples
     ///
     /// ```
     /// # use contiguous_collections::Array2;
-    /// let a2: Array2<u32> = Array2::new_from_rows([[1, 2, 3, 4], [5, 6, 7, 8]]);
-    /// assert_eq!(a2.row(0), Some(&[1, 2, 3, 4][..]));
-    /// assert_eq!(a2.row(1), Some(&[5, 6, 7, 8][..]));
-    /// assert!(a2.row(2).is_none());
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let (elements, num_cols) = a2.as_parts();
+    /// assert_eq!(elements[num_cols + 2], 6);
     /// ```
-    pub fn row(&self, row_index: usize) -> Option<&[T]> {
-        let start = row_index * self.num_cols;
-        let end = (row_index + 1) * self.num_cols;
-        if end <= self.data.len() {
-            Some(&self.data[start..end])
-        } else {
-            None
-        }
-    }
-
-    /// Returns a mutable slice of the underlying buffer with elements
-    /// of the row at the given index, or None if the row index is out of bounds.
-    pub fn row_mut(&mut self, row_index: usize) -> Option<&mut [T]> {
-        let start = row_index * self.num_cols;
-        let end = (row_index + 1) * self.num_cols;
-        if end <= self.data.len() {
-            Some(&mut self.data[start..end])
-        } else {
-            None
-        }
+    pub const fn as_parts(&self) -> (&[T], usize) {
+        (&self.data, self.num_cols)
     }
 
-    /// Returns an iterator over rows. Each item is a slice of all elements
-    /// in the corresponding row.
+    /// Creates an [`Array2`] from a buffer of elements in row-major order and the number of columns,
+    /// without copying the elements. This is the inverse of [`into_raw_parts`](struct.Array2.html#method.into_raw_parts).
+    ///
+    /// Returns [`Array2Error::InvalidLength`] if the buffer cannot be split into rows of `num_cols` elements.
+    /// An empty buffer is accepted with any number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let a2 = Array2::from_raw_parts(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+    /// assert_eq!(a2, Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]));
+    ///
+    /// assert_eq!(
+    ///     Array2::from_raw_parts(vec![1, 2, 3, 4], 3),
+    ///     Err(Array2Error::InvalidLength { len: 4, row_len: 3 })
+    /// );
+    /// ```
+    pub fn from_raw_parts(data: impl Into<Box<[T]>>, num_cols: usize) -> Result<Self, Array2Error> {
+        let data = data.into();
+        let fits = match data.len().checked_rem(num_cols) {
+            Some(rem) => rem == 0,
+            None => data.is_empty(),
+        };
+        if !fits {
+            return Err(Array2Error::InvalidLength {
+                len: data.len(),
+                row_len: num_cols,
+            });
+        }
+        Ok(Array2 { data, num_cols })
+    }
+
+    /// Consumes the array and returns the underlying buffer in row-major order together with
+    /// the number of columns, without copying the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2], [3, 4]]);
+    /// let (data, num_cols) = a2.into_raw_parts();
+    /// assert_eq!((&data[..], num_cols), (&[1, 2, 3, 4][..], 2));
+    /// assert_eq!(Array2::from_raw_parts(data, num_cols), Ok(Array2::new_from_rows([[1, 2], [3, 4]])));
+    /// ```
+    pub fn into_raw_parts(self) -> (Box<[T]>, usize) {
+        (self.data, self.num_cols)
+    }
+
+    /// Returns a reference to the element at the given row and column,
+    /// or None if either index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a2.get(1, 2), Some(&6));
+    /// assert_eq!(a2.get(0, 3), None);
+    /// ```
+    pub fn get(&self, row_index: usize, col_index: usize) -> Option<&T> {
+        self.row(row_index)?.get(col_index)
+    }
+
+    /// Returns a mutable reference to the element at the given row and column,
+    /// or None if either index is out of bounds.
+    pub fn get_mut(&mut self, row_index: usize, col_index: usize) -> Option<&mut T> {
+        self.row_mut(row_index)?.get_mut(col_index)
+    }
+
+    /// Returns a reference to the element at the given `(x, y)` coordinates,
+    /// where `x` is the column index and `y` is the row index, or None if either is out of bounds.
+    ///
+    /// This is the same as [`get(y, x)`](struct.Array2.html#method.get), for code that follows
+    /// the graphics convention of listing the horizontal coordinate first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a2.get_xy(2, 1), Some(&6));
+    /// assert_eq!(a2.get_xy(2, 1), a2.get(1, 2));
+    /// assert_eq!(a2.get_xy(1, 2), None);
+    /// ```
+    pub fn get_xy(&self, x: usize, y: usize) -> Option<&T> {
+        self.get(y, x)
+    }
+
+    /// Returns a mutable reference to the element at the given `(x, y)` coordinates,
+    /// where `x` is the column index and `y` is the row index, or None if either is out of bounds.
+    pub fn get_xy_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.get_mut(y, x)
+    }
+
+    /// Returns the index in the underlying buffer of the element at the given row and column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a2.index_of(1, 2), 5);
+    /// assert_eq!(a2.elements()[a2.index_of(1, 0)], 4);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds, even if the resulting flat index would be in bounds.
+    /// See [`try_index_of`](struct.Array2.html#method.try_index_of) for a non-panicking version.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// a2.index_of(0, 3);
+    /// ```
+    pub fn index_of(&self, row_index: usize, col_index: usize) -> usize {
+        let num_rows = self.num_rows();
+        assert!(
+            row_index < num_rows && col_index < self.num_cols,
+            "Position ({}, {}) is out of bounds for {} rows and {} columns",
+            row_index,
+            col_index,
+            num_rows,
+            self.num_cols
+        );
+        row_index * self.num_cols + col_index
+    }
+
+    /// Returns the index in the underlying buffer of the element at the given row and column,
+    /// or [`Array2Error::PositionOutOfBounds`] if either index is out of bounds.
+    /// See [`index_of`](struct.Array2.html#method.index_of).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a2.try_index_of(1, 2), Ok(5));
+    /// assert_eq!(a2.try_index_of(0, 3), Err(Array2Error::PositionOutOfBounds { row: 0, col: 3 }));
+    /// ```
+    pub fn try_index_of(&self, row_index: usize, col_index: usize) -> Result<usize, Array2Error> {
+        if row_index < self.num_rows() && col_index < self.num_cols {
+            Ok(row_index * self.num_cols + col_index)
+        } else {
+            Err(Array2Error::PositionOutOfBounds {
+                row: row_index,
+                col: col_index,
+            })
+        }
+    }
+
+    /// Returns the `(row, col)` position of the element at the given index in the underlying buffer.
+    /// This is the inverse of [`index_of`](struct.Array2.html#method.index_of).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let max_index = (0..a2.num_elements()).max_by_key(|&i| a2.elements()[i]).unwrap();
+    /// assert_eq!(a2.coords_of(max_index), (1, 2));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    /// See [`try_coords_of`](struct.Array2.html#method.try_coords_of) for a non-panicking version.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// a2.coords_of(6);
+    /// ```
+    pub fn coords_of(&self, index: usize) -> (usize, usize) {
+        assert!(
+            index < self.data.len(),
+            "Index {} is out of bounds for {} elements",
+            index,
+            self.data.len()
+        );
+        (index / self.num_cols, index % self.num_cols)
+    }
+
+    /// Returns the `(row, col)` position of the element at the given index in the underlying buffer,
+    /// or [`Array2Error::IndexOutOfBounds`] if the index is out of bounds.
+    /// See [`coords_of`](struct.Array2.html#method.coords_of).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a2.try_coords_of(4), Ok((1, 1)));
+    /// assert_eq!(a2.try_coords_of(6), Err(Array2Error::IndexOutOfBounds { index: 6, len: 6 }));
+    /// ```
+    pub fn try_coords_of(&self, index: usize) -> Result<(usize, usize), Array2Error> {
+        if index < self.data.len() {
+            Ok((index / self.num_cols, index % self.num_cols))
+        } else {
+            Err(Array2Error::IndexOutOfBounds {
+                index,
+                len: self.data.len(),
+            })
+        }
+    }
+
+    /// Returns a slice of the underlying buffer with elements of the row
+    /// at the given index, or None if the row index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2: Array2<u32> = Array2::new_from_rows([[1, 2, 3, 4], [5, 6, 7, 8]]);
+    /// assert_eq!(a2.row(0), Some(&[1, 2, 3, 4][..]));
+    /// assert_eq!(a2.row(1), Some(&[5, 6, 7, 8][..]));
+    /// assert!(a2.row(2).is_none());
+    /// ```
+    pub fn row(&self, row_index: usize) -> Option<&[T]> {
+        let start = row_index * self.num_cols;
+        let end = (row_index + 1) * self.num_cols;
+        if end <= self.data.len() {
+            Some(&self.data[start..end])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable slice of the underlying buffer with elements
+    /// of the row at the given index, or None if the row index is out of bounds.
+    pub fn row_mut(&mut self, row_index: usize) -> Option<&mut [T]> {
+        let start = row_index * self.num_cols;
+        let end = (row_index + 1) * self.num_cols;
+        if end <= self.data.len() {
+            Some(&mut self.data[start..end])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a view of the given band of consecutive rows,
+    /// or None if the range is out of bounds or its start is greater than its end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2], [3, 4], [5, 6], [7, 8]]);
+    /// let band = a2.row_range(1..3).unwrap();
+    /// assert_eq!(band.rows().collect::<Vec<_>>(), vec![&[3, 4], &[5, 6]]);
+    /// assert!(a2.row_range(3..5).is_none());
+    /// ```
+    pub fn row_range(&self, row_indexes: impl RangeBounds<usize>) -> Option<Array2View<'_, T>> {
+        let num_rows = self.num_rows();
+        let rows = try_bounds_to_range(row_indexes, num_rows)?;
+        Some(Array2View::new(
+            self,
+            rows.start,
+            0,
+            self.num_cols,
+            rows.len(),
+        ))
+    }
+
+    /// Returns an iterator over rows. Each item is a slice of all elements
+    /// in the corresponding row.
     ///
     /// # Examples
     ///
@@ -191,7 +1138,46 @@ impl<T> Array2<T> {
     pub fn rows(
         &self,
     ) -> impl ExactSizeIterator<Item = &[T]> + DoubleEndedIterator + FusedIterator {
-        self.data.chunks(self.num_cols)
+        // An array without columns has no elements, so any non-zero chunk size yields no rows
+        self.data.chunks(self.num_cols.max(1))
+    }
+
+    /// Returns an iterator over rows. Each item is a mutable slice of all elements
+    /// in the corresponding row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[3, 1, 2], [6, 5, 4]]);
+    /// a2.rows_mut().for_each(|row| row.sort());
+    /// for (i, row) in a2.rows_mut().rev().enumerate() {
+    ///     row[0] = i;
+    /// }
+    /// assert_eq!(a2, Array2::new_from_rows([[1, 2, 3], [0, 5, 6]]));
+    /// ```
+    pub fn rows_mut(
+        &mut self,
+    ) -> impl ExactSizeIterator<Item = &mut [T]> + DoubleEndedIterator + FusedIterator {
+        // An array without columns has no elements, so any non-zero chunk size yields no rows
+        self.data.chunks_mut(self.num_cols.max(1))
+    }
+
+    /// Consumes the [`Array2`] and returns an iterator over rows, each moved into a [`Vec`]
+    /// without cloning the elements. See the [`IntoIterator`] implementation for an iterator over elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([["a".to_string(), "b".to_string()], ["c".to_string(), "d".to_string()]]);
+    /// let rows: Vec<Vec<String>> = a2.into_rows().collect();
+    /// assert_eq!(rows, vec![vec!["a", "b"], vec!["c", "d"]]);
+    /// ```
+    pub fn into_rows(self) -> impl ExactSizeIterator<Item = Vec<T>> + FusedIterator {
+        let (num_cols, num_rows) = (self.num_cols, self.num_rows());
+        let mut elements = self.data.into_vec().into_iter();
+        (0..num_rows).map(move |_| elements.by_ref().take(num_cols).collect())
     }
 
     /// Returns an iterator over elements of the column at the given index,
@@ -256,69 +1242,2380 @@ impl<T> Array2<T> {
         })
     }
 
-    /// Returns a new [`Array2`] created from a slice of rows and columns of this array.
+    /// Returns an iterator over elements of the main diagonal, from the top left corner
+    /// towards the bottom right corner.
+    ///
+    /// For non-square arrays, the diagonal ends at the last row or column, whichever comes first.
     ///
     /// # Examples
     ///
     /// ```
     /// # use contiguous_collections::Array2;
-    /// let a2: Array2<u32> = Array2::new_from_rows([[1, 2, 3], [5, 6, 7], [8, 9, 10]]);
-    /// assert_eq!(a2.subarray(.., ..), a2);
-    /// assert_eq!(a2.subarray(.., ..1).rows().collect::<Vec<_>>(), vec![&[1], &[5], &[8]]);
-    /// assert_eq!(a2.subarray(1.., 1..=2).rows().collect::<Vec<_>>(), vec![&[6, 7], &[9, 10]]);
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// assert_eq!(a2.diag().copied().collect::<Vec<_>>(), vec![1, 5, 9]);
     /// ```
-    pub fn subarray(
+    pub fn diag(&self) -> impl ExactSizeIterator<Item = &T> + DoubleEndedIterator + FusedIterator {
+        let len = self.num_cols.min(self.num_rows());
+        (0..len).map(move |i| &self.data[i * (self.num_cols + 1)])
+    }
+
+    /// Returns an iterator over elements of the anti-diagonal, from the top right corner
+    /// towards the bottom left corner.
+    ///
+    /// For non-square arrays, the diagonal ends at the last row or the first column, whichever comes first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// assert_eq!(a2.anti_diag().copied().collect::<Vec<_>>(), vec![3, 5, 7]);
+    /// ```
+    pub fn anti_diag(
         &self,
-        row_indexes: impl RangeBounds<usize>,
-        col_indexes: impl SliceIndex<[T], Output = [T]> + Clone,
-    ) -> Array2<T>
-    where
-        T: Clone,
-    {
-        let subarray_rows = self
-            .rows()
-            .enumerate()
-            .filter(|(i, _)| row_indexes.contains(i))
-            .map(|(_, r)| r.index(col_indexes.clone()).to_owned());
-        Array2::new_from_rows(subarray_rows)
+    ) -> impl ExactSizeIterator<Item = &T> + DoubleEndedIterator + FusedIterator {
+        let len = self.num_cols.min(self.num_rows());
+        (0..len).map(move |i| &self.data[(i + 1) * self.num_cols - 1 - i])
     }
 
-    /// Returns a new [`Array2`] of the same dimensions as this array,
-    /// with function `f` applied to each element in row-major order.
+    /// Returns an iterator over all diagonals parallel to the main diagonal,
+    /// starting with the one in the bottom left corner and ending with the one in the top right corner.
+    /// Each item is an iterator over elements of the corresponding diagonal, from top to bottom.
     ///
     /// # Examples
     ///
     /// ```
     /// # use contiguous_collections::Array2;
-    /// let a2_u32: Array2<u32> = Array2::new_from_rows([[1, 2], [3, 4], [5, 6]]);
-    /// let a2_x10f32 = a2_u32.map(|&elt| elt as f32 * 10.0);
-    /// assert_eq!(a2_x10f32.rows().collect::<Vec<_>>(), vec![&[10.0, 20.0], &[30.0, 40.0], &[50.0, 60.0]]);
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let diagonals: Vec<Vec<i32>> = a2.diagonals().map(|d| d.copied().collect()).collect();
+    /// assert_eq!(diagonals, vec![vec![4], vec![1, 5], vec![2, 6], vec![3]]);
     /// ```
-    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Array2<U> {
-        let data = self.data.iter().map(f).collect();
+    pub fn diagonals(
+        &self,
+    ) -> impl ExactSizeIterator<Item = impl ExactSizeIterator<Item = &T> + DoubleEndedIterator>
+           + DoubleEndedIterator
+           + FusedIterator {
         let num_cols = self.num_cols;
-        Array2 { data, num_cols }
+        let num_rows = self.num_rows();
+        let num_diagonals = match num_rows {
+            0 => 0,
+            _ => num_rows + num_cols - 1,
+        };
+        (0..num_diagonals).map(move |d| {
+            let (first_row, first_col) = if d < num_rows {
+                (num_rows - 1 - d, 0)
+            } else {
+                (0, d + 1 - num_rows)
+            };
+            let len = (num_rows - first_row).min(num_cols - first_col);
+            (0..len).map(move |i| &self.data[(first_row + i) * num_cols + first_col + i])
+        })
     }
-}
 
-impl<T> Index<usize> for Array2<T> {
-    type Output = [T];
+    /// Returns an iterator over all diagonals parallel to the anti-diagonal,
+    /// starting with the one in the top left corner and ending with the one in the bottom right corner.
+    /// Each item is an iterator over elements of the corresponding diagonal, from top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let diagonals: Vec<Vec<i32>> = a2.anti_diagonals().map(|d| d.copied().collect()).collect();
+    /// assert_eq!(diagonals, vec![vec![1], vec![2, 4], vec![3, 5], vec![6]]);
+    /// ```
+    pub fn anti_diagonals(
+        &self,
+    ) -> impl ExactSizeIterator<Item = impl ExactSizeIterator<Item = &T> + DoubleEndedIterator>
+           + DoubleEndedIterator
+           + FusedIterator {
+        let num_cols = self.num_cols;
+        let num_rows = self.num_rows();
+        let num_diagonals = match num_rows {
+            0 => 0,
+            _ => num_rows + num_cols - 1,
+        };
+        (0..num_diagonals).map(move |d| {
+            let first_row = d.saturating_sub(num_cols - 1);
+            let first_col = d - first_row;
+            let len = (num_rows - first_row).min(first_col + 1);
+            (0..len).map(move |i| &self.data[(first_row + i) * num_cols + first_col - i])
+        })
+    }
 
-    /// Returns a slice of the underlying buffer with elements of the row at the given index.
+    /// Returns an iterator over all overlapping rectangular windows of the given size,
+    /// in row-major order of their top left corners. If the array is smaller than
+    /// the window in either dimension, the iterator yields nothing.
     ///
-    /// Panics if the index is out of bounds. See [`row`](struct.Array2.html#method.row) for a non-panicking version.
-    fn index(&self, row_index: usize) -> &Self::Output {
-        self.row(row_index)
-            .unwrap_or_else(|| panic!("Row index {} is out of bounds", row_index))
+    /// Panics if `num_cols` or `num_rows` is zero.
+    /// See [`try_windows2`](struct.Array2.html#method.try_windows2) for a non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let sums: Vec<i32> = a2
+    ///     .windows2(2, 2)
+    ///     .map(|w| w.rows().flatten().sum())
+    ///     .collect();
+    /// assert_eq!(sums, vec![12, 16, 24, 28]);
+    /// ```
+    pub fn windows2(
+        &self,
+        num_cols: usize,
+        num_rows: usize,
+    ) -> impl ExactSizeIterator<Item = Array2View<'_, T>> + DoubleEndedIterator + FusedIterator
+    {
+        assert!(
+            num_cols != 0 && num_rows != 0,
+            "Window dimensions must be non-zero"
+        );
+        let count_x = (self.num_cols + 1).saturating_sub(num_cols);
+        let count_y = (self.num_rows() + 1).saturating_sub(num_rows);
+        (0..count_x * count_y)
+            .map(move |i| Array2View::new(self, i / count_x, i % count_x, num_cols, num_rows))
     }
-}
 
-impl<T> IndexMut<usize> for Array2<T> {
-    /// Returns a mutable slice of the underlying buffer with elements of the row at the given index.
+    /// Returns an iterator over all overlapping rectangular windows of the given size like
+    /// [`windows2`](struct.Array2.html#method.windows2), or [`Array2Error::EmptyWindow`]
+    /// if `num_cols` or `num_rows` is zero.
     ///
-    /// Panics if the index is out of bounds. See [`row_mut`](struct.Array2.html#method.row) for a non-panicking version.
-    fn index_mut(&mut self, row_index: usize) -> &mut Self::Output {
-        self.row_mut(row_index)
-            .unwrap_or_else(|| panic!("Row index {} is out of bounds", row_index))
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a2.try_windows2(2, 2).map(|w| w.len()), Ok(2));
+    /// assert_eq!(
+    ///     a2.try_windows2(0, 2).map(|w| w.len()),
+    ///     Err(Array2Error::EmptyWindow { num_cols: 0, num_rows: 2 })
+    /// );
+    /// ```
+    pub fn try_windows2(
+        &self,
+        num_cols: usize,
+        num_rows: usize,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Array2View<'_, T>> + DoubleEndedIterator + FusedIterator,
+        Array2Error,
+    > {
+        check_window(num_cols, num_rows)?;
+        Ok(self.windows2(num_cols, num_rows))
+    }
+
+    /// Returns an iterator over non-overlapping rectangular tiles of the given size,
+    /// in row-major order. If the array dimensions are not multiples of the tile dimensions,
+    /// the tiles at the right and bottom edges are smaller.
+    /// See [`chunks2_exact`](struct.Array2.html#method.chunks2_exact) for a version that skips them.
+    ///
+    /// Panics if `num_cols` or `num_rows` is zero.
+    /// See [`try_chunks2`](struct.Array2.html#method.try_chunks2) for a non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let tiles: Vec<Array2<i32>> = a2.chunks2(2, 2).map(|t| t.to_array2()).collect();
+    /// assert_eq!(tiles, vec![
+    ///     Array2::new_from_rows([[1, 2], [4, 5]]),
+    ///     Array2::new_from_rows([[3], [6]]),
+    ///     Array2::new_from_rows([[7, 8]]),
+    ///     Array2::new_from_rows([[9]]),
+    /// ]);
+    /// ```
+    pub fn chunks2(
+        &self,
+        num_cols: usize,
+        num_rows: usize,
+    ) -> impl ExactSizeIterator<Item = Array2View<'_, T>> + DoubleEndedIterator + FusedIterator
+    {
+        assert!(
+            num_cols != 0 && num_rows != 0,
+            "Tile dimensions must be non-zero"
+        );
+        let (array_num_cols, array_num_rows) = (self.num_cols, self.num_rows());
+        let count_x = array_num_cols.div_ceil(num_cols);
+        let count_y = array_num_rows.div_ceil(num_rows);
+        (0..count_x * count_y).map(move |i| {
+            let first_row = (i / count_x) * num_rows;
+            let first_col = (i % count_x) * num_cols;
+            Array2View::new(
+                self,
+                first_row,
+                first_col,
+                num_cols.min(array_num_cols - first_col),
+                num_rows.min(array_num_rows - first_row),
+            )
+        })
+    }
+
+    /// Returns an iterator over non-overlapping rectangular tiles of the given size like
+    /// [`chunks2`](struct.Array2.html#method.chunks2), or [`Array2Error::EmptyWindow`]
+    /// if `num_cols` or `num_rows` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a2.try_chunks2(2, 2).map(|t| t.len()), Ok(2));
+    /// assert_eq!(
+    ///     a2.try_chunks2(2, 0).map(|t| t.len()),
+    ///     Err(Array2Error::EmptyWindow { num_cols: 2, num_rows: 0 })
+    /// );
+    /// ```
+    pub fn try_chunks2(
+        &self,
+        num_cols: usize,
+        num_rows: usize,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Array2View<'_, T>> + DoubleEndedIterator + FusedIterator,
+        Array2Error,
+    > {
+        check_window(num_cols, num_rows)?;
+        Ok(self.chunks2(num_cols, num_rows))
+    }
+
+    /// Returns an [`Array2`] with a hash of each non-overlapping rectangular tile of the given size,
+    /// laid out like the tiles returned by [`chunks2`](struct.Array2.html#method.chunks2).
+    ///
+    /// Comparing the hashes of two arrays tile by tile is a cheap way to find the regions that differ,
+    /// e.g. between consecutive frames. Hashes are only comparable if computed with the same `build_hasher`,
+    /// so use a deterministic one such as [`BuildHasherDefault`](std::hash::BuildHasherDefault)
+    /// or reuse the same [`RandomState`](std::collections::hash_map::RandomState) instance.
+    ///
+    /// The hashes are computed in a single pass over the underlying buffer.
+    ///
+    /// Panics if `num_cols` or `num_rows` is zero.
+    /// See [`try_region_hashes`](struct.Array2.html#method.try_region_hashes) for a non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// # use std::{collections::hash_map::DefaultHasher, hash::BuildHasherDefault};
+    /// let hasher = BuildHasherDefault::<DefaultHasher>::default();
+    /// let prev = Array2::new(5, 4, 0u8);
+    /// let mut next = prev.clone();
+    /// next[3][4] = 1;
+    ///
+    /// let (prev_hashes, next_hashes) = (prev.region_hashes(2, 2, &hasher), next.region_hashes(2, 2, &hasher));
+    /// assert_eq!((next_hashes.num_cols(), next_hashes.num_rows()), (3, 2));
+    /// let changed: Vec<(usize, usize)> = (0..2)
+    ///     .flat_map(|r| (0..3).map(move |c| (r, c)))
+    ///     .filter(|&(r, c)| prev_hashes[r][c] != next_hashes[r][c])
+    ///     .collect();
+    /// assert_eq!(changed, vec![(1, 2)]);
+    /// ```
+    pub fn region_hashes(
+        &self,
+        num_cols: usize,
+        num_rows: usize,
+        build_hasher: &impl BuildHasher,
+    ) -> Array2<u64>
+    where
+        T: Hash,
+    {
+        assert!(
+            num_cols != 0 && num_rows != 0,
+            "Tile dimensions must be non-zero"
+        );
+        let count_x = self.num_cols.div_ceil(num_cols);
+        let mut hashes = Vec::with_capacity(count_x * self.num_rows().div_ceil(num_rows));
+        let mut hashers = Vec::with_capacity(count_x);
+        for band in self.data.chunks(num_rows * self.num_cols.max(1)) {
+            hashers.extend((0..count_x).map(|_| build_hasher.build_hasher()));
+            for row in band.chunks_exact(self.num_cols) {
+                for (tile_row, hasher) in row.chunks(num_cols).zip(&mut hashers) {
+                    T::hash_slice(tile_row, hasher);
+                }
+            }
+            hashes.extend(hashers.drain(..).map(|hasher| hasher.finish()));
+        }
+        Array2 {
+            data: hashes.into_boxed_slice(),
+            num_cols: count_x,
+        }
+    }
+
+    /// Returns an [`Array2`] with a hash of each non-overlapping rectangular tile of the given size
+    /// like [`region_hashes`](struct.Array2.html#method.region_hashes), or [`Array2Error::EmptyWindow`]
+    /// if `num_cols` or `num_rows` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// # use std::collections::hash_map::RandomState;
+    /// let hasher = RandomState::new();
+    /// let a2 = Array2::new(5, 4, 0u8);
+    /// assert_eq!(a2.try_region_hashes(2, 2, &hasher).map(|h| h.shape()), Ok(a2.region_hashes(2, 2, &hasher).shape()));
+    /// assert_eq!(
+    ///     a2.try_region_hashes(0, 0, &hasher),
+    ///     Err(Array2Error::EmptyWindow { num_cols: 0, num_rows: 0 })
+    /// );
+    /// ```
+    pub fn try_region_hashes(
+        &self,
+        num_cols: usize,
+        num_rows: usize,
+        build_hasher: &impl BuildHasher,
+    ) -> Result<Array2<u64>, Array2Error>
+    where
+        T: Hash,
+    {
+        check_window(num_cols, num_rows)?;
+        Ok(self.region_hashes(num_cols, num_rows, build_hasher))
+    }
+
+    /// Returns an iterator over non-overlapping rectangular tiles of the given size,
+    /// in row-major order. If the array dimensions are not multiples of the tile dimensions,
+    /// the remaining elements at the right and bottom edges are skipped.
+    ///
+    /// Panics if `num_cols` or `num_rows` is zero.
+    /// See [`try_chunks2_exact`](struct.Array2.html#method.try_chunks2_exact) for a non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let tiles: Vec<Array2<i32>> = a2.chunks2_exact(2, 2).map(|t| t.to_array2()).collect();
+    /// assert_eq!(tiles, vec![Array2::new_from_rows([[1, 2], [4, 5]])]);
+    /// ```
+    pub fn chunks2_exact(
+        &self,
+        num_cols: usize,
+        num_rows: usize,
+    ) -> impl ExactSizeIterator<Item = Array2View<'_, T>> + DoubleEndedIterator + FusedIterator
+    {
+        assert!(
+            num_cols != 0 && num_rows != 0,
+            "Tile dimensions must be non-zero"
+        );
+        let count_x = self.num_cols / num_cols;
+        let count_y = self.num_rows() / num_rows;
+        (0..count_x * count_y).map(move |i| {
+            let first_row = (i / count_x) * num_rows;
+            let first_col = (i % count_x) * num_cols;
+            Array2View::new(self, first_row, first_col, num_cols, num_rows)
+        })
+    }
+
+    /// Returns an iterator over non-overlapping rectangular tiles of the given size like
+    /// [`chunks2_exact`](struct.Array2.html#method.chunks2_exact), or [`Array2Error::EmptyWindow`]
+    /// if `num_cols` or `num_rows` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a2.try_chunks2_exact(2, 2).map(|t| t.len()), Ok(1));
+    /// assert_eq!(
+    ///     a2.try_chunks2_exact(0, 1).map(|t| t.len()),
+    ///     Err(Array2Error::EmptyWindow { num_cols: 0, num_rows: 1 })
+    /// );
+    /// ```
+    pub fn try_chunks2_exact(
+        &self,
+        num_cols: usize,
+        num_rows: usize,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Array2View<'_, T>> + DoubleEndedIterator + FusedIterator,
+        Array2Error,
+    > {
+        check_window(num_cols, num_rows)?;
+        Ok(self.chunks2_exact(num_cols, num_rows))
+    }
+
+    /// Returns a new [`Array2`] created from a slice of rows and columns of this array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2: Array2<u32> = Array2::new_from_rows([[1, 2, 3], [5, 6, 7], [8, 9, 10]]);
+    /// assert_eq!(a2.subarray(.., ..), a2);
+    /// assert_eq!(a2.subarray(.., ..1).rows().collect::<Vec<_>>(), vec![&[1], &[5], &[8]]);
+    /// assert_eq!(a2.subarray(1.., 1..=2).rows().collect::<Vec<_>>(), vec![&[6, 7], &[9, 10]]);
+    /// ```
+    pub fn subarray(
+        &self,
+        row_indexes: impl RangeBounds<usize>,
+        col_indexes: impl SliceIndex<[T], Output = [T]> + Clone,
+    ) -> Array2<T>
+    where
+        T: Clone,
+    {
+        let subarray_rows = self
+            .rows()
+            .enumerate()
+            .filter(|(i, _)| row_indexes.contains(i))
+            .map(|(_, r)| r.index(col_indexes.clone()).to_owned());
+        Array2::new_from_rows(subarray_rows)
+    }
+
+    /// Returns a new [`Array2`] with only the given ranges of rows and columns of this array.
+    /// This is the same as [`subarray`](struct.Array2.html#method.subarray), named to pair with
+    /// [`padded`](struct.Array2.html#method.padded) and [`resized`](struct.Array2.html#method.resized).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let image: Array2<u8> = Array2::new_from_rows([[0, 0, 0, 0], [0, 7, 8, 0], [0, 9, 6, 0]]);
+    /// assert_eq!(image.crop(1..3, 1..3), Array2::new_from_rows([[7, 8], [9, 6]]));
+    /// assert_eq!(image.crop(1..3, 1..3).padded(1, 0, 1, 1, 0), image);
+    /// ```
+    pub fn crop(
+        &self,
+        rows: impl RangeBounds<usize>,
+        cols: impl SliceIndex<[T], Output = [T]> + Clone,
+    ) -> Array2<T>
+    where
+        T: Clone,
+    {
+        self.subarray(rows, cols)
+    }
+
+    /// Returns a new [`Array2`] with this array surrounded by the given number
+    /// of rows and columns set to `fill`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2: Array2<u32> = Array2::new_from_rows([[1, 2], [3, 4]]);
+    /// let padded = a2.padded(1, 0, 2, 1, 0);
+    /// assert_eq!(padded, Array2::new_from_rows([[0, 0, 0, 0, 0], [0, 0, 1, 2, 0], [0, 0, 3, 4, 0]]));
+    /// ```
+    pub fn padded(&self, top: usize, bottom: usize, left: usize, right: usize, fill: T) -> Array2<T>
+    where
+        T: Clone,
+    {
+        let num_cols = left + self.num_cols + right;
+        let num_rows = top + self.num_rows() + bottom;
+        let mut data = Vec::with_capacity(num_cols * num_rows);
+        data.resize(top * num_cols, fill.clone());
+        for row in self.rows() {
+            data.resize(data.len() + left, fill.clone());
+            data.extend_from_slice(row);
+            data.resize(data.len() + right, fill.clone());
+        }
+        data.resize(num_cols * num_rows, fill);
+        Array2 {
+            data: data.into_boxed_slice(),
+            num_cols,
+        }
+    }
+
+    /// Returns a new [`Array2`] of the given dimensions, with elements copied from
+    /// the top left corner of this array and the remaining elements set to `fill`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2: Array2<u32> = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a2.resized(2, 3, 0), Array2::new_from_rows([[1, 2], [4, 5], [0, 0]]));
+    /// assert_eq!(a2.resized(4, 1, 0), Array2::new_from_rows([[1, 2, 3, 0]]));
+    /// ```
+    pub fn resized(&self, num_cols: usize, num_rows: usize, fill: T) -> Array2<T>
+    where
+        T: Clone,
+    {
+        let mut data = Vec::with_capacity(num_cols * num_rows);
+        for row in self.rows().take(num_rows) {
+            let num_copied = num_cols.min(row.len());
+            data.extend_from_slice(&row[..num_copied]);
+            data.resize(data.len() + num_cols - num_copied, fill.clone());
+        }
+        data.resize(num_cols * num_rows, fill);
+        Array2 {
+            data: data.into_boxed_slice(),
+            num_cols,
+        }
+    }
+
+    /// Sets all elements to the given value, keeping the dimensions of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2: Array2<u32> = Array2::new_from_rows([[1, 2], [3, 4]]);
+    /// a2.fill(0);
+    /// assert_eq!(a2, Array2::new(2, 2, 0));
+    /// ```
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.data.fill(value);
+    }
+
+    /// Sets all elements to values returned by calling function `f` repeatedly, in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2: Array2<u32> = Array2::new(3, 2, 0);
+    /// let mut next = 0;
+    /// a2.fill_with(|| { next += 1; next });
+    /// assert_eq!(a2, Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]));
+    /// ```
+    pub fn fill_with(&mut self, f: impl FnMut() -> T) {
+        self.data.fill_with(f);
+    }
+
+    /// Sets elements in the given rows and columns to the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2: Array2<u32> = Array2::new(4, 3, 0);
+    /// a2.fill_region(1.., 1..=2, 7);
+    /// assert_eq!(a2, Array2::new_from_rows([[0, 0, 0, 0], [0, 7, 7, 0], [0, 7, 7, 0]]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is out of bounds or its start is greater than its end.
+    /// See [`try_fill_region`](struct.Array2.html#method.try_fill_region) for a non-panicking version.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::Array2;
+    /// let mut a2: Array2<u32> = Array2::new(2, 2, 0);
+    /// a2.fill_region(..3, .., 1);
+    /// ```
+    pub fn fill_region(
+        &mut self,
+        row_indexes: impl RangeBounds<usize>,
+        col_indexes: impl RangeBounds<usize>,
+        value: T,
+    ) where
+        T: Clone,
+    {
+        let num_rows = self.num_rows();
+        let rows = bounds_to_range(row_indexes, num_rows);
+        let cols = bounds_to_range(col_indexes, self.num_cols);
+        let row_elements = rows.start * self.num_cols..rows.end * self.num_cols;
+        for row in self.data[row_elements].chunks_exact_mut(self.num_cols.max(1)) {
+            row[cols.clone()].fill(value.clone());
+        }
+    }
+
+    /// Sets elements in the given rows and columns to the given value, or returns
+    /// [`Array2Error::RangeOutOfBounds`] without modifying the array if either range is out of bounds
+    /// or its start is greater than its end. See [`fill_region`](struct.Array2.html#method.fill_region).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let mut a2: Array2<u32> = Array2::new(3, 2, 0);
+    /// assert_eq!(a2.try_fill_region(.., 1..4, 7), Err(Array2Error::RangeOutOfBounds { len: 3 }));
+    /// assert_eq!(a2.try_fill_region(1.., 1..3, 7), Ok(()));
+    /// assert_eq!(a2, Array2::new_from_rows([[0, 0, 0], [0, 7, 7]]));
+    /// ```
+    pub fn try_fill_region(
+        &mut self,
+        row_indexes: impl RangeBounds<usize>,
+        col_indexes: impl RangeBounds<usize>,
+        value: T,
+    ) -> Result<(), Array2Error>
+    where
+        T: Clone,
+    {
+        let rows = checked_bounds_to_range(row_indexes, self.num_rows())?;
+        let cols = checked_bounds_to_range(col_indexes, self.num_cols)?;
+        self.fill_region(rows, cols, value);
+        Ok(())
+    }
+
+    /// Copies the elements in the given rows and columns of `src` into this array,
+    /// with the top left corner of the region placed at `dst_origin`, given as `(row, column)`.
+    ///
+    /// Each row of the region is copied with a single [`clone_from_slice`](slice::clone_from_slice) call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let sprite = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let mut screen = Array2::new(4, 3, 0);
+    /// screen.copy_region_from(&sprite, .., 1.., (1, 0));
+    /// assert_eq!(screen, Array2::new_from_rows([[0, 0, 0, 0], [2, 3, 0, 0], [5, 6, 0, 0]]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is out of bounds for `src`, or if the region does not fit into
+    /// this array at `dst_origin`. Use [`copy_region_from_clipped`](struct.Array2.html#method.copy_region_from_clipped)
+    /// to copy only the part of the region that fits, or [`try_copy_region_from`](struct.Array2.html#method.try_copy_region_from)
+    /// for a non-panicking version.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::Array2;
+    /// let sprite = Array2::new(2, 2, 1);
+    /// let mut screen = Array2::new(4, 3, 0);
+    /// screen.copy_region_from(&sprite, .., .., (2, 0));
+    /// ```
+    pub fn copy_region_from(
+        &mut self,
+        src: &Array2<T>,
+        src_rows: impl RangeBounds<usize>,
+        src_cols: impl RangeBounds<usize>,
+        dst_origin: (usize, usize),
+    ) where
+        T: Clone,
+    {
+        let src_rows = bounds_to_range(src_rows, src.num_rows());
+        let src_cols = bounds_to_range(src_cols, src.num_cols);
+        let (dst_row, dst_col) = dst_origin;
+        let num_rows = self.num_rows();
+        assert!(
+            dst_row.saturating_add(src_rows.len()) <= num_rows
+                && dst_col.saturating_add(src_cols.len()) <= self.num_cols,
+            "Region of {} rows and {} columns at ({}, {}) does not fit into {} rows and {} columns",
+            src_rows.len(),
+            src_cols.len(),
+            dst_row,
+            dst_col,
+            num_rows,
+            self.num_cols
+        );
+        self.copy_rows_from(src, src_rows, src_cols, dst_origin);
+    }
+
+    /// Copies the elements in the given rows and columns of `src` into this array like
+    /// [`copy_region_from`](struct.Array2.html#method.copy_region_from), or returns an error
+    /// without modifying the array:
+    ///
+    /// * [`Array2Error::RangeOutOfBounds`] if either range is out of bounds for `src`
+    ///   or its start is greater than its end,
+    /// * [`Array2Error::RegionOutOfBounds`] if the region does not fit into this array at `dst_origin`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error, Coord, Shape};
+    /// let sprite = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let mut screen = Array2::new(4, 3, 0);
+    /// assert_eq!(screen.try_copy_region_from(&sprite, 3.., .., (0, 0)), Err(Array2Error::RangeOutOfBounds { len: 2 }));
+    /// assert_eq!(
+    ///     screen.try_copy_region_from(&sprite, .., .., (2, 0)),
+    ///     Err(Array2Error::RegionOutOfBounds { region: Shape { rows: 2, cols: 3 }, origin: Coord { row: 2, col: 0 } })
+    /// );
+    /// assert_eq!(screen.try_copy_region_from(&sprite, ..1, .., (2, 1)), Ok(()));
+    /// assert_eq!(screen, Array2::new_from_rows([[0, 0, 0, 0], [0, 0, 0, 0], [0, 1, 2, 3]]));
+    /// ```
+    pub fn try_copy_region_from(
+        &mut self,
+        src: &Array2<T>,
+        src_rows: impl RangeBounds<usize>,
+        src_cols: impl RangeBounds<usize>,
+        dst_origin: (usize, usize),
+    ) -> Result<(), Array2Error>
+    where
+        T: Clone,
+    {
+        let src_rows = checked_bounds_to_range(src_rows, src.num_rows())?;
+        let src_cols = checked_bounds_to_range(src_cols, src.num_cols)?;
+        let (dst_row, dst_col) = dst_origin;
+        if dst_row.saturating_add(src_rows.len()) > self.num_rows()
+            || dst_col.saturating_add(src_cols.len()) > self.num_cols
+        {
+            return Err(Array2Error::RegionOutOfBounds {
+                region: Shape::new(src_rows.len(), src_cols.len()),
+                origin: Coord::new(dst_row, dst_col),
+            });
+        }
+        self.copy_rows_from(src, src_rows, src_cols, dst_origin);
+        Ok(())
+    }
+
+    /// Copies the elements in the given rows and columns of `src` into this array,
+    /// with the top left corner of the region placed at `dst_origin`, given as `(row, column)`,
+    /// skipping the parts of the region that are out of bounds of either array.
+    /// The origin may be negative, e.g. to draw a sprite partially off screen.
+    ///
+    /// Returns the rows and columns of this array that were written to, which are empty
+    /// if the region does not overlap the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let sprite = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let mut screen = Array2::new(4, 3, 0);
+    /// assert_eq!(screen.copy_region_from_clipped(&sprite, .., .., (-1, 2)), (0..1, 2..4));
+    /// assert_eq!(screen, Array2::new_from_rows([[0, 0, 4, 5], [0, 0, 0, 0], [0, 0, 0, 0]]));
+    ///
+    /// let (rows, cols) = screen.copy_region_from_clipped(&sprite, .., ..10, (5, 0));
+    /// assert!(rows.is_empty());
+    /// ```
+    pub fn copy_region_from_clipped(
+        &mut self,
+        src: &Array2<T>,
+        src_rows: impl RangeBounds<usize>,
+        src_cols: impl RangeBounds<usize>,
+        dst_origin: (isize, isize),
+    ) -> (Range<usize>, Range<usize>)
+    where
+        T: Clone,
+    {
+        let (src_rows, dst_rows) = clip_axis(
+            clamp_bounds_to_range(src_rows, src.num_rows()),
+            dst_origin.0,
+            self.num_rows(),
+        );
+        let (src_cols, dst_cols) = clip_axis(
+            clamp_bounds_to_range(src_cols, src.num_cols),
+            dst_origin.1,
+            self.num_cols,
+        );
+        if dst_rows.is_empty() || dst_cols.is_empty() {
+            return (
+                dst_rows.start..dst_rows.start,
+                dst_cols.start..dst_cols.start,
+            );
+        }
+        self.copy_rows_from(src, src_rows, src_cols, (dst_rows.start, dst_cols.start));
+        (dst_rows, dst_cols)
+    }
+
+    /// Copies a region of `src` that is known to fit into this array at `dst_origin`.
+    fn copy_rows_from(
+        &mut self,
+        src: &Array2<T>,
+        src_rows: Range<usize>,
+        src_cols: Range<usize>,
+        dst_origin: (usize, usize),
+    ) where
+        T: Clone,
+    {
+        let (dst_row, dst_col) = dst_origin;
+        for (i, src_row) in src_rows.enumerate() {
+            let src_start = src_row * src.num_cols;
+            let src_elements = &src.data[src_start + src_cols.start..src_start + src_cols.end];
+            let dst_start = (dst_row + i) * self.num_cols + dst_col;
+            self.data[dst_start..dst_start + src_elements.len()].clone_from_slice(src_elements);
+        }
+    }
+
+    /// Returns a new [`Array2`] of the same dimensions as this array,
+    /// with function `f` applied to each element in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2_u32: Array2<u32> = Array2::new_from_rows([[1, 2], [3, 4], [5, 6]]);
+    /// let a2_x10f32 = a2_u32.map(|&elt| elt as f32 * 10.0);
+    /// assert_eq!(a2_x10f32.rows().collect::<Vec<_>>(), vec![&[10.0, 20.0], &[30.0, 40.0], &[50.0, 60.0]]);
+    /// ```
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Array2<U> {
+        let data = self.data.iter().map(f).collect();
+        let num_cols = self.num_cols;
+        Array2 { data, num_cols }
+    }
+
+    /// Returns a new [`Array2`] of the same dimensions as this array, with fallible function `f`
+    /// applied to each element in row-major order, or the first error returned by `f`.
+    /// Elements after the one that failed are not visited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let cells = Array2::new_from_rows([["1", "2"], ["3", "4"]]);
+    /// let parsed = cells.try_map(|s| s.parse::<u8>()).unwrap();
+    /// assert_eq!(parsed, Array2::new_from_rows([[1, 2], [3, 4]]));
+    ///
+    /// let wide = Array2::new_from_rows([[1u32, 300], [2, 400]]);
+    /// let mut visited = 0;
+    /// let narrow = wide.try_map(|&elt| { visited += 1; u8::try_from(elt) });
+    /// assert!(narrow.is_err());
+    /// assert_eq!(visited, 2);
+    /// ```
+    pub fn try_map<U, E>(&self, f: impl FnMut(&T) -> Result<U, E>) -> Result<Array2<U>, E> {
+        let data = self.data.iter().map(f).collect::<Result<_, E>>()?;
+        let num_cols = self.num_cols;
+        Ok(Array2 { data, num_cols })
+    }
+
+    /// Returns an iterator over the elements in positions where the mask is true, in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, -2, 3], [-4, 5, -6]]);
+    /// let mask = a2.map(|&elt| elt < 0);
+    /// assert_eq!(a2.select(&mask).collect::<Vec<_>>(), vec![&-2, &-4, &-6]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mask has a different shape than this array.
+    /// See [`try_select`](struct.Array2.html#method.try_select) for a non-panicking version.
+    pub fn select<'a>(
+        &'a self,
+        mask: &'a Array2<bool>,
+    ) -> impl DoubleEndedIterator<Item = &'a T> + FusedIterator {
+        self.assert_same_shape(mask);
+        self.data
+            .iter()
+            .zip(mask.data.iter())
+            .filter_map(|(elt, &selected)| selected.then_some(elt))
+    }
+
+    /// Returns an iterator over the elements in positions where the mask is true, in row-major order,
+    /// or [`Array2Error::ShapeMismatch`] if the mask has a different shape than this array.
+    /// See [`select`](struct.Array2.html#method.select).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error, Shape};
+    /// let a2 = Array2::new_from_rows([[1, -2, 3], [-4, 5, -6]]);
+    /// assert_eq!(a2.try_select(&a2.map(|&elt| elt > 2)).unwrap().collect::<Vec<_>>(), vec![&3, &5]);
+    /// assert_eq!(
+    ///     a2.try_select(&Array2::new(3, 1, true)).err(),
+    ///     Some(Array2Error::ShapeMismatch { expected: Shape { rows: 2, cols: 3 }, found: Shape { rows: 1, cols: 3 } })
+    /// );
+    /// ```
+    pub fn try_select<'a>(
+        &'a self,
+        mask: &'a Array2<bool>,
+    ) -> Result<impl DoubleEndedIterator<Item = &'a T> + FusedIterator, Array2Error> {
+        self.check_same_shape(mask)?;
+        Ok(self.select(mask))
+    }
+
+    /// Sets the elements in positions where the mask is true to the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, -2, 3], [-4, 5, -6]]);
+    /// let mask = a2.map(|&elt| elt < 0);
+    /// a2.masked_fill(&mask, 0);
+    /// assert_eq!(a2, Array2::new_from_rows([[1, 0, 3], [0, 5, 0]]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mask has a different shape than this array.
+    /// See [`try_masked_fill`](struct.Array2.html#method.try_masked_fill) for a non-panicking version.
+    pub fn masked_fill(&mut self, mask: &Array2<bool>, value: T)
+    where
+        T: Clone,
+    {
+        self.assert_same_shape(mask);
+        for (elt, _) in self
+            .data
+            .iter_mut()
+            .zip(mask.data.iter())
+            .filter(|(_, &selected)| selected)
+        {
+            *elt = value.clone();
+        }
+    }
+
+    /// Sets the elements in positions where the mask is true to the given value,
+    /// or returns [`Array2Error::ShapeMismatch`] without modifying the array
+    /// if the mask has a different shape than this array.
+    /// See [`masked_fill`](struct.Array2.html#method.masked_fill).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let mut a2 = Array2::new_from_rows([[1, 2], [3, 4]]);
+    /// assert!(a2.try_masked_fill(&Array2::new(2, 3, true), 0).is_err());
+    /// assert_eq!(a2.try_masked_fill(&Array2::new_from_rows([[true, false], [false, true]]), 0), Ok(()));
+    /// assert_eq!(a2, Array2::new_from_rows([[0, 2], [3, 0]]));
+    /// ```
+    pub fn try_masked_fill(&mut self, mask: &Array2<bool>, value: T) -> Result<(), Array2Error>
+    where
+        T: Clone,
+    {
+        self.check_same_shape(mask)?;
+        self.masked_fill(mask, value);
+        Ok(())
+    }
+
+    /// Returns [`Array2Error::ShapeMismatch`] if `other` has a different shape than this array.
+    pub(crate) fn check_same_shape<U>(&self, other: &Array2<U>) -> Result<(), Array2Error> {
+        if self.num_cols == other.num_cols && self.data.len() == other.data.len() {
+            Ok(())
+        } else {
+            Err(Array2Error::ShapeMismatch {
+                expected: self.shape(),
+                found: other.shape(),
+            })
+        }
+    }
+
+    /// Returns the `(row, column)` position of the first element in row-major order
+    /// that satisfies the predicate, or None if there is no such element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let maze = Array2::from_str_grid("#.#\n#S.\n###", Ok::<_, ()>).unwrap();
+    /// assert_eq!(maze.position(|&c| c == 'S'), Some((1, 1)));
+    /// assert_eq!(maze.position(|&c| c == 'E'), None);
+    /// ```
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        let index = self.data.iter().position(pred)?;
+        Some((index / self.num_cols, index % self.num_cols))
+    }
+
+    /// Returns an iterator over the `(row, column)` positions of all elements
+    /// that satisfy the predicate, in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[0, 1, 0], [1, 0, 1]]);
+    /// assert_eq!(a2.positions(|&elt| elt == 1).collect::<Vec<_>>(), vec![(0, 1), (1, 0), (1, 2)]);
+    /// ```
+    pub fn positions<'a>(
+        &'a self,
+        mut pred: impl FnMut(&T) -> bool + 'a,
+    ) -> impl DoubleEndedIterator<Item = (usize, usize)> + FusedIterator + 'a {
+        let num_cols = self.num_cols;
+        self.data
+            .iter()
+            .enumerate()
+            .filter(move |(_, elt)| pred(elt))
+            .map(move |(index, _)| (index / num_cols, index % num_cols))
+    }
+
+    /// Returns the column index of the first element in the given row that satisfies the predicate,
+    /// or None if there is no such element.
+    ///
+    /// Panics if the row index is out of bounds.
+    /// See [`try_find_in_row`](struct.Array2.html#method.try_find_in_row) for a non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a2.find_in_row(1, |&elt| elt % 2 == 0), Some(0));
+    /// assert_eq!(a2.find_in_row(1, |&elt| elt > 6), None);
+    /// ```
+    pub fn find_in_row(&self, row_index: usize, pred: impl FnMut(&T) -> bool) -> Option<usize> {
+        self[row_index].iter().position(pred)
+    }
+
+    /// Returns the row index of the first element in the given column that satisfies the predicate,
+    /// or None if there is no such element.
+    ///
+    /// Panics if the column index is out of bounds.
+    /// See [`try_find_in_col`](struct.Array2.html#method.try_find_in_col) for a non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a2.find_in_col(1, |&elt| elt > 2), Some(1));
+    /// assert_eq!(a2.find_in_col(1, |&elt| elt > 5), None);
+    /// ```
+    pub fn find_in_col(&self, col_index: usize, pred: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.col(col_index)
+            .unwrap_or_else(|| panic!("Column index {} is out of bounds", col_index))
+            .position(pred)
+    }
+
+    /// Returns the column index of the first element in the given row that satisfies the predicate,
+    /// None if there is no such element, or [`Array2Error::IndexOutOfBounds`] if the row index is out of bounds.
+    /// See [`find_in_row`](struct.Array2.html#method.find_in_row).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a2.try_find_in_row(1, |&elt| elt > 4), Ok(Some(1)));
+    /// assert_eq!(a2.try_find_in_row(2, |&elt| elt > 4), Err(Array2Error::IndexOutOfBounds { index: 2, len: 2 }));
+    ///
+    /// let empty: Array2<i32> = Array2::new(0, 0, 0);
+    /// assert_eq!(empty.try_find_in_row(0, |_| true), Err(Array2Error::IndexOutOfBounds { index: 0, len: 0 }));
+    /// ```
+    pub fn try_find_in_row(
+        &self,
+        row_index: usize,
+        pred: impl FnMut(&T) -> bool,
+    ) -> Result<Option<usize>, Array2Error> {
+        // Checked against the number of rows, since `row` gives an empty slice
+        // for any index when there are no columns.
+        if row_index >= self.num_rows() {
+            return Err(Array2Error::IndexOutOfBounds {
+                index: row_index,
+                len: self.num_rows(),
+            });
+        }
+        Ok(self[row_index].iter().position(pred))
+    }
+
+    /// Returns the row index of the first element in the given column that satisfies the predicate,
+    /// None if there is no such element, or [`Array2Error::IndexOutOfBounds`] if the column index is out of bounds.
+    /// See [`find_in_col`](struct.Array2.html#method.find_in_col).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a2.try_find_in_col(2, |&elt| elt > 4), Ok(Some(1)));
+    /// assert_eq!(a2.try_find_in_col(3, |&elt| elt > 4), Err(Array2Error::IndexOutOfBounds { index: 3, len: 3 }));
+    /// ```
+    pub fn try_find_in_col(
+        &self,
+        col_index: usize,
+        pred: impl FnMut(&T) -> bool,
+    ) -> Result<Option<usize>, Array2Error> {
+        match self.col(col_index) {
+            Some(mut col) => Ok(col.position(pred)),
+            None => Err(Array2Error::IndexOutOfBounds {
+                index: col_index,
+                len: self.num_cols,
+            }),
+        }
+    }
+
+    /// Calls function `f` with the `(row, column)` index and a mutable reference of each element
+    /// in row-major order, stopping at the first element for which `f` returns [`ControlFlow::Break`].
+    ///
+    /// Returns the break value, or [`ControlFlow::Continue`] if `f` was called for all elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// # use std::ops::ControlFlow;
+    /// let mut a2 = Array2::new_from_rows([[1, -1, 3], [-4, 5, 6]]);
+    /// // Repair the first negative element only
+    /// let repaired = a2.try_for_each_element_mut(|(row, col), elt| {
+    ///     if *elt < 0 {
+    ///         *elt = 0;
+    ///         return ControlFlow::Break((row, col));
+    ///     }
+    ///     ControlFlow::Continue(())
+    /// });
+    /// assert_eq!(repaired, ControlFlow::Break((0, 1)));
+    /// assert_eq!(a2, Array2::new_from_rows([[1, 0, 3], [-4, 5, 6]]));
+    /// ```
+    pub fn try_for_each_element_mut<B>(
+        &mut self,
+        mut f: impl FnMut((usize, usize), &mut T) -> ControlFlow<B>,
+    ) -> ControlFlow<B> {
+        let num_cols = self.num_cols;
+        for (i, elt) in self.data.iter_mut().enumerate() {
+            f((i / num_cols, i % num_cols), elt)?;
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Replaces elements at the given flat (row-major) indexes with new values,
+    /// writing them in a single ascending pass over the underlying buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, OrdVec, OrdVecKeyFst};
+    /// let mut a2 = Array2::new(3, 2, 0);
+    /// let updates: OrdVec<_, OrdVecKeyFst> = vec![(5, 9), (1, 7)].into();
+    /// a2.apply_sorted_updates(&updates);
+    /// assert_eq!(a2, Array2::new_from_rows([[0, 7, 0], [0, 0, 9]]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds, in which case no elements are updated.
+    /// See [`try_apply_sorted_updates`](struct.Array2.html#method.try_apply_sorted_updates) for a non-panicking version.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::{Array2, OrdVec, OrdVecKeyFst};
+    /// let mut a2 = Array2::new(3, 2, 0);
+    /// let updates: OrdVec<_, OrdVecKeyFst> = vec![(1, 7), (6, 9)].into();
+    /// a2.apply_sorted_updates(&updates);
+    /// ```
+    pub fn apply_sorted_updates(&mut self, updates: &OrdVec<(usize, T), OrdVecKeyFst>)
+    where
+        T: Clone,
+    {
+        if let Some(&(last_index, _)) = updates.last() {
+            assert!(
+                last_index < self.data.len(),
+                "Element index {} is out of bounds",
+                last_index
+            );
+        }
+        for (index, value) in updates.iter() {
+            self.data[*index] = value.clone();
+        }
+    }
+
+    /// Overwrites the elements at the given indexes in the underlying buffer like
+    /// [`apply_sorted_updates`](struct.Array2.html#method.apply_sorted_updates),
+    /// or returns [`Array2Error::IndexOutOfBounds`] with the largest index without updating any elements
+    /// if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error, OrdVec, OrdVecKeyFst};
+    /// let mut a2 = Array2::new(3, 2, 0);
+    /// let updates: OrdVec<_, OrdVecKeyFst> = vec![(1, 7), (6, 9)].into();
+    /// assert_eq!(a2.try_apply_sorted_updates(&updates), Err(Array2Error::IndexOutOfBounds { index: 6, len: 6 }));
+    /// assert_eq!(a2, Array2::new(3, 2, 0));
+    /// ```
+    pub fn try_apply_sorted_updates(
+        &mut self,
+        updates: &OrdVec<(usize, T), OrdVecKeyFst>,
+    ) -> Result<(), Array2Error>
+    where
+        T: Clone,
+    {
+        match updates.last() {
+            Some(&(index, _)) if index >= self.data.len() => Err(Array2Error::IndexOutOfBounds {
+                index,
+                len: self.data.len(),
+            }),
+            _ => {
+                self.apply_sorted_updates(updates);
+                Ok(())
+            }
+        }
+    }
+
+    /// Sorts rows in ascending order of keys extracted from each row by the key function `K`.
+    /// The sort is stable: rows with equal keys keep their relative order.
+    ///
+    /// After sorting, rows can be looked up by key using
+    /// [`find_row_by_key`](struct.Array2.html#method.find_row_by_key).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, OrdVecKeyCol};
+    /// let mut table = Array2::new_from_rows([[3, 30], [1, 10], [2, 20]]);
+    /// table.sort_rows_by_key::<OrdVecKeyCol<0>>();
+    /// assert_eq!(table, Array2::new_from_rows([[1, 10], [2, 20], [3, 30]]));
+    /// ```
+    pub fn sort_rows_by_key<K: OrdVecKey<[T]>>(&mut self) {
+        if self.data.is_empty() {
+            return;
+        }
+        let num_cols = self.num_cols;
+        let mut order: Vec<usize> = (0..self.num_rows()).collect();
+        order.sort_by(|&a, &b| {
+            let row_a = &self.data[a * num_cols..(a + 1) * num_cols];
+            let row_b = &self.data[b * num_cols..(b + 1) * num_cols];
+            K::cmp_keys(K::get_key(row_a), K::get_key(row_b))
+        });
+        permute_in_place(&mut self.data, |i| {
+            order[i / num_cols] * num_cols + i % num_cols
+        });
+    }
+
+    /// Returns the index of a row with the given key extracted by the key function `K`,
+    /// or None if there is no such row, using binary search.
+    ///
+    /// The rows must be sorted by the same key function
+    /// (see [`sort_rows_by_key`](struct.Array2.html#method.sort_rows_by_key)),
+    /// otherwise the result is unspecified. If several rows have the given key,
+    /// any one of them may be returned. To check the order once and then look up rows
+    /// repeatedly, see [`SortedRows`](crate::SortedRows).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, OrdVecKeyCol};
+    /// let mut table = Array2::new_from_rows([["b", "2"], ["c", "3"], ["a", "1"]]);
+    /// table.sort_rows_by_key::<OrdVecKeyCol<0>>();
+    /// assert_eq!(table.find_row_by_key::<OrdVecKeyCol<0>>(&"c"), Some(2));
+    /// assert_eq!(table.find_row_by_key::<OrdVecKeyCol<0>>(&"d"), None);
+    /// ```
+    pub fn find_row_by_key<K: OrdVecKey<[T]>>(&self, key: &K::Key) -> Option<usize> {
+        let (mut lo, mut hi) = (0, self.num_rows());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let row = &self.data[mid * self.num_cols..(mid + 1) * self.num_cols];
+            match K::cmp_keys(K::get_key(row), key) {
+                Ordering::Less => lo = mid + 1,
+                Ordering::Greater => hi = mid,
+                Ordering::Equal => return Some(mid),
+            }
+        }
+        None
+    }
+
+    /// Swaps two rows of the array.
+    ///
+    /// Panics if either row index is out of bounds.
+    /// See [`try_swap_rows`](struct.Array2.html#method.try_swap_rows) for a non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2], [3, 4], [5, 6]]);
+    /// a2.swap_rows(0, 2);
+    /// assert_eq!(a2, Array2::new_from_rows([[5, 6], [3, 4], [1, 2]]));
+    /// ```
+    pub fn swap_rows(&mut self, row_index_a: usize, row_index_b: usize) {
+        let num_rows = self.num_rows();
+        for i in [row_index_a, row_index_b] {
+            assert!(i < num_rows, "Row index {} is out of bounds", i);
+        }
+        let (lo, hi) = (row_index_a.min(row_index_b), row_index_a.max(row_index_b));
+        if lo != hi {
+            let (head, tail) = self.data.split_at_mut(hi * self.num_cols);
+            head[lo * self.num_cols..(lo + 1) * self.num_cols]
+                .swap_with_slice(&mut tail[..self.num_cols]);
+        }
+    }
+
+    /// Swaps two columns of the array.
+    ///
+    /// Panics if either column index is out of bounds.
+    /// See [`try_swap_cols`](struct.Array2.html#method.try_swap_cols) for a non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// a2.swap_cols(0, 2);
+    /// assert_eq!(a2, Array2::new_from_rows([[3, 2, 1], [6, 5, 4]]));
+    /// ```
+    pub fn swap_cols(&mut self, col_index_a: usize, col_index_b: usize) {
+        for i in [col_index_a, col_index_b] {
+            assert!(i < self.num_cols, "Column index {} is out of bounds", i);
+        }
+        for row in self.data.chunks_mut(self.num_cols) {
+            row.swap(col_index_a, col_index_b);
+        }
+    }
+
+    /// Swaps two rows of the array, or returns [`Array2Error::IndexOutOfBounds`] without modifying it
+    /// if either row index is out of bounds. See [`swap_rows`](struct.Array2.html#method.swap_rows).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let mut a2 = Array2::new_from_rows([[1, 2], [3, 4]]);
+    /// assert_eq!(a2.try_swap_rows(0, 2), Err(Array2Error::IndexOutOfBounds { index: 2, len: 2 }));
+    /// assert_eq!(a2.try_swap_rows(0, 1), Ok(()));
+    /// assert_eq!(a2, Array2::new_from_rows([[3, 4], [1, 2]]));
+    /// ```
+    pub fn try_swap_rows(
+        &mut self,
+        row_index_a: usize,
+        row_index_b: usize,
+    ) -> Result<(), Array2Error> {
+        let len = self.num_rows();
+        if let Some(index) = [row_index_a, row_index_b].into_iter().find(|&i| i >= len) {
+            return Err(Array2Error::IndexOutOfBounds { index, len });
+        }
+        self.swap_rows(row_index_a, row_index_b);
+        Ok(())
+    }
+
+    /// Swaps two columns of the array, or returns [`Array2Error::IndexOutOfBounds`] without modifying it
+    /// if either column index is out of bounds. See [`swap_cols`](struct.Array2.html#method.swap_cols).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let mut a2 = Array2::new_from_rows([[1, 2], [3, 4]]);
+    /// assert_eq!(a2.try_swap_cols(5, 0), Err(Array2Error::IndexOutOfBounds { index: 5, len: 2 }));
+    /// assert_eq!(a2.try_swap_cols(0, 1), Ok(()));
+    /// assert_eq!(a2, Array2::new_from_rows([[2, 1], [4, 3]]));
+    /// ```
+    pub fn try_swap_cols(
+        &mut self,
+        col_index_a: usize,
+        col_index_b: usize,
+    ) -> Result<(), Array2Error> {
+        let len = self.num_cols;
+        if let Some(index) = [col_index_a, col_index_b].into_iter().find(|&i| i >= len) {
+            return Err(Array2Error::IndexOutOfBounds { index, len });
+        }
+        self.swap_cols(col_index_a, col_index_b);
+        Ok(())
+    }
+
+    /// Reorders the rows in place so that the row at each index `i` is replaced by
+    /// the row previously at index `order[i]`, e.g. to apply a sort order computed elsewhere.
+    ///
+    /// The permutation is decomposed into cycles, and a cycle of `k` rows is applied with `k - 1`
+    /// row swaps. A row may therefore be swapped several times before it reaches its place,
+    /// but no temporary copy of a row is made.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut table = Array2::new_from_rows([["b", "2"], ["c", "3"], ["a", "1"]]);
+    /// table.permute_rows(&[2, 0, 1]);
+    /// assert_eq!(table, Array2::new_from_rows([["a", "1"], ["b", "2"], ["c", "3"]]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is not a permutation of the row indexes.
+    /// See [`try_permute_rows`](struct.Array2.html#method.try_permute_rows) for a non-panicking version.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1], [2], [3]]);
+    /// a2.permute_rows(&[0, 1, 1]);
+    /// ```
+    pub fn permute_rows(&mut self, order: &[usize]) {
+        if let Err(e) = self.try_permute_rows(order) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Reorders the rows in place like [`permute_rows`](struct.Array2.html#method.permute_rows),
+    /// or returns [`Array2Error::InvalidPermutation`] without modifying the array
+    /// if `order` is not a permutation of the row indexes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let mut a2 = Array2::new_from_rows([[1], [2], [3]]);
+    /// assert_eq!(a2.try_permute_rows(&[1, 2, 0]), Ok(()));
+    /// assert_eq!(a2, Array2::new_from_rows([[2], [3], [1]]));
+    /// assert_eq!(a2.try_permute_rows(&[0, 1]), Err(Array2Error::InvalidPermutation { len: 3 }));
+    /// assert_eq!(a2.try_permute_rows(&[0, 1, 3]), Err(Array2Error::InvalidPermutation { len: 3 }));
+    /// ```
+    pub fn try_permute_rows(&mut self, order: &[usize]) -> Result<(), Array2Error> {
+        for (a, b) in permutation_swaps(order, self.num_rows())? {
+            self.swap_rows(a, b);
+        }
+        Ok(())
+    }
+
+    /// Reorders the columns in place so that the column at each index `i` is replaced by
+    /// the column previously at index `order[i]`.
+    ///
+    /// The permutation is decomposed into swaps once, which are then applied to each row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// a2.permute_cols(&[2, 0, 1]);
+    /// assert_eq!(a2, Array2::new_from_rows([[3, 1, 2], [6, 4, 5]]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is not a permutation of the column indexes.
+    /// See [`try_permute_cols`](struct.Array2.html#method.try_permute_cols) for a non-panicking version.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2, 3]]);
+    /// a2.permute_cols(&[0, 1]);
+    /// ```
+    pub fn permute_cols(&mut self, order: &[usize]) {
+        if let Err(e) = self.try_permute_cols(order) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Reorders the columns in place like [`permute_cols`](struct.Array2.html#method.permute_cols),
+    /// or returns [`Array2Error::InvalidPermutation`] without modifying the array
+    /// if `order` is not a permutation of the column indexes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let mut a2 = Array2::new_from_rows([[1, 2], [3, 4]]);
+    /// assert_eq!(a2.try_permute_cols(&[1, 1]), Err(Array2Error::InvalidPermutation { len: 2 }));
+    /// assert_eq!(a2.try_permute_cols(&[1, 0]), Ok(()));
+    /// assert_eq!(a2, Array2::new_from_rows([[2, 1], [4, 3]]));
+    /// ```
+    pub fn try_permute_cols(&mut self, order: &[usize]) -> Result<(), Array2Error> {
+        let swaps = permutation_swaps(order, self.num_cols)?;
+        if !swaps.is_empty() {
+            for row in self.data.chunks_mut(self.num_cols) {
+                for &(a, b) in &swaps {
+                    row.swap(a, b);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Transposes the array in place, turning rows into columns.
+    /// The number of rows and the number of columns are swapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// a2.transpose();
+    /// assert_eq!(a2, Array2::new_from_rows([[1, 4], [2, 5], [3, 6]]));
+    /// ```
+    pub fn transpose(&mut self) {
+        if self.data.is_empty() {
+            return;
+        }
+        let (num_cols, num_rows) = (self.num_cols, self.num_rows());
+        permute_in_place(&mut self.data, |i| {
+            let (row, col) = (i / num_rows, i % num_rows);
+            col * num_cols + row
+        });
+        self.num_cols = num_rows;
+    }
+
+    /// Rotates the array by 90 degrees clockwise in place.
+    /// The number of rows and the number of columns are swapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// a2.rotate_90_cw();
+    /// assert_eq!(a2, Array2::new_from_rows([[4, 1], [5, 2], [6, 3]]));
+    /// ```
+    pub fn rotate_90_cw(&mut self) {
+        if self.data.is_empty() {
+            return;
+        }
+        let (num_cols, num_rows) = (self.num_cols, self.num_rows());
+        permute_in_place(&mut self.data, |i| {
+            let (row, col) = (i / num_rows, i % num_rows);
+            (num_rows - 1 - col) * num_cols + row
+        });
+        self.num_cols = num_rows;
+    }
+
+    /// Rotates the array by 90 degrees counterclockwise in place.
+    /// The number of rows and the number of columns are swapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// a2.rotate_90_ccw();
+    /// assert_eq!(a2, Array2::new_from_rows([[3, 6], [2, 5], [1, 4]]));
+    /// ```
+    pub fn rotate_90_ccw(&mut self) {
+        if self.data.is_empty() {
+            return;
+        }
+        let (num_cols, num_rows) = (self.num_cols, self.num_rows());
+        permute_in_place(&mut self.data, |i| {
+            let (row, col) = (i / num_rows, i % num_rows);
+            col * num_cols + (num_cols - 1 - row)
+        });
+        self.num_cols = num_rows;
+    }
+
+    /// Mirrors the array left to right in place, reversing the order of elements in each row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// a2.flip_horizontal();
+    /// assert_eq!(a2, Array2::new_from_rows([[3, 2, 1], [6, 5, 4]]));
+    /// ```
+    pub fn flip_horizontal(&mut self) {
+        if self.data.is_empty() {
+            return;
+        }
+        for row in self.data.chunks_mut(self.num_cols) {
+            row.reverse();
+        }
+    }
+
+    /// Mirrors the array top to bottom in place, reversing the order of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// a2.flip_vertical();
+    /// assert_eq!(a2, Array2::new_from_rows([[4, 5, 6], [1, 2, 3]]));
+    /// ```
+    pub fn flip_vertical(&mut self) {
+        if self.data.is_empty() {
+            return;
+        }
+        let num_rows = self.num_rows();
+        for i in 0..num_rows / 2 {
+            self.swap_rows(i, num_rows - 1 - i);
+        }
+    }
+}
+
+/// Error returned by fallible [`Array2`] operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Array2Error {
+    /// The array at `index` has `found` columns, but `expected` columns were required.
+    NumColsMismatch {
+        /// Index of the offending array.
+        index: usize,
+        /// Required number of columns.
+        expected: usize,
+        /// Actual number of columns.
+        found: usize,
+    },
+    /// The array at `index` has `found` rows, but `expected` rows were required.
+    NumRowsMismatch {
+        /// Index of the offending array.
+        index: usize,
+        /// Required number of rows.
+        expected: usize,
+        /// Actual number of rows.
+        found: usize,
+    },
+    /// The array has `found` dimensions, but `expected` dimensions were required.
+    ShapeMismatch {
+        /// Required dimensions.
+        expected: Shape,
+        /// Actual dimensions.
+        found: Shape,
+    },
+    /// The row at index `row` has `found` elements, but `expected` elements were required
+    /// to match the preceding rows.
+    RowLengthMismatch {
+        /// Index of the offending row.
+        row: usize,
+        /// Required number of elements.
+        expected: usize,
+        /// Actual number of elements.
+        found: usize,
+    },
+    /// The array would have `num_rows` rows with no columns,
+    /// which cannot be represented because the number of rows is derived from the number of columns.
+    ZeroColumns {
+        /// Requested number of rows.
+        num_rows: usize,
+    },
+    /// The number of elements in an array with `num_cols` columns and `num_rows` rows,
+    /// or their size in bytes, overflows `usize`.
+    SizeOverflow {
+        /// Requested number of columns.
+        num_cols: usize,
+        /// Requested number of rows.
+        num_rows: usize,
+    },
+    /// The position at `row` and `col` is out of bounds.
+    PositionOutOfBounds {
+        /// Row index of the position.
+        row: usize,
+        /// Column index of the position.
+        col: usize,
+    },
+    /// The position at `row` and `col` is given more than once.
+    DuplicatePosition {
+        /// Row index of the position.
+        row: usize,
+        /// Column index of the position.
+        col: usize,
+    },
+    /// The input of length `len` cannot be split into rows of length `row_len`,
+    /// either because it is not a multiple of `row_len` or because `row_len` is zero.
+    InvalidLength {
+        /// Length of the input.
+        len: usize,
+        /// Required length of each row.
+        row_len: usize,
+    },
+    /// The input ended after `found` elements, but `expected` elements were required.
+    NotEnoughElements {
+        /// Required number of elements.
+        expected: usize,
+        /// Actual number of elements.
+        found: usize,
+    },
+    /// The input has more than the `expected` number of elements.
+    TooManyElements {
+        /// Required number of elements.
+        expected: usize,
+    },
+    /// The row, column or element index `index` is out of bounds for `len` rows, columns or elements.
+    IndexOutOfBounds {
+        /// Offending index.
+        index: usize,
+        /// Number of rows, columns or elements.
+        len: usize,
+    },
+    /// The indexes are not a permutation of `0..len`: some index is out of bounds or repeated,
+    /// or there are not exactly `len` of them.
+    InvalidPermutation {
+        /// Number of indexes to permute.
+        len: usize,
+    },
+    /// A range of row or column indexes is out of bounds for `len` rows or columns,
+    /// or its start is greater than its end.
+    RangeOutOfBounds {
+        /// Number of rows or columns.
+        len: usize,
+    },
+    /// A region with the dimensions `region` does not fit into the array when placed at `origin`.
+    RegionOutOfBounds {
+        /// Dimensions of the region.
+        region: Shape,
+        /// Position of the top left corner of the region.
+        origin: Coord,
+    },
+    /// A window or tile with `num_cols` columns and `num_rows` rows has no elements.
+    EmptyWindow {
+        /// Requested number of columns.
+        num_cols: usize,
+        /// Requested number of rows.
+        num_rows: usize,
+    },
+    /// Elements of `size` bytes cannot be reinterpreted as elements of `target_size` bytes.
+    ElementSizeMismatch {
+        /// Size of the elements in bytes.
+        size: usize,
+        /// Size of the target elements in bytes.
+        target_size: usize,
+    },
+}
+
+impl std::fmt::Display for Array2Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Array2Error::NumColsMismatch {
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Array {} has {} columns, expected {}",
+                index, found, expected
+            ),
+            Array2Error::NumRowsMismatch {
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Array {} has {} rows, expected {}",
+                index, found, expected
+            ),
+            Array2Error::ShapeMismatch { expected, found } => write!(
+                f,
+                "Array has {} columns and {} rows, expected {} columns and {} rows",
+                found.cols, found.rows, expected.cols, expected.rows
+            ),
+            Array2Error::RowLengthMismatch {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Row {} has {} elements, expected {}",
+                row, found, expected
+            ),
+            Array2Error::ZeroColumns { num_rows } => {
+                write!(
+                    f,
+                    "Array with {} rows must have at least one column",
+                    num_rows
+                )
+            }
+            Array2Error::SizeOverflow { num_cols, num_rows } => write!(
+                f,
+                "Array with {} columns and {} rows is too large",
+                num_cols, num_rows
+            ),
+            Array2Error::PositionOutOfBounds { row, col } => {
+                write!(f, "Position ({}, {}) is out of bounds", row, col)
+            }
+            Array2Error::DuplicatePosition { row, col } => {
+                write!(f, "Position ({}, {}) is given more than once", row, col)
+            }
+            Array2Error::InvalidLength { len, row_len: 0 } => {
+                write!(f, "Length {} cannot be split into rows of zero length", len)
+            }
+            Array2Error::InvalidLength { len, row_len } => write!(
+                f,
+                "Length {} is not a multiple of the row length {}",
+                len, row_len
+            ),
+            Array2Error::NotEnoughElements { expected, found } => {
+                write!(f, "Input has {} elements, expected {}", found, expected)
+            }
+            Array2Error::TooManyElements { expected } => {
+                write!(f, "Input has more than the expected {} elements", expected)
+            }
+            Array2Error::IndexOutOfBounds { index, len } => {
+                write!(f, "Index {} is out of bounds for length {}", index, len)
+            }
+            Array2Error::InvalidPermutation { len } => {
+                write!(f, "Indexes are not a permutation of 0..{}", len)
+            }
+            Array2Error::RangeOutOfBounds { len } => {
+                write!(f, "Range is out of bounds for length {}", len)
+            }
+            Array2Error::RegionOutOfBounds { region, origin } => write!(
+                f,
+                "Region of {} rows and {} columns at ({}, {}) does not fit into the array",
+                region.rows, region.cols, origin.row, origin.col
+            ),
+            Array2Error::EmptyWindow { num_cols, num_rows } => write!(
+                f,
+                "Window of {} columns and {} rows has no elements",
+                num_cols, num_rows
+            ),
+            Array2Error::ElementSizeMismatch { size, target_size } => write!(
+                f,
+                "Cannot cast elements of {} bytes to elements of {} bytes",
+                size, target_size
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Array2Error {}
+
+/// Error returned by [`Array2::from_str_grid`]. Line and column numbers start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseGridError<E> {
+    /// The input has no lines, or all of its lines are empty.
+    Empty,
+    /// The line has `found` characters, but `expected` characters were required
+    /// to match the first line.
+    RaggedLine {
+        /// Line number of the offending line.
+        line: usize,
+        /// Number of characters in the first line.
+        expected: usize,
+        /// Actual number of characters.
+        found: usize,
+    },
+    /// The character at the given position could not be converted to an element.
+    InvalidChar {
+        /// Line number of the offending character.
+        line: usize,
+        /// Column number of the offending character, counted in characters.
+        column: usize,
+        /// Error returned by the conversion function.
+        error: E,
+    },
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for ParseGridError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ParseGridError::Empty => write!(f, "Grid is empty"),
+            ParseGridError::RaggedLine {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Line {} has {} characters, expected {}",
+                line, found, expected
+            ),
+            ParseGridError::InvalidChar {
+                line,
+                column,
+                error,
+            } => write!(
+                f,
+                "Invalid character at line {}, column {}: {}",
+                line, column, error
+            ),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for ParseGridError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseGridError::InvalidChar { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl<T> Index<usize> for Array2<T> {
+    type Output = [T];
+
+    /// Returns a slice of the underlying buffer with elements of the row at the given index.
+    ///
+    /// Panics if the index is out of bounds. See [`row`](struct.Array2.html#method.row) for a non-panicking version.
+    fn index(&self, row_index: usize) -> &Self::Output {
+        self.row(row_index)
+            .unwrap_or_else(|| panic!("Row index {} is out of bounds", row_index))
+    }
+}
+
+impl<T> IndexMut<usize> for Array2<T> {
+    /// Returns a mutable slice of the underlying buffer with elements of the row at the given index.
+    ///
+    /// Panics if the index is out of bounds. See [`row_mut`](struct.Array2.html#method.row) for a non-panicking version.
+    fn index_mut(&mut self, row_index: usize) -> &mut Self::Output {
+        self.row_mut(row_index)
+            .unwrap_or_else(|| panic!("Row index {} is out of bounds", row_index))
+    }
+}
+
+impl<T> Index<Coord> for Array2<T> {
+    type Output = T;
+
+    /// Returns a reference to the element at the given coordinates.
+    ///
+    /// Panics if the coordinates are out of bounds. See [`get`](struct.Array2.html#method.get) for a non-panicking version.
+    fn index(&self, coord: Coord) -> &Self::Output {
+        self.get(coord.row, coord.col)
+            .unwrap_or_else(|| panic!("Coordinates {:?} are out of bounds", coord))
+    }
+}
+
+impl<T> IndexMut<Coord> for Array2<T> {
+    /// Returns a mutable reference to the element at the given coordinates.
+    ///
+    /// Panics if the coordinates are out of bounds. See [`get_mut`](struct.Array2.html#method.get_mut) for a non-panicking version.
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        self.get_mut(coord.row, coord.col)
+            .unwrap_or_else(|| panic!("Coordinates {:?} are out of bounds", coord))
+    }
+}
+
+macro_rules! impl_index_row_range {
+    ($($range:ty),*) => {$(
+        impl<T> Index<$range> for Array2<T> {
+            type Output = [T];
+
+            /// Returns a slice of the underlying buffer with elements of the rows in the given range.
+            ///
+            /// Panics if the range is out of bounds. See [`row_range`](struct.Array2.html#method.row_range)
+            /// for a non-panicking version that returns a view.
+            fn index(&self, row_indexes: $range) -> &Self::Output {
+                let num_rows = self.num_rows();
+                let rows = bounds_to_range(row_indexes, num_rows);
+                &self.data[rows.start * self.num_cols..rows.end * self.num_cols]
+            }
+        }
+
+        impl<T> IndexMut<$range> for Array2<T> {
+            /// Returns a mutable slice of the underlying buffer with elements of the rows in the given range.
+            ///
+            /// Panics if the range is out of bounds.
+            fn index_mut(&mut self, row_indexes: $range) -> &mut Self::Output {
+                let num_rows = self.num_rows();
+                let rows = bounds_to_range(row_indexes, num_rows);
+                &mut self.data[rows.start * self.num_cols..rows.end * self.num_cols]
+            }
+        }
+    )*};
+}
+
+// Indexing by a range of rows, e.g. `a2[1..3]`, returns the rows as one flat slice.
+impl_index_row_range!(
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeTo<usize>,
+    RangeInclusive<usize>,
+    RangeToInclusive<usize>,
+    RangeFull
+);
+
+/// Consumes the [`Array2`] and returns an iterator over its elements in row-major order.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::Array2;
+/// let a2 = Array2::new_from_rows([[1, 2], [3, 4]]);
+/// assert_eq!(a2.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+/// ```
+impl<T> IntoIterator for Array2<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_vec().into_iter()
+    }
+}
+
+/// Returns an iterator over references to elements in row-major order.
+impl<'a, T> IntoIterator for &'a Array2<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+/// Returns an iterator over mutable references to elements in row-major order.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::Array2;
+/// let mut a2 = Array2::new_from_rows([[1, 2], [3, 4]]);
+/// for elt in &mut a2 {
+///     *elt *= 10;
+/// }
+/// assert_eq!(a2, Array2::new_from_rows([[10, 20], [30, 40]]));
+/// ```
+impl<'a, T> IntoIterator for &'a mut Array2<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<T: bytemuck::Pod> Array2<T> {
+    /// Returns the underlying buffer (row-major order) as bytes, e.g. to upload it to a GPU buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2: Array2<u16> = Array2::new_from_rows([[1, 2], [3, 4]]);
+    /// assert_eq!(a2.as_bytes().len(), 8);
+    /// assert_eq!(a2.as_bytes()[..2], 1u16.to_ne_bytes());
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(&self.data)
+    }
+
+    /// Returns the underlying buffer (row-major order) as mutable bytes, e.g. to read a file into it.
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        bytemuck::cast_slice_mut(&mut self.data)
+    }
+
+    /// Creates an [`Array2`] with the given number of columns by copying elements from bytes
+    /// in native byte order, such as the output of [`as_bytes`](struct.Array2.html#method.as_bytes).
+    /// The bytes do not need to be aligned.
+    ///
+    /// Returns [`Array2Error::InvalidLength`] if the bytes cannot be split into rows, including when
+    /// `num_cols` is zero, or [`Array2Error::SizeOverflow`] with `num_rows` set to 1
+    /// if the length of a single row in bytes overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let a2: Array2<u32> = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(Array2::from_bytes(a2.as_bytes(), 3), Ok(a2));
+    /// assert_eq!(
+    ///     Array2::<u32>::from_bytes(&[0; 20], 3),
+    ///     Err(Array2Error::InvalidLength { len: 20, row_len: 12 })
+    /// );
+    /// assert_eq!(
+    ///     Array2::<u32>::from_bytes(&[0; 8], usize::MAX),
+    ///     Err(Array2Error::SizeOverflow { num_cols: usize::MAX, num_rows: 1 })
+    /// );
+    ///
+    /// let err = Array2::<u32>::from_bytes(&[0; 8], 0).unwrap_err();
+    /// assert_eq!(err.to_string(), "Length 8 cannot be split into rows of zero length");
+    /// ```
+    pub fn from_bytes(bytes: &[u8], num_cols: usize) -> Result<Self, Array2Error> {
+        let row_len = match num_cols.checked_mul(std::mem::size_of::<T>()) {
+            Some(row_len) => row_len,
+            None => {
+                return Err(Array2Error::SizeOverflow {
+                    num_cols,
+                    num_rows: 1,
+                })
+            }
+        };
+        if row_len == 0 || bytes.len() % row_len != 0 {
+            return Err(Array2Error::InvalidLength {
+                len: bytes.len(),
+                row_len,
+            });
+        }
+        let data = bytes
+            .chunks_exact(std::mem::size_of::<T>())
+            .map(bytemuck::pod_read_unaligned)
+            .collect();
+        Ok(Array2 { data, num_cols })
+    }
+
+    /// Reinterprets the elements as another plain-old-data type of the same size, reusing the allocation
+    /// if the alignments of the types are the same.
+    ///
+    /// Panics if the sizes of `T` and `U` differ.
+    /// See [`try_cast`](struct.Array2.html#method.try_cast) for a non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2: Array2<f32> = Array2::new_from_rows([[1.0, -2.0]]);
+    /// let bits: Array2<u32> = a2.cast();
+    /// assert_eq!(bits, Array2::new_from_rows([[0x3f80_0000, 0xc000_0000]]));
+    /// ```
+    pub fn cast<U: bytemuck::Pod>(self) -> Array2<U> {
+        assert!(
+            std::mem::size_of::<T>() == std::mem::size_of::<U>(),
+            "Cannot cast between element types of different sizes"
+        );
+        let data = match bytemuck::allocation::try_cast_slice_box(self.data) {
+            Ok(data) => data,
+            Err((_, data)) => data.iter().map(|&elt| bytemuck::cast(elt)).collect(),
+        };
+        Array2 {
+            data,
+            num_cols: self.num_cols,
+        }
+    }
+
+    /// Reinterprets the elements as another plain-old-data type of the same size
+    /// like [`cast`](struct.Array2.html#method.cast), or returns [`Array2Error::ElementSizeMismatch`]
+    /// if the sizes of `T` and `U` differ. The array is dropped in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error};
+    /// let a2: Array2<u16> = Array2::new_from_rows([[1, 2]]);
+    /// assert_eq!(a2.clone().try_cast::<i16>(), Ok(Array2::new_from_rows([[1, 2]])));
+    /// assert_eq!(a2.try_cast::<u32>(), Err(Array2Error::ElementSizeMismatch { size: 2, target_size: 4 }));
+    /// ```
+    pub fn try_cast<U: bytemuck::Pod>(self) -> Result<Array2<U>, Array2Error> {
+        let (size, target_size) = (std::mem::size_of::<T>(), std::mem::size_of::<U>());
+        if size != target_size {
+            return Err(Array2Error::ElementSizeMismatch { size, target_size });
+        }
+        Ok(self.cast())
+    }
+}
+
+/// Converts range bounds into a range within `0..len`,
+/// or returns None if the range is out of bounds or its start is greater than its end.
+/// Returns the number of elements of an array with the given dimensions,
+/// or panics with the message of [`Array2Error::SizeOverflow`] if it overflows `usize`.
+pub(crate) fn num_elements_or_panic(num_cols: usize, num_rows: usize) -> usize {
+    match num_cols.checked_mul(num_rows) {
+        Some(num_elements) => num_elements,
+        None => panic!("{}", Array2Error::SizeOverflow { num_cols, num_rows }),
+    }
+}
+
+pub(crate) fn try_bounds_to_range(
+    bounds: impl RangeBounds<usize>,
+    len: usize,
+) -> Option<Range<usize>> {
+    let start = match bounds.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match bounds.end_bound() {
+        Bound::Included(&end) => end.checked_add(1)?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    (start <= end && end <= len).then_some(start..end)
+}
+
+/// Converts range bounds into a range within `0..len`, or returns [`Array2Error::RangeOutOfBounds`]
+/// if the range is out of bounds or its start is greater than its end.
+fn checked_bounds_to_range(
+    bounds: impl RangeBounds<usize>,
+    len: usize,
+) -> Result<Range<usize>, Array2Error> {
+    try_bounds_to_range(bounds, len).ok_or(Array2Error::RangeOutOfBounds { len })
+}
+
+/// Returns [`Array2Error::EmptyWindow`] if a window or tile with the given dimensions has no elements.
+fn check_window(num_cols: usize, num_rows: usize) -> Result<(), Array2Error> {
+    if num_cols == 0 || num_rows == 0 {
+        return Err(Array2Error::EmptyWindow { num_cols, num_rows });
+    }
+    Ok(())
+}
+
+/// Converts range bounds into a range within `0..len`, panicking like slice indexing
+/// if the range is out of bounds or its start is greater than its end.
+pub(crate) fn bounds_to_range(bounds: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    try_bounds_to_range(bounds, len)
+        .unwrap_or_else(|| panic!("Range is out of bounds for length {}", len))
+}
+
+/// Converts range bounds into a range within `0..len`, clamping the bounds to `len`
+/// and returning an empty range if the start is greater than the end.
+fn clamp_bounds_to_range(bounds: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match bounds.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match bounds.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    let end = end.min(len);
+    start.min(end)..end
+}
+
+/// Clips a source range placed at `dst_start` to the destination `0..dst_len`,
+/// returning the source and destination ranges of equal length that overlap it.
+fn clip_axis(src: Range<usize>, dst_start: isize, dst_len: usize) -> (Range<usize>, Range<usize>) {
+    let skip = if dst_start < 0 {
+        dst_start.unsigned_abs()
+    } else {
+        0
+    };
+    let src_start = src.start.saturating_add(skip).min(src.end);
+    let dst_start = (dst_start.max(0) as usize).min(dst_len);
+    let len = (src.end - src_start).min(dst_len - dst_start);
+    (src_start..src_start + len, dst_start..dst_start + len)
+}
+
+/// Returns a sequence of swaps that replaces each element at index `i` by the element previously
+/// at `order[i]`, or an error if `order` is not a permutation of `0..len`.
+fn permutation_swaps(order: &[usize], len: usize) -> Result<Vec<(usize, usize)>, Array2Error> {
+    let invalid = Array2Error::InvalidPermutation { len };
+    if order.len() != len {
+        return Err(invalid);
+    }
+    let mut visited = vec![false; len];
+    for &i in order {
+        if i >= len || visited[i] {
+            return Err(invalid);
+        }
+        visited[i] = true;
+    }
+    visited.fill(false);
+    let mut swaps = Vec::new();
+    for start in 0..len {
+        let mut i = start;
+        while !visited[i] {
+            visited[i] = true;
+            let next = order[i];
+            if next == start {
+                break;
+            }
+            swaps.push((i, next));
+            i = next;
+        }
+    }
+    Ok(swaps)
+}
+
+/// Rearranges elements in place so that each `data[i]` is replaced by the element previously at `src(i)`.
+/// `src` must be a permutation of the indexes of `data`.
+pub(crate) fn permute_in_place<T>(data: &mut [T], src: impl Fn(usize) -> usize) {
+    let mut visited = vec![false; data.len()];
+    for start in 0..data.len() {
+        let mut i = start;
+        while !visited[i] {
+            visited[i] = true;
+            let next = src(i);
+            if next == start {
+                break;
+            }
+            data.swap(i, next);
+            i = next;
+        }
+    }
+}
+
+/// Serializes an [`Array2`] as a sequence of rows.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::Array2;
+/// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+/// assert_eq!(serde_json::to_string(&a2).unwrap(), "[[1,2,3],[4,5,6]]");
+/// ```
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Array2<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.rows())
+    }
+}
+
+/// Deserializes an [`Array2`] from a sequence of rows of equal length, such as a nested JSON array.
+/// Fails if the rows have different lengths, or if there are rows but they are all empty,
+/// since an array with no columns cannot have rows (see [`Array2Error::ZeroColumns`]).
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::Array2;
+/// let a2: Array2<u32> = serde_json::from_str("[[1, 2, 3], [4, 5, 6]]").unwrap();
+/// assert_eq!(a2, Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]));
+/// assert_eq!(serde_json::to_string(&a2).unwrap(), "[[1,2,3],[4,5,6]]");
+///
+/// let ragged = serde_json::from_str::<Array2<u32>>("[[1, 2, 3], [4, 5]]");
+/// assert!(ragged.unwrap_err().to_string().starts_with("row 1 has 2 elements, expected 3"));
+/// assert!(serde_json::from_str::<Array2<u32>>("[[], []]").is_err());
+/// assert_eq!(serde_json::from_str::<Array2<u32>>("[]").unwrap().num_elements(), 0);
+/// ```
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Array2<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(serde_rows::RowsVisitor(std::marker::PhantomData))
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_rows {
+    use super::Array2;
+    use serde::de::{DeserializeSeed, Deserializer, Error, SeqAccess, Visitor};
+
+    pub(super) struct RowsVisitor<T>(pub(super) std::marker::PhantomData<T>);
+
+    impl<'de, T: serde::Deserialize<'de>> Visitor<'de> for RowsVisitor<T> {
+        type Value = Array2<T>;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("a sequence of rows of equal length")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut data = Vec::new();
+            let mut num_cols = None;
+            let mut row_index = 0;
+            while let Some(row_len) = seq.next_element_seed(RowSeed(&mut data))? {
+                match num_cols {
+                    None => num_cols = Some(row_len),
+                    Some(n) if n != row_len => {
+                        return Err(A::Error::custom(format_args!(
+                            "row {} has {} elements, expected {}",
+                            row_index, row_len, n
+                        )))
+                    }
+                    Some(_) => {}
+                }
+                row_index += 1;
+            }
+            if num_cols == Some(0) {
+                return Err(A::Error::custom(format_args!(
+                    "{} rows have no elements, expected at least one",
+                    row_index
+                )));
+            }
+            Ok(Array2 {
+                data: data.into_boxed_slice(),
+                num_cols: num_cols.unwrap_or(0),
+            })
+        }
+    }
+
+    /// Appends elements of a row to the shared buffer, returning the number of elements in the row.
+    struct RowSeed<'a, T>(&'a mut Vec<T>);
+
+    impl<'de, T: serde::Deserialize<'de>> DeserializeSeed<'de> for RowSeed<'_, T> {
+        type Value = usize;
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+            deserializer.deserialize_seq(self)
+        }
+    }
+
+    impl<'de, T: serde::Deserialize<'de>> Visitor<'de> for RowSeed<'_, T> {
+        type Value = usize;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("a row of elements")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<usize, A::Error> {
+            let len_before = self.0.len();
+            while let Some(elt) = seq.next_element()? {
+                self.0.push(elt);
+            }
+            Ok(self.0.len() - len_before)
+        }
+    }
+}
+
+/// Generates an array with a random number of columns (at least one) and rows,
+/// filled with arbitrary elements.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::Array2;
+/// use arbitrary::{Arbitrary, Unstructured};
+/// let bytes: Vec<u8> = (0..64).collect();
+/// let a2 = Array2::<u16>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+/// assert!(a2.num_cols() > 0);
+/// assert_eq!(a2.num_elements(), a2.num_cols() * a2.num_rows());
+/// ```
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for Array2<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = u.arbitrary_len::<T>()?;
+        let num_cols = u.int_in_range(1..=len.max(1))?;
+        let data = (0..len / num_cols * num_cols)
+            .map(|_| T::arbitrary(u))
+            .collect::<arbitrary::Result<_>>()?;
+        Ok(Array2 { data, num_cols })
+    }
+}
+
+/// Generates an array with 1 to 16 columns and 0 to 16 rows, filled with elements generated
+/// by the element type's strategy. Shrinking reduces the dimensions as well as the elements.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::Array2;
+/// use proptest::prelude::*;
+/// proptest!(|(a2 in any::<Array2<i8>>())| {
+///     prop_assert_eq!(a2.rows().count(), a2.num_rows());
+/// });
+/// ```
+#[cfg(feature = "proptest")]
+impl<T: proptest::arbitrary::Arbitrary + 'static> proptest::arbitrary::Arbitrary for Array2<T> {
+    type Parameters = T::Parameters;
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+        let elt = proptest::arbitrary::any_with::<T>(params).boxed();
+        (1..=16usize, 0..=16usize)
+            .prop_flat_map(move |(num_cols, num_rows)| {
+                proptest::collection::vec(elt.clone(), num_cols * num_rows).prop_map(move |data| {
+                    Array2 {
+                        data: data.into_boxed_slice(),
+                        num_cols,
+                    }
+                })
+            })
+            .boxed()
     }
 }
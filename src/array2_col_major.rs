@@ -0,0 +1,359 @@
+use std::{
+    iter::FusedIterator,
+    ops::{Index, IndexMut},
+};
+
+use crate::{Array2, Coord, Shape};
+
+/// Fixed-size two-dimensional array stored as a flat boxed slice in column-major order.
+///
+/// This is the counterpart of [`Array2`] for algorithms that mostly access elements column by column:
+/// columns are contiguous slices of the underlying buffer, while rows are strided iterators.
+/// Both types implement [`Grid`](crate::Grid) for code that works with either layout.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{Array2, Array2ColMajor};
+/// let a2 = Array2ColMajor::from(Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]));
+/// assert_eq!(a2.col(1), Some(&[2, 5][..]));
+/// assert_eq!(a2.row(1).map(|r| r.copied().collect()), Some(vec![4, 5, 6]));
+/// assert_eq!(a2.elements(), [1, 4, 2, 5, 3, 6]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Array2ColMajor<T>(Array2<T>);
+
+impl<T> Array2ColMajor<T> {
+    /// Creates an [`Array2ColMajor`] of the given dimensions with all elements set to the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2ColMajor;
+    /// let a2 = Array2ColMajor::new(3, 2, 0);
+    /// assert_eq!((a2.num_cols(), a2.num_rows()), (3, 2));
+    /// assert_eq!(a2.elements(), [0; 6]);
+    /// ```
+    pub fn new(num_cols: usize, num_rows: usize, init_value: T) -> Self
+    where
+        T: Clone,
+    {
+        Array2ColMajor(Array2::new(num_rows, num_cols, init_value))
+    }
+
+    /// Creates an [`Array2ColMajor`] from an iterator over columns.
+    ///
+    /// Panics if the columns have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2ColMajor;
+    /// let a2 = Array2ColMajor::new_from_cols([[1, 4], [2, 5], [3, 6]]);
+    /// assert_eq!((a2.num_cols(), a2.num_rows()), (3, 2));
+    /// assert_eq!(a2.get(1, 0), Some(&4));
+    /// ```
+    pub fn new_from_cols(
+        cols: impl IntoIterator<
+            Item = impl IntoIterator<Item = T, IntoIter = impl ExactSizeIterator<Item = T>>,
+        >,
+    ) -> Self {
+        Array2ColMajor(Array2::new_from_rows(cols))
+    }
+
+    /// Returns the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2ColMajor;
+    /// let a2 = Array2ColMajor::new_from_cols([[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(a2.num_cols(), 3);
+    /// ```
+    pub const fn num_cols(&self) -> usize {
+        self.0.num_rows()
+    }
+
+    /// Returns the number of rows (elements per column).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2ColMajor;
+    /// let a2 = Array2ColMajor::new_from_cols([[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(a2.num_rows(), 2);
+    /// ```
+    pub const fn num_rows(&self) -> usize {
+        self.0.num_cols()
+    }
+
+    /// Returns the number of elements across all columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2ColMajor;
+    /// let a2 = Array2ColMajor::new_from_cols([[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(a2.num_elements(), 6);
+    /// ```
+    pub const fn num_elements(&self) -> usize {
+        self.0.num_elements()
+    }
+
+    /// Returns the number of rows and columns as a [`Shape`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2ColMajor, Shape};
+    /// let a2 = Array2ColMajor::new_from_cols([[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(a2.shape(), Shape { rows: 2, cols: 3 });
+    /// ```
+    pub const fn shape(&self) -> Shape {
+        Shape::new(self.num_rows(), self.num_cols())
+    }
+
+    /// Returns a slice of the underlying buffer (column-major order).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2ColMajor;
+    /// let a2 = Array2ColMajor::new_from_cols([[1, 2], [3, 4]]);
+    /// assert_eq!(a2.elements(), [1, 2, 3, 4]);
+    /// ```
+    pub const fn elements(&self) -> &[T] {
+        self.0.elements()
+    }
+
+    /// Returns a mutable slice of the underlying buffer (column-major order).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2ColMajor;
+    /// let mut a2 = Array2ColMajor::new_from_cols([[1, 2], [3, 4]]);
+    /// a2.elements_mut()[1] = 20;
+    /// assert_eq!(a2.get(1, 0), Some(&20));
+    /// ```
+    pub fn elements_mut(&mut self) -> &mut [T] {
+        self.0.elements_mut()
+    }
+
+    /// Returns a reference to the element at the given row and column,
+    /// or None if either index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2ColMajor;
+    /// let a2 = Array2ColMajor::new_from_cols([[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(a2.get(1, 2), Some(&6));
+    /// assert_eq!(a2.get(2, 0), None);
+    /// ```
+    pub fn get(&self, row_index: usize, col_index: usize) -> Option<&T> {
+        self.0.get(col_index, row_index)
+    }
+
+    /// Returns a mutable reference to the element at the given row and column,
+    /// or None if either index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2ColMajor;
+    /// let mut a2 = Array2ColMajor::new_from_cols([[1, 2], [3, 4]]);
+    /// *a2.get_mut(0, 1).unwrap() = 30;
+    /// assert_eq!(a2.elements(), [1, 2, 30, 4]);
+    /// ```
+    pub fn get_mut(&mut self, row_index: usize, col_index: usize) -> Option<&mut T> {
+        self.0.get_mut(col_index, row_index)
+    }
+
+    /// Returns a slice of the underlying buffer with elements of the column
+    /// at the given index, or None if the column index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2ColMajor;
+    /// let a2 = Array2ColMajor::new_from_cols([[1, 2], [3, 4]]);
+    /// assert_eq!(a2.col(1), Some(&[3, 4][..]));
+    /// assert_eq!(a2.col(2), None);
+    /// ```
+    pub fn col(&self, col_index: usize) -> Option<&[T]> {
+        self.0.row(col_index)
+    }
+
+    /// Returns a mutable slice of the underlying buffer with elements of the column
+    /// at the given index, or None if the column index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2ColMajor;
+    /// let mut a2 = Array2ColMajor::new_from_cols([[3, 1, 2], [6, 5, 4]]);
+    /// a2.col_mut(0).unwrap().sort();
+    /// assert_eq!(a2.col(0), Some(&[1, 2, 3][..]));
+    /// ```
+    pub fn col_mut(&mut self, col_index: usize) -> Option<&mut [T]> {
+        self.0.row_mut(col_index)
+    }
+
+    /// Returns an iterator over columns. Each item is a slice of all elements
+    /// in the corresponding column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2ColMajor;
+    /// let a2 = Array2ColMajor::new_from_cols([[1, 2], [3, 4], [5, 6]]);
+    /// let sums: Vec<i32> = a2.cols().map(|col| col.iter().sum()).collect();
+    /// assert_eq!(sums, vec![3, 7, 11]);
+    /// ```
+    pub fn cols(
+        &self,
+    ) -> impl ExactSizeIterator<Item = &[T]> + DoubleEndedIterator + FusedIterator {
+        self.0.rows()
+    }
+
+    /// Returns an iterator over elements of the row at the given index,
+    /// or None if the row index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2ColMajor;
+    /// let a2 = Array2ColMajor::new_from_cols([[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(a2.row(1).unwrap().copied().collect::<Vec<_>>(), vec![2, 4, 6]);
+    /// assert!(a2.row(2).is_none());
+    /// ```
+    pub fn row(
+        &self,
+        row_index: usize,
+    ) -> Option<impl ExactSizeIterator<Item = &T> + DoubleEndedIterator> {
+        self.0.col(row_index)
+    }
+
+    /// Returns an iterator over mutable references to elements of the row
+    /// at the given index, or None if the row index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2ColMajor;
+    /// let mut a2 = Array2ColMajor::new_from_cols([[1, 2], [3, 4]]);
+    /// a2.row_mut(0).unwrap().for_each(|elt| *elt = 0);
+    /// assert_eq!(a2.elements(), [0, 2, 0, 4]);
+    /// ```
+    pub fn row_mut(
+        &mut self,
+        row_index: usize,
+    ) -> Option<impl ExactSizeIterator<Item = &mut T> + DoubleEndedIterator> {
+        self.0.col_mut(row_index)
+    }
+
+    /// Returns an iterator over rows.
+    /// Each item is an iterator over elements of the corresponding row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2ColMajor;
+    /// let a2 = Array2ColMajor::new_from_cols([[1, 4], [2, 5], [3, 6]]);
+    /// let rows: Vec<Vec<i32>> = a2.rows().map(|r| r.copied().collect()).collect();
+    /// assert_eq!(rows, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// ```
+    pub fn rows(
+        &self,
+    ) -> impl ExactSizeIterator<Item = impl ExactSizeIterator<Item = &T> + DoubleEndedIterator>
+           + DoubleEndedIterator
+           + FusedIterator {
+        self.0.cols()
+    }
+
+    /// Returns a new [`Array2ColMajor`] of the same dimensions as this array,
+    /// with function `f` applied to each element in column-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2ColMajor;
+    /// let a2 = Array2ColMajor::new_from_cols([[1, 2], [3, 4]]);
+    /// assert_eq!(a2.map(|&elt| elt * 10), Array2ColMajor::new_from_cols([[10, 20], [30, 40]]));
+    /// ```
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Array2ColMajor<U> {
+        Array2ColMajor(self.0.map(f))
+    }
+}
+
+/// Converts a row-major [`Array2`] into an [`Array2ColMajor`] with the same rows and columns,
+/// rearranging the elements in place.
+impl<T> From<Array2<T>> for Array2ColMajor<T> {
+    fn from(mut value: Array2<T>) -> Self {
+        value.transpose();
+        Array2ColMajor(value)
+    }
+}
+
+/// Converts an [`Array2ColMajor`] into a row-major [`Array2`] with the same rows and columns,
+/// rearranging the elements in place.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{Array2, Array2ColMajor};
+/// let a2 = Array2ColMajor::new_from_cols([[1, 4], [2, 5], [3, 6]]);
+/// assert_eq!(Array2::from(a2), Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]));
+/// ```
+impl<T> From<Array2ColMajor<T>> for Array2<T> {
+    fn from(value: Array2ColMajor<T>) -> Self {
+        let mut array = value.0;
+        array.transpose();
+        array
+    }
+}
+
+impl<T> Index<usize> for Array2ColMajor<T> {
+    type Output = [T];
+
+    /// Returns a slice of the underlying buffer with elements of the column at the given index.
+    ///
+    /// Panics if the index is out of bounds. See [`col`](struct.Array2ColMajor.html#method.col) for a non-panicking version.
+    fn index(&self, col_index: usize) -> &Self::Output {
+        self.col(col_index)
+            .unwrap_or_else(|| panic!("Column index {} is out of bounds", col_index))
+    }
+}
+
+impl<T> IndexMut<usize> for Array2ColMajor<T> {
+    /// Returns a mutable slice of the underlying buffer with elements of the column at the given index.
+    ///
+    /// Panics if the index is out of bounds. See [`col_mut`](struct.Array2ColMajor.html#method.col_mut) for a non-panicking version.
+    fn index_mut(&mut self, col_index: usize) -> &mut Self::Output {
+        self.col_mut(col_index)
+            .unwrap_or_else(|| panic!("Column index {} is out of bounds", col_index))
+    }
+}
+
+impl<T> Index<Coord> for Array2ColMajor<T> {
+    type Output = T;
+
+    /// Returns a reference to the element at the given coordinates.
+    ///
+    /// Panics if the coordinates are out of bounds. See [`get`](struct.Array2ColMajor.html#method.get) for a non-panicking version.
+    fn index(&self, coord: Coord) -> &Self::Output {
+        self.get(coord.row, coord.col)
+            .unwrap_or_else(|| panic!("Coordinates {:?} are out of bounds", coord))
+    }
+}
+
+impl<T> IndexMut<Coord> for Array2ColMajor<T> {
+    /// Returns a mutable reference to the element at the given coordinates.
+    ///
+    /// Panics if the coordinates are out of bounds. See [`get_mut`](struct.Array2ColMajor.html#method.get_mut) for a non-panicking version.
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        self.get_mut(coord.row, coord.col)
+            .unwrap_or_else(|| panic!("Coordinates {:?} are out of bounds", coord))
+    }
+}
@@ -0,0 +1,277 @@
+use std::ops::{Index, IndexMut};
+
+use crate::Array2;
+
+/// Mutable view of a single column of an [`Array2`], returned by
+/// [`col_view_mut`](struct.Array2.html#method.col_view_mut).
+///
+/// Elements of a column are not contiguous in the row-major buffer, so the view accesses them
+/// with a stride equal to the number of columns. Element indexes are row indexes.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::Array2;
+/// let mut a2 = Array2::new_from_rows([[1, 9], [2, 8], [3, 7]]);
+/// let mut col = a2.col_view_mut(1).unwrap();
+/// assert_eq!(col.len(), 3);
+/// col.swap(0, 2);
+/// col[1] = 0;
+/// assert_eq!(col.to_vec(), vec![7, 0, 9]);
+/// assert_eq!(a2, Array2::new_from_rows([[1, 7], [2, 0], [3, 9]]));
+/// ```
+pub struct ColMut<'a, T> {
+    data: &'a mut [T],
+    stride: usize,
+    len: usize,
+}
+
+impl<'a, T> ColMut<'a, T> {
+    /// Creates a view of the column of `array` at the given index, which must be within bounds.
+    pub(crate) fn new(array: &'a mut Array2<T>, col_index: usize) -> Self {
+        let (stride, len) = (array.num_cols(), array.num_rows());
+        let data = match len {
+            0 => &mut [],
+            _ => &mut array.elements_mut()[col_index..],
+        };
+        ColMut { data, stride, len }
+    }
+
+    /// Returns the number of elements in the column, which is the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(a2.col_view_mut(0).unwrap().len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the column has no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2: Array2<u8> = Array2::new(2, 0, 0);
+    /// assert!(a2.col_view_mut(1).unwrap().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the element in the given row, or None if the row index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2], [3, 4]]);
+    /// let col = a2.col_view_mut(1).unwrap();
+    /// assert_eq!(col.get(1), Some(&4));
+    /// assert_eq!(col.get(2), None);
+    /// ```
+    pub fn get(&self, row_index: usize) -> Option<&T> {
+        if row_index < self.len {
+            Some(&self.data[row_index * self.stride])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element in the given row,
+    /// or None if the row index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2], [3, 4]]);
+    /// let mut col = a2.col_view_mut(0).unwrap();
+    /// *col.get_mut(1).unwrap() *= 10;
+    /// assert!(col.get_mut(2).is_none());
+    /// assert_eq!(a2, Array2::new_from_rows([[1, 2], [30, 4]]));
+    /// ```
+    pub fn get_mut(&mut self, row_index: usize) -> Option<&mut T> {
+        if row_index < self.len {
+            Some(&mut self.data[row_index * self.stride])
+        } else {
+            None
+        }
+    }
+
+    /// Swaps the elements in rows `a` and `b`.
+    ///
+    /// Panics if either row index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2], [3, 4], [5, 6]]);
+    /// a2.col_view_mut(1).unwrap().swap(0, 2);
+    /// assert_eq!(a2, Array2::new_from_rows([[1, 6], [3, 4], [5, 2]]));
+    /// ```
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(
+            a < self.len && b < self.len,
+            "Row index {} is out of bounds",
+            a.max(b)
+        );
+        self.data.swap(a * self.stride, b * self.stride);
+    }
+
+    /// Returns an iterator over the elements from the first row to the last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2], [3, 4], [5, 6]]);
+    /// let col = a2.col_view_mut(1).unwrap();
+    /// assert_eq!(col.iter().sum::<i32>(), 12);
+    /// assert_eq!(col.iter().rev().next(), Some(&6));
+    /// ```
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &T> + DoubleEndedIterator {
+        self.data.iter().step_by(self.stride)
+    }
+
+    /// Returns an iterator over mutable references to the elements from the first row to the last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2], [3, 4]]);
+    /// for (i, element) in a2.col_view_mut(0).unwrap().iter_mut().enumerate() {
+    ///     *element += i * 100;
+    /// }
+    /// assert_eq!(a2, Array2::new_from_rows([[1, 2], [103, 4]]));
+    /// ```
+    pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = &mut T> + DoubleEndedIterator {
+        self.data.iter_mut().step_by(self.stride)
+    }
+
+    /// Sets all elements of the column to the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([['a', 'b'], ['c', 'd']]);
+    /// a2.col_view_mut(0).unwrap().fill('-');
+    /// assert_eq!(a2, Array2::new_from_rows([['-', 'b'], ['-', 'd']]));
+    /// ```
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.iter_mut().for_each(|element| *element = value.clone());
+    }
+
+    /// Overwrites the elements of the column with the items of the iterator,
+    /// from the first row to the last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2], [3, 4]]);
+    /// a2.col_view_mut(0).unwrap().copy_from_iter([5, 6]);
+    /// assert_eq!(a2, Array2::new_from_rows([[5, 2], [6, 4]]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator does not yield exactly as many items as there are rows.
+    /// The elements written before the mismatch is detected are not restored.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2], [3, 4]]);
+    /// a2.col_view_mut(0).unwrap().copy_from_iter([5, 6, 7]);
+    /// ```
+    pub fn copy_from_iter(&mut self, items: impl IntoIterator<Item = T>) {
+        let len = self.len;
+        let mut items = items.into_iter();
+        let mut count = 0;
+        for (element, item) in self.iter_mut().zip(items.by_ref()) {
+            *element = item;
+            count += 1;
+        }
+        assert!(
+            count == len && items.next().is_none(),
+            "Iterator length must be equal to the number of rows ({})",
+            len
+        );
+    }
+
+    /// Returns a [`Vec`] with a copy of the elements of the column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2], [3, 4]]);
+    /// assert_eq!(a2.col_view_mut(1).unwrap().to_vec(), vec![2, 4]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for ColMut<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Index<usize> for ColMut<'_, T> {
+    type Output = T;
+
+    /// Returns a reference to the element in the given row.
+    ///
+    /// Panics if the index is out of bounds. See [`get`](struct.ColMut.html#method.get) for a non-panicking version.
+    fn index(&self, row_index: usize) -> &Self::Output {
+        self.get(row_index)
+            .unwrap_or_else(|| panic!("Row index {} is out of bounds", row_index))
+    }
+}
+
+impl<T> IndexMut<usize> for ColMut<'_, T> {
+    /// Returns a mutable reference to the element in the given row.
+    ///
+    /// Panics if the index is out of bounds. See [`get_mut`](struct.ColMut.html#method.get_mut) for a non-panicking version.
+    fn index_mut(&mut self, row_index: usize) -> &mut Self::Output {
+        self.get_mut(row_index)
+            .unwrap_or_else(|| panic!("Row index {} is out of bounds", row_index))
+    }
+}
+
+impl<T> Array2<T> {
+    /// Returns a mutable view of the column at the given index with slice-like operations,
+    /// or None if the column index is out of bounds. See [`ColMut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// a2.col_view_mut(2).unwrap().fill(0);
+    /// assert_eq!(a2, Array2::new_from_rows([[1, 2, 0], [4, 5, 0]]));
+    /// assert!(a2.col_view_mut(3).is_none());
+    /// ```
+    pub fn col_view_mut(&mut self, col_index: usize) -> Option<ColMut<'_, T>> {
+        if col_index < self.num_cols() {
+            Some(ColMut::new(self, col_index))
+        } else {
+            None
+        }
+    }
+}
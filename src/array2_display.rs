@@ -0,0 +1,91 @@
+use std::fmt::{self, Display, Write};
+
+use crate::Array2;
+
+/// Configurable [`Display`] adapter for an [`Array2`], returned by [`Array2::display`].
+///
+/// Renders the array row by row, one line per row, with the elements of each column
+/// right-aligned to the widest element in that column. The precision of the format string,
+/// e.g. `{:.2}`, is applied to each element.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::Array2;
+/// let a2 = Array2::new_from_rows([[1.0, -2.5], [10.25, 4.0]]);
+/// assert_eq!(a2.to_string(), "    1 -2.5\n10.25    4");
+/// assert_eq!(format!("{:.1}", a2.display().separator(" | ")), " 1.0 | -2.5\n10.2 |  4.0");
+/// assert_eq!(a2.display().separator(",").min_width(6).to_string(), "     1,  -2.5\n 10.25,     4");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Array2Display<'a, T> {
+    array: &'a Array2<T>,
+    separator: &'a str,
+    min_width: usize,
+}
+
+impl<'a, T> Array2Display<'a, T> {
+    pub(crate) fn new(array: &'a Array2<T>) -> Self {
+        Array2Display {
+            array,
+            separator: " ",
+            min_width: 0,
+        }
+    }
+
+    /// Sets the string placed between elements of a row. Defaults to a single space.
+    pub fn separator(self, separator: &'a str) -> Self {
+        Array2Display { separator, ..self }
+    }
+
+    /// Sets the minimum width of each column. Defaults to zero, i.e. the width of the widest element.
+    pub fn min_width(self, min_width: usize) -> Self {
+        Array2Display { min_width, ..self }
+    }
+}
+
+impl<T: Display> Display for Array2Display<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let num_cols = self.array.num_cols();
+        let mut cells = Vec::with_capacity(self.array.num_elements());
+        for elt in self.array.elements() {
+            let mut cell = String::new();
+            match f.precision() {
+                Some(precision) => write!(cell, "{:.*}", precision, elt)?,
+                None => write!(cell, "{}", elt)?,
+            }
+            cells.push(cell);
+        }
+        let mut widths = vec![self.min_width; num_cols];
+        for (i, cell) in cells.iter().enumerate() {
+            let width = &mut widths[i % num_cols];
+            *width = (*width).max(cell.chars().count());
+        }
+        for (i, cell) in cells.iter().enumerate() {
+            let col = i % num_cols;
+            if col > 0 {
+                f.write_str(self.separator)?;
+            } else if i > 0 {
+                f.write_char('\n')?;
+            }
+            write!(f, "{:>width$}", cell, width = widths[col])?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Array2<T> {
+    /// Returns an adapter for rendering the array as a grid with aligned columns,
+    /// with a configurable separator and column width.
+    /// The [`Display`] implementation of [`Array2`] uses the default settings.
+    pub fn display(&self) -> Array2Display<'_, T> {
+        Array2Display::new(self)
+    }
+}
+
+/// Renders the array row by row with aligned columns, see [`Array2Display`].
+impl<T: Display> Display for Array2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.display().fmt(f)
+    }
+}
@@ -0,0 +1,347 @@
+use std::{
+    iter::FusedIterator,
+    ops::{Index, IndexMut},
+};
+
+use crate::{Array2, Array2Error, Shape};
+
+/// Two-dimensional array with `R` rows and `C` columns known at compile time,
+/// stored inline as `[[T; C]; R]` without a heap allocation.
+///
+/// Use [`Array2`] for arrays whose dimensions are only known at runtime;
+/// the two can be converted into each other.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{Array2, Array2Fixed};
+/// let mut kernel: Array2Fixed<i32, 3, 3> = Array2Fixed::new(0);
+/// kernel[1] = [1, -4, 1];
+/// kernel[0][1] = 1;
+/// kernel[2][1] = 1;
+/// assert_eq!(kernel.col(1).unwrap().copied().collect::<Vec<_>>(), vec![1, -4, 1]);
+///
+/// let a2: Array2<i32> = kernel.into();
+/// assert_eq!(a2, Array2::new_from_rows([[0, 1, 0], [1, -4, 1], [0, 1, 0]]));
+/// assert_eq!(Array2Fixed::try_from(a2), Ok(kernel));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Array2Fixed<T, const R: usize, const C: usize> {
+    data: [[T; C]; R],
+}
+
+impl<T, const R: usize, const C: usize> Array2Fixed<T, R, C> {
+    /// Creates an [`Array2Fixed`] with all elements set to the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2Fixed;
+    /// let a2: Array2Fixed<char, 2, 3> = Array2Fixed::new('.');
+    /// assert_eq!(a2.into_rows(), [['.'; 3]; 2]);
+    /// ```
+    pub fn new(init_value: T) -> Self
+    where
+        T: Clone,
+    {
+        Array2Fixed {
+            data: std::array::from_fn(|_| std::array::from_fn(|_| init_value.clone())),
+        }
+    }
+
+    /// Creates an [`Array2Fixed`] from an array of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2Fixed;
+    /// let a2 = Array2Fixed::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!((a2.num_cols(), a2.num_rows()), (3, 2));
+    /// ```
+    pub const fn from_rows(rows: [[T; C]; R]) -> Self {
+        Array2Fixed { data: rows }
+    }
+
+    /// Returns the number of columns (elements per row).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2Fixed;
+    /// let a2: Array2Fixed<u8, 2, 5> = Array2Fixed::new(0);
+    /// assert_eq!(a2.num_cols(), 5);
+    /// ```
+    pub const fn num_cols(&self) -> usize {
+        C
+    }
+
+    /// Returns the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2Fixed;
+    /// let a2: Array2Fixed<u8, 2, 5> = Array2Fixed::new(0);
+    /// assert_eq!(a2.num_rows(), 2);
+    /// ```
+    pub const fn num_rows(&self) -> usize {
+        R
+    }
+
+    /// Returns the number of rows and columns as a [`Shape`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2Fixed, Shape};
+    /// let a2 = Array2Fixed::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a2.shape(), Shape { rows: 2, cols: 3 });
+    /// ```
+    pub const fn shape(&self) -> Shape {
+        Shape::new(R, C)
+    }
+
+    /// Returns a slice of all elements (row-major order).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2Fixed;
+    /// let a2 = Array2Fixed::from_rows([[1, 2], [3, 4]]);
+    /// assert_eq!(a2.elements(), [1, 2, 3, 4]);
+    /// ```
+    pub fn elements(&self) -> &[T] {
+        self.data.as_flattened()
+    }
+
+    /// Returns a mutable slice of all elements (row-major order).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2Fixed;
+    /// let mut a2 = Array2Fixed::from_rows([[1, 2], [3, 4]]);
+    /// a2.elements_mut().reverse();
+    /// assert_eq!(a2.into_rows(), [[4, 3], [2, 1]]);
+    /// ```
+    pub fn elements_mut(&mut self) -> &mut [T] {
+        self.data.as_flattened_mut()
+    }
+
+    /// Returns a reference to the element at the given row and column,
+    /// or None if either index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2Fixed;
+    /// let a2 = Array2Fixed::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a2.get(1, 2), Some(&6));
+    /// assert_eq!(a2.get(2, 0), None);
+    /// assert_eq!(a2.get(0, 3), None);
+    /// ```
+    pub fn get(&self, row_index: usize, col_index: usize) -> Option<&T> {
+        self.data.get(row_index)?.get(col_index)
+    }
+
+    /// Returns a mutable reference to the element at the given row and column,
+    /// or None if either index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2Fixed;
+    /// let mut a2 = Array2Fixed::from_rows([[1, 2], [3, 4]]);
+    /// *a2.get_mut(0, 1).unwrap() = 20;
+    /// assert_eq!(a2.into_rows(), [[1, 20], [3, 4]]);
+    /// ```
+    pub fn get_mut(&mut self, row_index: usize, col_index: usize) -> Option<&mut T> {
+        self.data.get_mut(row_index)?.get_mut(col_index)
+    }
+
+    /// Returns the row at the given index, or None if the row index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2Fixed;
+    /// let a2 = Array2Fixed::from_rows([[1, 2], [3, 4]]);
+    /// assert_eq!(a2.row(1), Some(&[3, 4]));
+    /// assert_eq!(a2.row(2), None);
+    /// ```
+    pub fn row(&self, row_index: usize) -> Option<&[T; C]> {
+        self.data.get(row_index)
+    }
+
+    /// Returns a mutable reference to the row at the given index, or None if the row index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2Fixed;
+    /// let mut a2 = Array2Fixed::from_rows([[1, 2], [3, 4]]);
+    /// *a2.row_mut(0).unwrap() = [0, 0];
+    /// assert_eq!(a2.into_rows(), [[0, 0], [3, 4]]);
+    /// ```
+    pub fn row_mut(&mut self, row_index: usize) -> Option<&mut [T; C]> {
+        self.data.get_mut(row_index)
+    }
+
+    /// Returns an iterator over rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2Fixed;
+    /// let a2 = Array2Fixed::from_rows([[1, 2], [3, 4], [5, 6]]);
+    /// let sums: Vec<i32> = a2.rows().map(|row| row.iter().sum()).collect();
+    /// assert_eq!(sums, vec![3, 7, 11]);
+    /// ```
+    pub fn rows(
+        &self,
+    ) -> impl ExactSizeIterator<Item = &[T; C]> + DoubleEndedIterator + FusedIterator {
+        self.data.iter()
+    }
+
+    /// Returns an iterator over elements of the column at the given index,
+    /// or None if the column index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2Fixed;
+    /// let a2 = Array2Fixed::from_rows([[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(a2.col(1).unwrap().copied().collect::<Vec<_>>(), vec![2, 4, 6]);
+    /// assert!(a2.col(2).is_none());
+    /// ```
+    pub fn col(
+        &self,
+        col_index: usize,
+    ) -> Option<impl ExactSizeIterator<Item = &T> + DoubleEndedIterator + FusedIterator> {
+        if col_index < C {
+            Some(self.data.iter().map(move |row| &row[col_index]))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over mutable references to elements of the column
+    /// at the given index, or None if the column index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2Fixed;
+    /// let mut a2 = Array2Fixed::from_rows([[1, 2], [3, 4]]);
+    /// a2.col_mut(0).unwrap().for_each(|elt| *elt *= -1);
+    /// assert_eq!(a2.into_rows(), [[-1, 2], [-3, 4]]);
+    /// ```
+    pub fn col_mut(
+        &mut self,
+        col_index: usize,
+    ) -> Option<impl ExactSizeIterator<Item = &mut T> + DoubleEndedIterator + FusedIterator> {
+        if col_index < C {
+            Some(self.data.iter_mut().map(move |row| &mut row[col_index]))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a new [`Array2Fixed`] of the same dimensions as this array,
+    /// with function `f` applied to each element in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2Fixed;
+    /// let a2 = Array2Fixed::from_rows([[1, 2], [3, 4]]);
+    /// assert_eq!(a2.map(|&elt| elt * 10), Array2Fixed::from_rows([[10, 20], [30, 40]]));
+    /// ```
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Array2Fixed<U, R, C> {
+        Array2Fixed {
+            data: std::array::from_fn(|r| std::array::from_fn(|c| f(&self.data[r][c]))),
+        }
+    }
+
+    /// Consumes the [`Array2Fixed`] and returns the array of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2Fixed;
+    /// let a2 = Array2Fixed::from_rows([[1, 2], [3, 4]]);
+    /// let [first, second] = a2.into_rows();
+    /// assert_eq!((first, second), ([1, 2], [3, 4]));
+    /// ```
+    pub fn into_rows(self) -> [[T; C]; R] {
+        self.data
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Array2Fixed<T, R, C> {
+    fn from(rows: [[T; C]; R]) -> Self {
+        Array2Fixed::from_rows(rows)
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<Array2Fixed<T, R, C>> for Array2<T> {
+    fn from(value: Array2Fixed<T, R, C>) -> Self {
+        Array2 {
+            data: value.data.into_iter().flatten().collect(),
+            num_cols: C,
+        }
+    }
+}
+
+/// Converts an [`Array2`] with `R` rows and `C` columns into an [`Array2Fixed`],
+/// returning [`Array2Error::ShapeMismatch`] if the dimensions differ.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{Array2, Array2Error, Array2Fixed, Shape};
+/// let a2 = Array2::new_from_rows([[1, 2, 3]]);
+/// assert_eq!(
+///     Array2Fixed::<_, 3, 1>::try_from(a2),
+///     Err(Array2Error::ShapeMismatch { expected: Shape { rows: 3, cols: 1 }, found: Shape { rows: 1, cols: 3 } })
+/// );
+/// ```
+impl<T, const R: usize, const C: usize> TryFrom<Array2<T>> for Array2Fixed<T, R, C> {
+    type Error = Array2Error;
+
+    fn try_from(value: Array2<T>) -> Result<Self, Self::Error> {
+        let found = value.shape();
+        if found != Shape::new(R, C) {
+            return Err(Array2Error::ShapeMismatch {
+                expected: Shape::new(R, C),
+                found,
+            });
+        }
+        let mut elements = value.data.into_vec().into_iter();
+        Ok(Array2Fixed {
+            data: std::array::from_fn(|_| {
+                std::array::from_fn(|_| elements.next().expect("length was checked"))
+            }),
+        })
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<usize> for Array2Fixed<T, R, C> {
+    type Output = [T; C];
+
+    /// Returns the row at the given index.
+    ///
+    /// Panics if the index is out of bounds.
+    fn index(&self, row_index: usize) -> &Self::Output {
+        &self.data[row_index]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<usize> for Array2Fixed<T, R, C> {
+    /// Returns a mutable reference to the row at the given index.
+    ///
+    /// Panics if the index is out of bounds.
+    fn index_mut(&mut self, row_index: usize) -> &mut Self::Output {
+        &mut self.data[row_index]
+    }
+}
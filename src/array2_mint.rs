@@ -0,0 +1,72 @@
+use crate::{Array2, Array2Error, Array2Fixed};
+
+/// Implements conversions between [`Array2`] and the row-major and column-major
+/// [`mint`] matrix types with the given number of rows and columns.
+macro_rules! impl_mint_conversions {
+    ($($(#[$attr:meta])* $row_major:ident, $col_major:ident, $rows:literal, $cols:literal;)*) => {$(
+        $(#[$attr])*
+        impl<T> From<mint::$row_major<T>> for Array2<T> {
+            fn from(value: mint::$row_major<T>) -> Self {
+                Array2Fixed::from_rows(<[[T; $cols]; $rows]>::from(value)).into()
+            }
+        }
+
+        impl<T> From<mint::$col_major<T>> for Array2<T> {
+            fn from(value: mint::$col_major<T>) -> Self {
+                mint::$row_major::from(value).into()
+            }
+        }
+
+        /// Returns [`Array2Error::ShapeMismatch`] if the dimensions differ.
+        impl<T> TryFrom<Array2<T>> for mint::$row_major<T> {
+            type Error = Array2Error;
+
+            fn try_from(value: Array2<T>) -> Result<Self, Self::Error> {
+                let fixed: Array2Fixed<T, $rows, $cols> = value.try_into()?;
+                Ok(fixed.into_rows().into())
+            }
+        }
+
+        /// Returns [`Array2Error::ShapeMismatch`] if the dimensions differ.
+        impl<T> TryFrom<Array2<T>> for mint::$col_major<T> {
+            type Error = Array2Error;
+
+            fn try_from(value: Array2<T>) -> Result<Self, Self::Error> {
+                mint::$row_major::try_from(value).map(Into::into)
+            }
+        }
+    )*};
+}
+
+impl_mint_conversions! {
+    RowMatrix2, ColumnMatrix2, 2, 2;
+    /// Converts a [`mint`] matrix into an [`Array2`]. The `RxC` suffix of the matrix types
+    /// is the number of rows and columns.
+    ///
+    /// Matrices of both row-major and column-major types can be converted back from
+    /// an [`Array2`] with the same number of rows and columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error, Shape};
+    /// let m = mint::RowMatrix2x3::from([[1, 2, 3], [4, 5, 6]]);
+    /// let a2 = Array2::from(m);
+    /// assert_eq!(a2, Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]));
+    ///
+    /// let c = mint::ColumnMatrix2x3::try_from(a2.clone()).unwrap();
+    /// assert_eq!(c.x, mint::Vector2 { x: 1, y: 4 });
+    /// assert_eq!(
+    ///     mint::RowMatrix3::try_from(a2).unwrap_err(),
+    ///     Array2Error::ShapeMismatch { expected: Shape { rows: 3, cols: 3 }, found: Shape { rows: 2, cols: 3 } }
+    /// );
+    /// ```
+    RowMatrix2x3, ColumnMatrix2x3, 2, 3;
+    RowMatrix2x4, ColumnMatrix2x4, 2, 4;
+    RowMatrix3x2, ColumnMatrix3x2, 3, 2;
+    RowMatrix3, ColumnMatrix3, 3, 3;
+    RowMatrix3x4, ColumnMatrix3x4, 3, 4;
+    RowMatrix4x2, ColumnMatrix4x2, 4, 2;
+    RowMatrix4x3, ColumnMatrix4x3, 4, 3;
+    RowMatrix4, ColumnMatrix4, 4, 4;
+}
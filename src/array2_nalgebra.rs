@@ -0,0 +1,67 @@
+use nalgebra::{DMatrix, DMatrixView, DMatrixViewMut, Dyn, Scalar};
+
+use crate::Array2;
+
+impl<T: Scalar> Array2<T> {
+    /// Returns a [`nalgebra`] matrix view of the elements without copying them,
+    /// with the strides set up for the row-major layout of [`Array2`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1.0, 2.0], [3.0, 4.0]]);
+    /// let view = a2.as_dmatrix_view();
+    /// assert_eq!(view[(0, 1)], 2.0);
+    /// assert_eq!(view.determinant(), -2.0);
+    /// ```
+    pub fn as_dmatrix_view(&self) -> DMatrixView<'_, T, Dyn, Dyn> {
+        let (num_cols, num_rows) = (self.num_cols, self.num_rows());
+        DMatrixView::from_slice_with_strides(&self.data, num_rows, num_cols, num_cols, 1)
+    }
+
+    /// Returns a mutable [`nalgebra`] matrix view of the elements without copying them,
+    /// with the strides set up for the row-major layout of [`Array2`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let mut a2 = Array2::new_from_rows([[1, 2], [3, 4]]);
+    /// a2.as_dmatrix_view_mut().column_mut(0).fill(0);
+    /// assert_eq!(a2, Array2::new_from_rows([[0, 2], [0, 4]]));
+    /// ```
+    pub fn as_dmatrix_view_mut(&mut self) -> DMatrixViewMut<'_, T, Dyn, Dyn> {
+        let (num_cols, num_rows) = (self.num_cols, self.num_rows());
+        DMatrixViewMut::from_slice_with_strides_mut(&mut self.data, num_rows, num_cols, num_cols, 1)
+    }
+}
+
+/// Converts an [`Array2`] into a column-major [`DMatrix`] with the same rows and columns.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::Array2;
+/// # use nalgebra::DMatrix;
+/// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+/// let m = DMatrix::from(a2.clone());
+/// assert_eq!(m, DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]));
+/// assert_eq!(Array2::from(m), a2);
+/// ```
+impl<T: Scalar> From<Array2<T>> for DMatrix<T> {
+    fn from(value: Array2<T>) -> Self {
+        let (num_cols, num_rows) = (value.num_cols, value.num_rows());
+        DMatrix::from_row_iterator(num_rows, num_cols, value.data.into_vec())
+    }
+}
+
+/// Converts a [`DMatrix`] into an [`Array2`] with the same rows and columns.
+/// A matrix with no columns becomes an empty array.
+impl<T: Scalar> From<DMatrix<T>> for Array2<T> {
+    fn from(value: DMatrix<T>) -> Self {
+        Array2::from_fn(value.ncols(), value.nrows(), |row, col| {
+            value[(row, col)].clone()
+        })
+    }
+}
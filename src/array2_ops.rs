@@ -0,0 +1,127 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{Array2, Array2Error};
+
+impl<T> Array2<T> {
+    /// Panics if `other` has a different shape than this array.
+    pub(crate) fn assert_same_shape<U>(&self, other: &Array2<U>) {
+        assert!(
+            self.num_cols == other.num_cols && self.data.len() == other.data.len(),
+            "Arrays must have the same shape, got {}x{} and {}x{}",
+            self.num_cols,
+            self.num_rows(),
+            other.num_cols,
+            other.num_rows()
+        );
+    }
+}
+
+macro_rules! impl_elementwise_arithmetic {
+    ($($op:ident::$op_fn:ident, $op_assign:ident::$op_assign_fn:ident, $try_op_assign_fn:ident;)*) => {$(
+        impl<T: Copy + $op_assign> Array2<T> {
+            #[doc = concat!("Applies `", stringify!($op_assign_fn), "` to each pair of elements at the same position,")]
+            /// or returns [`Array2Error::ShapeMismatch`] without modifying the array if the arrays have different shapes.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use contiguous_collections::{Array2, Array2Error};
+            #[doc = concat!("# use std::ops::", stringify!($op), ";")]
+            /// let mut a2 = Array2::new_from_rows([[8, 6], [4, 2]]);
+            #[doc = concat!("assert!(a2.", stringify!($try_op_assign_fn), "(&Array2::new(2, 1, 2)).is_err());")]
+            #[doc = concat!("assert_eq!(a2.", stringify!($try_op_assign_fn), "(&Array2::new(2, 2, 2)), Ok(()));")]
+            #[doc = concat!("assert_eq!(a2, Array2::new_from_rows([[8, 6], [4, 2]]).", stringify!($op_fn), "(&Array2::new(2, 2, 2)));")]
+            /// ```
+            pub fn $try_op_assign_fn(&mut self, rhs: &Array2<T>) -> Result<(), Array2Error> {
+                self.check_same_shape(rhs)?;
+                self.$op_assign_fn(rhs);
+                Ok(())
+            }
+        }
+
+        /// Applies the operation to each pair of elements at the same position.
+        ///
+        /// Panics if the arrays have different shapes.
+        impl<T: Copy + $op_assign> $op_assign<&Array2<T>> for Array2<T> {
+            fn $op_assign_fn(&mut self, rhs: &Array2<T>) {
+                self.assert_same_shape(rhs);
+                for (a, &b) in self.data.iter_mut().zip(rhs.data.iter()) {
+                    a.$op_assign_fn(b);
+                }
+            }
+        }
+
+        /// Applies the operation to each element and the scalar.
+        impl<T: Copy + $op_assign> $op_assign<T> for Array2<T> {
+            fn $op_assign_fn(&mut self, rhs: T) {
+                for a in self.data.iter_mut() {
+                    a.$op_assign_fn(rhs);
+                }
+            }
+        }
+
+        /// Applies the operation to each pair of elements at the same position, reusing the buffer of `self`.
+        ///
+        /// Panics if the arrays have different shapes.
+        impl<T: Copy + $op_assign> $op<&Array2<T>> for Array2<T> {
+            type Output = Array2<T>;
+            fn $op_fn(mut self, rhs: &Array2<T>) -> Array2<T> {
+                self.$op_assign_fn(rhs);
+                self
+            }
+        }
+
+        /// Applies the operation to each pair of elements at the same position.
+        ///
+        /// Panics if the arrays have different shapes.
+        impl<T: Copy + $op_assign> $op<&Array2<T>> for &Array2<T> {
+            type Output = Array2<T>;
+            fn $op_fn(self, rhs: &Array2<T>) -> Array2<T> {
+                self.clone().$op_fn(rhs)
+            }
+        }
+
+        /// Applies the operation to each element and the scalar, reusing the buffer of `self`.
+        impl<T: Copy + $op_assign> $op<T> for Array2<T> {
+            type Output = Array2<T>;
+            fn $op_fn(mut self, rhs: T) -> Array2<T> {
+                self.$op_assign_fn(rhs);
+                self
+            }
+        }
+
+        /// Applies the operation to each element and the scalar.
+        impl<T: Copy + $op_assign> $op<T> for &Array2<T> {
+            type Output = Array2<T>;
+            fn $op_fn(self, rhs: T) -> Array2<T> {
+                self.clone().$op_fn(rhs)
+            }
+        }
+    )*};
+}
+
+impl_elementwise_arithmetic! {
+    Add::add, AddAssign::add_assign, try_add_assign;
+    Sub::sub, SubAssign::sub_assign, try_sub_assign;
+    Mul::mul, MulAssign::mul_assign, try_mul_assign;
+    Div::div, DivAssign::div_assign, try_div_assign;
+}
+
+/// Negates each element, reusing the buffer.
+impl<T: Copy + Neg<Output = T>> Neg for Array2<T> {
+    type Output = Array2<T>;
+    fn neg(mut self) -> Array2<T> {
+        for a in self.data.iter_mut() {
+            *a = -*a;
+        }
+        self
+    }
+}
+
+/// Negates each element.
+impl<T: Copy + Neg<Output = T>> Neg for &Array2<T> {
+    type Output = Array2<T>;
+    fn neg(self) -> Array2<T> {
+        self.map(|&a| -a)
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{array2::num_elements_or_panic, Array2};
+
+/// Pool of [`Array2`] buffers for reusing the memory of temporary arrays.
+///
+/// Arrays handed out by the pool are allocated only if there is no recycled buffer
+/// with the same number of elements, so a pipeline that recycles its temporaries
+/// stops allocating after the first iteration. Buffers are matched by the number of elements only,
+/// so e.g. a recycled 4x2 array can be handed out as a 2x4 one.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{Array2, Array2Pool};
+/// let frame = Array2::new_from_rows([[1.0, 2.0], [3.0, 4.0]]);
+/// let mut pool = Array2Pool::new();
+/// for _ in 0..3 {
+///     let scaled = pool.map(&frame, |&x| x * 0.5);
+///     let mut accumulated = pool.take(frame.num_cols(), frame.num_rows(), 0.0);
+///     accumulated += &scaled;
+///     assert_eq!(accumulated, Array2::new_from_rows([[0.5, 1.0], [1.5, 2.0]]));
+///     pool.recycle(scaled);
+///     pool.recycle(accumulated);
+/// }
+/// assert_eq!(pool.len(), 2);
+/// ```
+#[derive(Debug)]
+pub struct Array2Pool<T> {
+    buffers: Vec<Box<[T]>>,
+}
+
+impl<T> Array2Pool<T> {
+    /// Creates an empty [`Array2Pool`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2Pool;
+    /// let pool: Array2Pool<u8> = Array2Pool::new();
+    /// assert!(pool.is_empty());
+    /// ```
+    pub const fn new() -> Self {
+        Array2Pool {
+            buffers: Vec::new(),
+        }
+    }
+
+    /// Returns the number of recycled buffers available for reuse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Pool};
+    /// let mut pool = Array2Pool::new();
+    /// pool.recycle(Array2::new(2, 2, 0));
+    /// pool.recycle(Array2::new(3, 1, 0));
+    /// assert_eq!(pool.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.buffers.len()
+    }
+
+    /// Returns true if there are no recycled buffers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Pool};
+    /// let mut pool = Array2Pool::new();
+    /// pool.recycle(Array2::new(2, 2, 0));
+    /// assert!(!pool.is_empty());
+    /// pool.take(4, 1, 0);
+    /// assert!(pool.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.buffers.is_empty()
+    }
+
+    /// Drops all recycled buffers, freeing their memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Pool};
+    /// let mut pool = Array2Pool::new();
+    /// pool.recycle(Array2::new(2, 2, 0));
+    /// pool.clear();
+    /// assert!(pool.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.buffers.clear();
+    }
+
+    /// Returns an [`Array2`] of the given dimensions with all elements set to the given value,
+    /// reusing a recycled buffer if one is available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Pool};
+    /// let mut pool = Array2Pool::new();
+    /// let a2 = Array2::new_from_rows([[1, 2], [3, 4]]);
+    /// let ptr = a2.elements().as_ptr();
+    /// pool.recycle(a2);
+    /// let reused = pool.take(4, 1, 0);
+    /// assert_eq!(reused, Array2::new(4, 1, 0));
+    /// assert_eq!(reused.elements().as_ptr(), ptr);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements overflows `usize`, like [`Array2::new`].
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::{Array2, Array2Pool};
+    /// let mut pool = Array2Pool::new();
+    /// pool.recycle(Array2::new(0, 0, 0));
+    /// pool.take(1 << (usize::BITS - 1), 2, 0);
+    /// ```
+    pub fn take(&mut self, num_cols: usize, num_rows: usize, init_value: T) -> Array2<T>
+    where
+        T: Clone,
+    {
+        match self.take_buffer(num_elements_or_panic(num_cols, num_rows)) {
+            Some(mut data) => {
+                data.fill(init_value);
+                Array2 { data, num_cols }
+            }
+            None => Array2::new(num_cols, num_rows, init_value),
+        }
+    }
+
+    /// Returns an [`Array2`] of the given dimensions, reusing a recycled buffer without resetting
+    /// its elements if one is available, or allocating one with all elements set to `init_value` otherwise.
+    ///
+    /// This avoids a pass over the buffer when the caller overwrites every element anyway.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Pool};
+    /// let mut pool = Array2Pool::new();
+    /// pool.recycle(Array2::new_from_rows([[1, 2, 3]]));
+    /// assert_eq!(pool.take_stale(1, 3, 0), Array2::new_from_rows([[1], [2], [3]]));
+    /// assert_eq!(pool.take_stale(1, 3, 0), Array2::new(1, 3, 0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements overflows `usize`, like [`Array2::new`].
+    pub fn take_stale(&mut self, num_cols: usize, num_rows: usize, init_value: T) -> Array2<T>
+    where
+        T: Clone,
+    {
+        match self.take_buffer(num_elements_or_panic(num_cols, num_rows)) {
+            Some(data) => Array2 { data, num_cols },
+            None => Array2::new(num_cols, num_rows, init_value),
+        }
+    }
+
+    /// Returns a new [`Array2`] of the same dimensions as `src`, with function `f` applied
+    /// to each element of `src` in row-major order, like [`Array2::map`],
+    /// but reusing a recycled buffer if one is available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Pool};
+    /// let mut pool = Array2Pool::new();
+    /// pool.recycle(Array2::new(2, 1, String::new()));
+    /// let src = Array2::new_from_rows([[1, 2]]);
+    /// assert_eq!(pool.map(&src, |x| x.to_string()), Array2::new_from_rows([["1", "2"]]).map(|s| s.to_string()));
+    /// assert!(pool.is_empty());
+    /// ```
+    pub fn map<S>(&mut self, src: &Array2<S>, mut f: impl FnMut(&S) -> T) -> Array2<T> {
+        match self.take_buffer(src.num_elements()) {
+            Some(mut data) => {
+                for (dst, s) in data.iter_mut().zip(src.elements()) {
+                    *dst = f(s);
+                }
+                Array2 {
+                    data,
+                    num_cols: src.num_cols(),
+                }
+            }
+            None => src.map(f),
+        }
+    }
+
+    /// Returns the buffer of the array to the pool for reuse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Pool};
+    /// let mut pool = Array2Pool::new();
+    /// pool.recycle(Array2::new(3, 2, 0));
+    /// // Arrays without elements have no buffer to reuse
+    /// pool.recycle(Array2::new(0, 0, 0));
+    /// assert_eq!(pool.len(), 1);
+    /// ```
+    pub fn recycle(&mut self, array: Array2<T>) {
+        if !array.data.is_empty() {
+            self.buffers.push(array.data);
+        }
+    }
+
+    fn take_buffer(&mut self, len: usize) -> Option<Box<[T]>> {
+        let index = self.buffers.iter().rposition(|b| b.len() == len)?;
+        Some(self.buffers.swap_remove(index))
+    }
+}
+
+impl<T> Default for Array2Pool<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
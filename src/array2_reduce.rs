@@ -0,0 +1,173 @@
+use std::{cmp::Ordering, iter::Sum, ops::Add};
+
+use crate::Array2;
+
+impl<T> Array2<T> {
+    /// Folds the elements of each row from left to right, starting from a copy of `init`,
+    /// and returns the result for each row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([["a", "b"], ["c", "d"]]);
+    /// let joined = a2.fold_rows(String::new(), |acc, s| acc + s);
+    /// assert_eq!(joined, vec!["ab", "cd"]);
+    /// ```
+    pub fn fold_rows<Acc: Clone>(&self, init: Acc, mut f: impl FnMut(Acc, &T) -> Acc) -> Vec<Acc> {
+        let num_rows = self.num_rows();
+        let mut result = Vec::with_capacity(num_rows);
+        for row in 0..num_rows {
+            let row = &self.data[row * self.num_cols..(row + 1) * self.num_cols];
+            result.push(row.iter().fold(init.clone(), &mut f));
+        }
+        result
+    }
+
+    /// Folds the elements of each column from top to bottom, starting from a copy of `init`,
+    /// and returns the result for each column.
+    ///
+    /// The elements are visited row by row in the order of the underlying buffer,
+    /// updating the accumulators of all columns along each row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, -5], [3, 2], [-4, 4]]);
+    /// let positive = a2.fold_cols(0, |count, &x| if x > 0 { count + 1 } else { count });
+    /// assert_eq!(positive, vec![2, 2]);
+    /// ```
+    pub fn fold_cols<Acc: Clone>(&self, init: Acc, mut f: impl FnMut(Acc, &T) -> Acc) -> Vec<Acc> {
+        let mut accs = vec![Some(init); self.num_cols];
+        for row in 0..self.num_rows() {
+            let row = &self.data[row * self.num_cols..(row + 1) * self.num_cols];
+            for (acc, element) in accs.iter_mut().zip(row) {
+                let prev = acc
+                    .take()
+                    .expect("fold_cols() must restore every accumulator");
+                *acc = Some(f(prev, element));
+            }
+        }
+        accs.into_iter().flatten().collect()
+    }
+
+    /// Returns the sum of all elements, which is zero if the array is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a2.sum(), 21);
+    /// ```
+    pub fn sum(&self) -> T
+    where
+        T: Copy + Sum,
+    {
+        self.data.iter().copied().sum()
+    }
+
+    /// Returns the sum of the elements of each row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1.0, 2.0], [3.0, 4.5]]);
+    /// assert_eq!(a2.row_sums(), vec![3.0, 7.5]);
+    /// ```
+    pub fn row_sums(&self) -> Vec<T>
+    where
+        T: Copy + Sum,
+    {
+        let num_rows = self.num_rows();
+        let mut result = Vec::with_capacity(num_rows);
+        for row in 0..num_rows {
+            let row = &self.data[row * self.num_cols..(row + 1) * self.num_cols];
+            result.push(row.iter().copied().sum());
+        }
+        result
+    }
+
+    /// Returns the sum of the elements of each column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(a2.col_sums(), vec![9, 12]);
+    /// ```
+    pub fn col_sums(&self) -> Vec<T>
+    where
+        T: Copy + Sum + Add<Output = T>,
+    {
+        self.fold_cols(std::iter::empty().sum(), |acc, &x| acc + x)
+    }
+
+    /// Returns the smallest element, or None if the array is empty.
+    /// Unlike [`Ord::min`], which compares whole arrays, this compares the elements.
+    /// If several elements are equally small, the first one in row-major order is returned.
+    ///
+    /// Elements that are not comparable to themselves, such as NaN, are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[2.5, f64::NAN], [-1.0, 7.0]]);
+    /// assert_eq!(a2.min_element(), Some(&-1.0));
+    /// assert_eq!(a2.max_element(), Some(&7.0));
+    /// ```
+    pub fn min_element(&self) -> Option<&T>
+    where
+        T: PartialOrd,
+    {
+        self.min_max().map(|(min, _)| min)
+    }
+
+    /// Returns the largest element, or None if the array is empty.
+    /// Unlike [`Ord::max`], which compares whole arrays, this compares the elements.
+    /// If several elements are equally large, the last one in row-major order is returned.
+    ///
+    /// Elements that are not comparable to themselves, such as NaN, are ignored.
+    pub fn max_element(&self) -> Option<&T>
+    where
+        T: PartialOrd,
+    {
+        self.min_max().map(|(_, max)| max)
+    }
+
+    /// Returns the smallest and the largest element in a single pass,
+    /// or None if the array has no elements that are comparable to themselves.
+    /// See [`min_element`](struct.Array2.html#method.min_element) and [`max_element`](struct.Array2.html#method.max_element).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[3, 1], [4, 1], [5, 9]]);
+    /// assert_eq!(a2.min_max(), Some((&1, &9)));
+    /// assert_eq!(Array2::new_from_rows([[f32::NAN]]).min_max(), None);
+    /// ```
+    pub fn min_max(&self) -> Option<(&T, &T)>
+    where
+        T: PartialOrd,
+    {
+        let mut elements = self
+            .data
+            .iter()
+            .filter(|x| x.partial_cmp(x) == Some(Ordering::Equal));
+        let first = elements.next()?;
+        Some(elements.fold((first, first), |(min, max), x| {
+            if x < min {
+                (x, max)
+            } else if x >= max {
+                (min, x)
+            } else {
+                (min, max)
+            }
+        }))
+    }
+}
@@ -0,0 +1,195 @@
+use std::{iter::FusedIterator, ops::Index};
+
+use crate::{Array2, Coord, Shape};
+
+/// Borrowed rectangular region of an [`Array2`].
+///
+/// Rows of the view are contiguous slices of the underlying buffer,
+/// but consecutive rows are separated by the elements of the array outside of the view.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::Array2;
+/// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+/// let view = a2.windows2(2, 2).last().unwrap();
+/// assert_eq!((view.num_cols(), view.num_rows()), (2, 2));
+/// assert_eq!(view.rows().collect::<Vec<_>>(), vec![&[5, 6], &[8, 9]]);
+/// assert_eq!(view[1], [8, 9]);
+/// ```
+pub struct Array2View<'a, T> {
+    data: &'a [T],
+    row_stride: usize,
+    num_cols: usize,
+    num_rows: usize,
+}
+
+impl<'a, T> Array2View<'a, T> {
+    /// Creates a view of the region of `array` with the top left corner at the given row and column.
+    /// The region must be within the bounds of the array.
+    pub(crate) fn new(
+        array: &'a Array2<T>,
+        first_row: usize,
+        first_col: usize,
+        num_cols: usize,
+        num_rows: usize,
+    ) -> Self {
+        let row_stride = array.num_cols();
+        let start = first_row * row_stride + first_col;
+        let len = match num_rows {
+            0 => 0,
+            _ => (num_rows - 1) * row_stride + num_cols,
+        };
+        Array2View {
+            data: &array.elements()[start..start + len],
+            row_stride,
+            num_cols,
+            num_rows,
+        }
+    }
+
+    /// Returns the number of columns (elements per row).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let view = a2.chunks2(2, 2).last().unwrap();
+    /// assert_eq!(view.num_cols(), 1);
+    /// ```
+    pub const fn num_cols(&self) -> usize {
+        self.num_cols
+    }
+
+    /// Returns the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2], [3, 4], [5, 6]]);
+    /// let view = a2.chunks2(2, 2).last().unwrap();
+    /// assert_eq!(view.num_rows(), 1);
+    /// ```
+    pub const fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Returns the number of rows and columns as a [`Shape`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Shape};
+    /// let a2 = Array2::new(4, 3, 0);
+    /// let view = a2.windows2(3, 2).next().unwrap();
+    /// assert_eq!(view.shape(), Shape::new(2, 3));
+    /// ```
+    pub const fn shape(&self) -> Shape {
+        Shape::new(self.num_rows, self.num_cols)
+    }
+
+    /// Returns a slice of the underlying buffer with elements of the row
+    /// at the given index, or None if the row index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let view = a2.windows2(2, 2).nth(1).unwrap();
+    /// assert_eq!(view.row(1), Some(&[5, 6][..]));
+    /// assert_eq!(view.row(2), None);
+    /// ```
+    pub fn row(&self, row_index: usize) -> Option<&'a [T]> {
+        if row_index < self.num_rows {
+            let start = row_index * self.row_stride;
+            Some(&self.data[start..start + self.num_cols])
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over rows. Each item is a slice of all elements
+    /// in the corresponding row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = a2.chunks2(2, 2).next().unwrap();
+    /// let sums: Vec<i32> = view.rows().map(|row| row.iter().sum()).collect();
+    /// assert_eq!(sums, vec![3, 9]);
+    /// ```
+    pub fn rows(
+        &self,
+    ) -> impl ExactSizeIterator<Item = &'a [T]> + DoubleEndedIterator + FusedIterator {
+        let (data, row_stride, num_cols) = (self.data, self.row_stride, self.num_cols);
+        (0..self.num_rows).map(move |i| &data[i * row_stride..i * row_stride + num_cols])
+    }
+
+    /// Returns a new [`Array2`] with a copy of the elements in the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Array2;
+    /// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let view = a2.chunks2(2, 2).next().unwrap();
+    /// assert_eq!(view.to_array2(), Array2::new_from_rows([[1, 2], [4, 5]]));
+    /// ```
+    pub fn to_array2(&self) -> Array2<T>
+    where
+        T: Clone,
+    {
+        let mut data = Vec::with_capacity(self.num_cols * self.num_rows);
+        for row in self.rows() {
+            data.extend_from_slice(row);
+        }
+        Array2 {
+            data: data.into_boxed_slice(),
+            num_cols: self.num_cols,
+        }
+    }
+}
+
+impl<T> Clone for Array2View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Array2View<'_, T> {}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for Array2View<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_list().entries(self.rows()).finish()
+    }
+}
+
+impl<T> Index<usize> for Array2View<'_, T> {
+    type Output = [T];
+
+    /// Returns a slice of the underlying buffer with elements of the row at the given index.
+    ///
+    /// Panics if the index is out of bounds. See [`row`](struct.Array2View.html#method.row) for a non-panicking version.
+    fn index(&self, row_index: usize) -> &Self::Output {
+        self.row(row_index)
+            .unwrap_or_else(|| panic!("Row index {} is out of bounds", row_index))
+    }
+}
+
+impl<T> Index<Coord> for Array2View<'_, T> {
+    type Output = T;
+
+    /// Returns a reference to the element at the given coordinates relative to the top left corner of the view.
+    ///
+    /// Panics if the coordinates are out of bounds.
+    fn index(&self, coord: Coord) -> &Self::Output {
+        self.row(coord.row)
+            .and_then(|row| row.get(coord.col))
+            .unwrap_or_else(|| panic!("Coordinates {:?} are out of bounds", coord))
+    }
+}
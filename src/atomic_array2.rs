@@ -0,0 +1,288 @@
+use std::sync::atomic::{self, Ordering};
+
+use crate::{array2::num_elements_or_panic, Array2};
+
+/// Atomic integer types that can be stored in an [`AtomicArray2`].
+///
+/// Implemented for all integer atomics from [`std::sync::atomic`] supported by the target platform.
+pub trait AtomicElement: Send + Sync {
+    /// The plain integer type wrapped by the atomic.
+    type Value: Copy;
+    /// Creates a new atomic initialized to the given value.
+    fn new(value: Self::Value) -> Self;
+    /// Loads the current value.
+    fn load(&self, order: Ordering) -> Self::Value;
+    /// Stores a new value.
+    fn store(&self, value: Self::Value, order: Ordering);
+    /// Adds to the current value (wrapping around on overflow), returning the previous value.
+    fn fetch_add(&self, value: Self::Value, order: Ordering) -> Self::Value;
+    /// Consumes the atomic and returns the contained value.
+    fn into_inner(self) -> Self::Value;
+}
+
+macro_rules! impl_atomic_element {
+    ($($width:literal => $atomic:ident($value:ty)),* $(,)?) => {
+        $(
+            #[cfg(target_has_atomic = $width)]
+            impl AtomicElement for atomic::$atomic {
+                type Value = $value;
+                #[inline(always)]
+                fn new(value: $value) -> Self {
+                    atomic::$atomic::new(value)
+                }
+                #[inline(always)]
+                fn load(&self, order: Ordering) -> $value {
+                    atomic::$atomic::load(self, order)
+                }
+                #[inline(always)]
+                fn store(&self, value: $value, order: Ordering) {
+                    atomic::$atomic::store(self, value, order)
+                }
+                #[inline(always)]
+                fn fetch_add(&self, value: $value, order: Ordering) -> $value {
+                    atomic::$atomic::fetch_add(self, value, order)
+                }
+                #[inline(always)]
+                fn into_inner(self) -> $value {
+                    atomic::$atomic::into_inner(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_atomic_element!(
+    "8" => AtomicU8(u8),
+    "8" => AtomicI8(i8),
+    "16" => AtomicU16(u16),
+    "16" => AtomicI16(i16),
+    "32" => AtomicU32(u32),
+    "32" => AtomicI32(i32),
+    "64" => AtomicU64(u64),
+    "64" => AtomicI64(i64),
+    "ptr" => AtomicUsize(usize),
+    "ptr" => AtomicIsize(isize),
+);
+
+/// Fixed-size two-dimensional array of atomic integers that can be updated concurrently
+/// through a shared reference, e.g. to accumulate a histogram from multiple threads without locks.
+///
+/// The convenience methods ([`load`](struct.AtomicArray2.html#method.load),
+/// [`store`](struct.AtomicArray2.html#method.store), [`fetch_add`](struct.AtomicArray2.html#method.fetch_add),
+/// [`snapshot`](struct.AtomicArray2.html#method.snapshot)) use [`Ordering::Relaxed`], which is sufficient
+/// for counters that are read after all writer threads have been joined. For other memory orderings,
+/// access the atomics directly via [`as_array2`](struct.AtomicArray2.html#method.as_array2).
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{Array2, AtomicArray2};
+/// # use std::sync::atomic::AtomicU32;
+/// let counts: AtomicArray2<AtomicU32> = AtomicArray2::new(2, 2, 0);
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| {
+///             for i in 0..100 {
+///                 counts.fetch_add(i % 2, 1, 1);
+///             }
+///         });
+///     }
+/// });
+/// assert_eq!(counts.snapshot(), Array2::new_from_rows([[0, 200], [0, 200]]));
+/// ```
+#[derive(Debug)]
+pub struct AtomicArray2<A: AtomicElement>(Array2<A>);
+
+impl<A: AtomicElement> AtomicArray2<A> {
+    /// Creates an [`AtomicArray2`] of the given dimensions with all elements set to the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::AtomicArray2;
+    /// # use std::sync::atomic::AtomicU64;
+    /// let a2: AtomicArray2<AtomicU64> = AtomicArray2::new(3, 2, 7);
+    /// assert_eq!(a2.load(1, 2), 7);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements overflows `usize`, like [`Array2::new`].
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::AtomicArray2;
+    /// # use std::sync::atomic::AtomicU8;
+    /// let a2: AtomicArray2<AtomicU8> = AtomicArray2::new(1 << (usize::BITS - 1), 2, 0);
+    /// ```
+    pub fn new(num_cols: usize, num_rows: usize, init_value: A::Value) -> Self {
+        let data = (0..num_elements_or_panic(num_cols, num_rows))
+            .map(|_| A::new(init_value))
+            .collect();
+        AtomicArray2(Array2 { data, num_cols })
+    }
+
+    /// Returns the number of columns (elements per row).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::AtomicArray2;
+    /// # use std::sync::atomic::AtomicU32;
+    /// let a2: AtomicArray2<AtomicU32> = AtomicArray2::new(3, 2, 0);
+    /// assert_eq!(a2.num_cols(), 3);
+    /// ```
+    pub const fn num_cols(&self) -> usize {
+        self.0.num_cols()
+    }
+
+    /// Returns the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::AtomicArray2;
+    /// # use std::sync::atomic::AtomicU32;
+    /// let a2: AtomicArray2<AtomicU32> = AtomicArray2::new(3, 2, 0);
+    /// assert_eq!(a2.num_rows(), 2);
+    /// ```
+    pub const fn num_rows(&self) -> usize {
+        self.0.num_rows()
+    }
+
+    /// Returns the underlying array of atomics, which can be used to access
+    /// elements with memory orderings other than [`Ordering::Relaxed`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::AtomicArray2;
+    /// # use std::sync::atomic::{AtomicU32, Ordering};
+    /// let a2: AtomicArray2<AtomicU32> = AtomicArray2::new(2, 2, 0);
+    /// a2.as_array2()[1][0].store(5, Ordering::Release);
+    /// assert_eq!(a2.as_array2()[1][0].load(Ordering::Acquire), 5);
+    /// ```
+    pub const fn as_array2(&self) -> &Array2<A> {
+        &self.0
+    }
+
+    /// Returns the value of the element at the given row and column.
+    ///
+    /// Panics if the row or column index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, AtomicArray2};
+    /// # use std::sync::atomic::AtomicU16;
+    /// let a2: AtomicArray2<AtomicU16> = Array2::new_from_rows([[1, 2], [3, 4]]).into();
+    /// assert_eq!(a2.load(1, 0), 3);
+    /// ```
+    pub fn load(&self, row_index: usize, col_index: usize) -> A::Value {
+        self.cell(row_index, col_index).load(Ordering::Relaxed)
+    }
+
+    /// Sets the value of the element at the given row and column.
+    ///
+    /// Panics if the row or column index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::AtomicArray2;
+    /// # use std::sync::atomic::AtomicU8;
+    /// let labels: AtomicArray2<AtomicU8> = AtomicArray2::new(4, 4, 0);
+    /// std::thread::scope(|s| {
+    ///     s.spawn(|| labels.store(2, 3, 7));
+    /// });
+    /// assert_eq!(labels.load(2, 3), 7);
+    /// assert_eq!(labels.load(3, 2), 0);
+    /// ```
+    pub fn store(&self, row_index: usize, col_index: usize, value: A::Value) {
+        self.cell(row_index, col_index)
+            .store(value, Ordering::Relaxed)
+    }
+
+    /// Adds to the element at the given row and column (wrapping around on overflow),
+    /// returning its previous value.
+    ///
+    /// Panics if the row or column index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::AtomicArray2;
+    /// # use std::sync::atomic::AtomicU8;
+    /// let a2: AtomicArray2<AtomicU8> = AtomicArray2::new(2, 2, 250);
+    /// assert_eq!(a2.fetch_add(0, 1, 10), 250);
+    /// assert_eq!(a2.load(0, 1), 4);
+    /// ```
+    pub fn fetch_add(&self, row_index: usize, col_index: usize, value: A::Value) -> A::Value {
+        self.cell(row_index, col_index)
+            .fetch_add(value, Ordering::Relaxed)
+    }
+
+    /// Returns a new [`Array2`] with the current values of all elements.
+    ///
+    /// The snapshot is not atomic as a whole: elements updated concurrently
+    /// with this call may or may not be reflected in it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, AtomicArray2};
+    /// # use std::sync::atomic::AtomicU64;
+    /// let hits: AtomicArray2<AtomicU64> = AtomicArray2::new(2, 1, 0);
+    /// hits.fetch_add(0, 1, 3);
+    /// let before = hits.snapshot();
+    /// hits.fetch_add(0, 1, 1);
+    /// assert_eq!(before, Array2::new_from_rows([[0, 3]]));
+    /// assert_eq!(hits.snapshot(), Array2::new_from_rows([[0, 4]]));
+    /// ```
+    pub fn snapshot(&self) -> Array2<A::Value> {
+        self.0.map(|cell| cell.load(Ordering::Relaxed))
+    }
+
+    /// Consumes the [`AtomicArray2`] and returns an [`Array2`] with the values of all elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, AtomicArray2};
+    /// # use std::sync::atomic::AtomicI32;
+    /// let a2: AtomicArray2<AtomicI32> = Array2::new_from_rows([[1, 2], [3, 4]]).into();
+    /// a2.fetch_add(1, 1, -8);
+    /// assert_eq!(a2.into_array2(), Array2::new_from_rows([[1, 2], [3, -4]]));
+    /// ```
+    pub fn into_array2(self) -> Array2<A::Value> {
+        let data = self
+            .0
+            .data
+            .into_vec()
+            .into_iter()
+            .map(A::into_inner)
+            .collect();
+        Array2 {
+            data,
+            num_cols: self.0.num_cols,
+        }
+    }
+
+    fn cell(&self, row_index: usize, col_index: usize) -> &A {
+        self.0
+            .row(row_index)
+            .and_then(|row| row.get(col_index))
+            .unwrap_or_else(|| {
+                panic!(
+                    "Element index ({}, {}) is out of bounds",
+                    row_index, col_index
+                )
+            })
+    }
+}
+
+/// Creates an [`AtomicArray2`] with the same dimensions and values as the given [`Array2`].
+impl<A: AtomicElement> From<Array2<A::Value>> for AtomicArray2<A> {
+    fn from(value: Array2<A::Value>) -> Self {
+        AtomicArray2(value.map(|&v| A::new(v)))
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{OrdVec, OrdVecKey};
+
+/// Which item a [`BoundedOrdVec`] evicts when an insert would exceed its maximum length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvictionPolicy {
+    /// Evict the item with the smallest key, e.g. to keep the N highest scores or the N most recent timestamps.
+    Smallest,
+    /// Evict the item with the largest key, e.g. to keep the N lowest scores or the N oldest timestamps.
+    Largest,
+}
+
+/// [`OrdVec`] with a maximum length, which evicts an item according to its [`EvictionPolicy`]
+/// when an insert would exceed it.
+///
+/// Read-only methods of the underlying [`OrdVec`] are available through [`Deref`](std::ops::Deref).
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{BoundedOrdVec, EvictionPolicy, OrdVecKeyFst};
+/// // Top 2 by score
+/// let mut top: BoundedOrdVec<(u32, &str), OrdVecKeyFst> = BoundedOrdVec::new(2, EvictionPolicy::Smallest);
+/// assert_eq!(top.insert((70, "Ariel")), None);
+/// assert_eq!(top.insert((90, "Maya")), None);
+/// assert_eq!(top.insert((80, "Ben")), Some((70, "Ariel")));
+/// assert_eq!(top.insert((60, "Dana")), Some((60, "Dana")));
+/// assert_eq!(top[..], [(80, "Ben"), (90, "Maya")]);
+/// ```
+pub struct BoundedOrdVec<T, K: OrdVecKey<T>> {
+    items: OrdVec<T, K>,
+    max_len: usize,
+    policy: EvictionPolicy,
+}
+
+impl<T, K: OrdVecKey<T>> BoundedOrdVec<T, K> {
+    /// Creates an empty [`BoundedOrdVec`] with the given maximum length and eviction policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{BoundedOrdVec, EvictionPolicy, OrdVecKeyIdentity};
+    /// let mut ov: BoundedOrdVec<u32, OrdVecKeyIdentity> = BoundedOrdVec::new(0, EvictionPolicy::Smallest);
+    /// assert!(ov.is_empty());
+    /// assert_eq!(ov.insert(1), Some(1));
+    /// assert!(ov.is_empty());
+    /// ```
+    pub fn new(max_len: usize, policy: EvictionPolicy) -> Self {
+        BoundedOrdVec {
+            items: OrdVec::new(),
+            max_len,
+            policy,
+        }
+    }
+
+    /// Returns the maximum number of items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{BoundedOrdVec, EvictionPolicy, OrdVecKeyIdentity};
+    /// let ov: BoundedOrdVec<u32, OrdVecKeyIdentity> = BoundedOrdVec::new(3, EvictionPolicy::Largest);
+    /// assert_eq!(ov.max_len(), 3);
+    /// ```
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Returns the eviction policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{BoundedOrdVec, EvictionPolicy, OrdVecKeyIdentity};
+    /// let ov: BoundedOrdVec<u32, OrdVecKeyIdentity> = BoundedOrdVec::new(3, EvictionPolicy::Largest);
+    /// assert_eq!(ov.policy(), EvictionPolicy::Largest);
+    /// ```
+    pub fn policy(&self) -> EvictionPolicy {
+        self.policy
+    }
+
+    /// Inserts the item, evicting an item if the collection is full.
+    ///
+    /// Returns the evicted item, which is the inserted item itself if its key would be evicted first,
+    /// or None if the collection was not full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{BoundedOrdVec, EvictionPolicy, OrdVecKeyIdentity};
+    /// // Lowest 2 latencies
+    /// let mut fastest: BoundedOrdVec<u32, OrdVecKeyIdentity> = BoundedOrdVec::new(2, EvictionPolicy::Largest);
+    /// assert_eq!(fastest.insert(30), None);
+    /// assert_eq!(fastest.insert(10), None);
+    /// assert_eq!(fastest.insert(20), Some(30));
+    /// assert_eq!(fastest.insert(40), Some(40));
+    /// assert_eq!(fastest[..], [10, 20]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if an item with the same key is already present.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::{BoundedOrdVec, EvictionPolicy, OrdVecKeyFst};
+    /// let mut ov: BoundedOrdVec<(u32, &str), OrdVecKeyFst> = BoundedOrdVec::new(2, EvictionPolicy::Largest);
+    /// ov.insert((5, "B"));
+    /// ov.insert((5, "A"));
+    /// ```
+    pub fn insert(&mut self, item: T) -> Option<T> {
+        assert!(
+            self.items.get_index_by_key(K::get_key(&item)).is_none(),
+            "Cannot insert an item with a duplicate key"
+        );
+        if self.items.len() < self.max_len {
+            self.items.insert_or_panic(item);
+            return None;
+        }
+        let evicted = match self.policy {
+            EvictionPolicy::Smallest => match self.items.first() {
+                Some(first) if K::cmp_keys(K::get_key(first), K::get_key(&item)).is_lt() => {
+                    self.items.pop_first()
+                }
+                _ => return Some(item),
+            },
+            EvictionPolicy::Largest => match self.items.last() {
+                Some(last) if K::cmp_keys(K::get_key(&item), K::get_key(last)).is_lt() => {
+                    self.items.pop_last()
+                }
+                _ => return Some(item),
+            },
+        };
+        self.items.insert_or_panic(item);
+        evicted
+    }
+
+    /// Removes an item with the given key and returns it, or None if such an item is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{BoundedOrdVec, EvictionPolicy, OrdVecKeyIdentity};
+    /// let mut ov: BoundedOrdVec<u32, OrdVecKeyIdentity> = BoundedOrdVec::new(2, EvictionPolicy::Smallest);
+    /// ov.insert(1);
+    /// ov.insert(2);
+    /// assert_eq!(ov.remove_by_key(&1), Some(1));
+    /// assert_eq!(ov.remove_by_key(&1), None);
+    /// assert_eq!(ov.insert(0), None);
+    /// assert_eq!(ov[..], [0, 2]);
+    /// ```
+    pub fn remove_by_key(&mut self, k: &<K as OrdVecKey<T>>::Key) -> Option<T> {
+        self.items.remove_by_key(k)
+    }
+
+    /// Consumes the [`BoundedOrdVec`] and returns the underlying [`OrdVec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{BoundedOrdVec, EvictionPolicy, OrdVecKeyIdentity};
+    /// let mut ov: BoundedOrdVec<u32, OrdVecKeyIdentity> = BoundedOrdVec::new(1, EvictionPolicy::Smallest);
+    /// ov.insert(1);
+    /// let mut inner = ov.into_inner();
+    /// inner.insert(2);
+    /// assert_eq!(inner[..], [1, 2]);
+    /// ```
+    pub fn into_inner(self) -> OrdVec<T, K> {
+        self.items
+    }
+}
+
+impl<T, K: OrdVecKey<T>> std::ops::Deref for BoundedOrdVec<T, K> {
+    type Target = OrdVec<T, K>;
+    fn deref(&self) -> &OrdVec<T, K> {
+        &self.items
+    }
+}
@@ -0,0 +1,205 @@
+use std::cell::OnceCell;
+
+use crate::{OrdVec, OrdVecKey};
+
+/// [`OrdVec`] with a lazily computed value derived from each item, e.g. a parsed representation
+/// of the item's contents.
+///
+/// The derived value of an item is computed by the projection function `F` the first time it is requested
+/// and cached until the item is modified or removed. The cache is kept in sync with the items
+/// when inserts and removals shift them, so cached values never need to be invalidated manually.
+///
+/// Read-only methods of the underlying [`OrdVec`] are available through [`Deref`](std::ops::Deref).
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{CachedOrdVec, OrdVec, OrdVecKeyFst};
+/// let ov: OrdVec<_, OrdVecKeyFst> = vec![(2, "20"), (1, "10")].into();
+/// let mut cached = CachedOrdVec::new(ov, |item: &(u32, &str)| item.1.parse::<u64>().unwrap());
+/// assert_eq!(cached.get_cached_by_key(&2), Some(&20));
+///
+/// cached.insert((0, "5"));
+/// assert_eq!(cached.get_cached_by_key(&2), Some(&20));
+/// assert_eq!(cached.get_cached_by_key(&0), Some(&5));
+///
+/// cached.get_mut_by_key(&2).unwrap().1 = "25";
+/// assert_eq!(cached.get_cached_by_key(&2), Some(&25));
+/// ```
+pub struct CachedOrdVec<T, K: OrdVecKey<T>, V, F: Fn(&T) -> V> {
+    items: OrdVec<T, K>,
+    cache: Vec<OnceCell<V>>,
+    project: F,
+}
+
+impl<T, K: OrdVecKey<T>, V, F: Fn(&T) -> V> CachedOrdVec<T, K, V, F> {
+    /// Creates a [`CachedOrdVec`] with the given items and projection function.
+    /// No derived values are computed until they are requested.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{CachedOrdVec, OrdVec, OrdVecKeyIdentity};
+    /// # use std::cell::Cell;
+    /// let calls = Cell::new(0);
+    /// let ov: OrdVec<u32, OrdVecKeyIdentity> = vec![3, 1, 2].into();
+    /// let cached = CachedOrdVec::new(ov, |&x| { calls.set(calls.get() + 1); x * x });
+    /// assert_eq!(calls.get(), 0);
+    /// assert_eq!(cached.get_cached(2), Some(&9));
+    /// assert_eq!(calls.get(), 1);
+    /// ```
+    pub fn new(items: OrdVec<T, K>, project: F) -> Self {
+        let cache = (0..items.len()).map(|_| OnceCell::new()).collect();
+        CachedOrdVec {
+            items,
+            cache,
+            project,
+        }
+    }
+
+    /// Returns the derived value of the item at the given index in the underlying ordered array,
+    /// computing it if it is not cached, or None if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{CachedOrdVec, OrdVec, OrdVecKeyIdentity};
+    /// # use std::cell::Cell;
+    /// let calls = Cell::new(0);
+    /// let ov: OrdVec<u32, OrdVecKeyIdentity> = vec![10, 20].into();
+    /// let cached = CachedOrdVec::new(ov, |&x| { calls.set(calls.get() + 1); x.to_string() });
+    /// assert_eq!(cached.get_cached(1).map(String::as_str), Some("20"));
+    /// assert_eq!(cached.get_cached(1).map(String::as_str), Some("20"));
+    /// assert_eq!(calls.get(), 1);
+    /// assert_eq!(cached.get_cached(2), None);
+    /// ```
+    pub fn get_cached(&self, index: usize) -> Option<&V> {
+        let item = self.items.get(index)?;
+        Some(self.cache[index].get_or_init(|| (self.project)(item)))
+    }
+
+    /// Returns the derived value of the item with the given key,
+    /// computing it if it is not cached, or None if such an item is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{CachedOrdVec, OrdVec, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![("b", "1,2"), ("a", "3")].into();
+    /// let cached = CachedOrdVec::new(ov, |item: &(&str, &str)| item.1.split(',').count());
+    /// assert_eq!(cached.get_cached_by_key(&"b"), Some(&2));
+    /// assert_eq!(cached.get_cached_by_key(&"c"), None);
+    /// ```
+    pub fn get_cached_by_key(&self, k: &<K as OrdVecKey<T>>::Key) -> Option<&V> {
+        self.get_cached(self.items.get_index_by_key(k)?)
+    }
+
+    /// Inserts the item, see [`OrdVec::insert`].
+    ///
+    /// Panics if an item with the same key is already present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{CachedOrdVec, OrdVec, OrdVecKeyIdentity};
+    /// let ov: OrdVec<i32, OrdVecKeyIdentity> = vec![1, 5].into();
+    /// let mut cached = CachedOrdVec::new(ov, |&x| -x);
+    /// assert_eq!(cached.get_cached(1), Some(&-5));
+    /// cached.insert(3);
+    /// assert_eq!(cached[..], [1, 3, 5]);
+    /// assert_eq!(cached.get_cached(1), Some(&-3));
+    /// assert_eq!(cached.get_cached(2), Some(&-5));
+    /// ```
+    pub fn insert(&mut self, item: T) {
+        let key = K::get_key(&item);
+        let index = self
+            .items
+            .partition_point(|other| K::cmp_keys(K::get_key(other), key).is_lt());
+        self.items.insert_or_panic(item);
+        self.cache.insert(index, OnceCell::new());
+    }
+
+    /// Removes the item with the given key together with its derived value and returns the item,
+    /// or None if such an item is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{CachedOrdVec, OrdVec, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![(1, 'a'), (2, 'b')].into();
+    /// let mut cached = CachedOrdVec::new(ov, |item: &(u32, char)| item.1.to_ascii_uppercase());
+    /// assert_eq!(cached.get_cached_by_key(&2), Some(&'B'));
+    /// assert_eq!(cached.remove_by_key(&1), Some((1, 'a')));
+    /// assert_eq!(cached.get_cached(0), Some(&'B'));
+    /// ```
+    pub fn remove_by_key(&mut self, k: &<K as OrdVecKey<T>>::Key) -> Option<T> {
+        let index = self.items.get_index_by_key(k)?;
+        self.cache.remove(index);
+        Some(self.items.vec_mut().remove(index))
+    }
+
+    /// Returns a mutable reference to the item with the given key, discarding its cached derived value.
+    ///
+    /// Warning: the behavior of the collection is undefined if the item's key
+    /// is changed in a way that affects its ordering relative to other items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{CachedOrdVec, OrdVec, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![(1, 2)].into();
+    /// let mut cached = CachedOrdVec::new(ov, |item: &(u32, u32)| item.1 * 10);
+    /// assert_eq!(cached.get_cached_by_key(&1), Some(&20));
+    /// cached.get_mut_by_key(&1).unwrap().1 = 7;
+    /// assert_eq!(cached.get_cached_by_key(&1), Some(&70));
+    /// assert!(cached.get_mut_by_key(&2).is_none());
+    /// ```
+    pub fn get_mut_by_key(&mut self, k: &<K as OrdVecKey<T>>::Key) -> Option<&mut T> {
+        let index = self.items.get_index_by_key(k)?;
+        self.cache[index].take();
+        Some(&mut self.items.0[index])
+    }
+
+    /// Discards all cached derived values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{CachedOrdVec, OrdVec, OrdVecKeyIdentity};
+    /// # use std::cell::Cell;
+    /// let calls = Cell::new(0);
+    /// let ov: OrdVec<u32, OrdVecKeyIdentity> = vec![1].into();
+    /// let mut cached = CachedOrdVec::new(ov, |&x| { calls.set(calls.get() + 1); x });
+    /// cached.get_cached(0);
+    /// cached.clear_cache();
+    /// cached.get_cached(0);
+    /// assert_eq!(calls.get(), 2);
+    /// ```
+    pub fn clear_cache(&mut self) {
+        self.cache.iter_mut().for_each(|cell| {
+            cell.take();
+        });
+    }
+
+    /// Consumes the [`CachedOrdVec`] and returns the underlying [`OrdVec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{CachedOrdVec, OrdVec, OrdVecKeyIdentity};
+    /// let ov: OrdVec<u32, OrdVecKeyIdentity> = vec![2, 1].into();
+    /// let mut cached = CachedOrdVec::new(ov, |&x| x + 1);
+    /// cached.insert(3);
+    /// assert_eq!(cached.into_inner()[..], [1, 2, 3]);
+    /// ```
+    pub fn into_inner(self) -> OrdVec<T, K> {
+        self.items
+    }
+}
+
+impl<T, K: OrdVecKey<T>, V, F: Fn(&T) -> V> std::ops::Deref for CachedOrdVec<T, K, V, F> {
+    type Target = OrdVec<T, K>;
+    fn deref(&self) -> &OrdVec<T, K> {
+        &self.items
+    }
+}
@@ -0,0 +1,169 @@
+use std::iter::FusedIterator;
+
+use crate::array2::num_elements_or_panic;
+
+/// Position of an element in a two-dimensional array, given as a row index and a column index.
+///
+/// Unlike a `(usize, usize)` tuple, the fields are named, so a row cannot be passed where
+/// a column is expected. Coordinates are ordered by row first, then by column,
+/// which is the row-major order of elements in an [`Array2`](crate::Array2).
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{Array2, Coord};
+/// let mut a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+/// let c = Coord { row: 1, col: 2 };
+/// assert_eq!(a2[c], 6);
+/// a2[Coord::new(0, 1)] = 0;
+/// assert_eq!(a2, Array2::new_from_rows([[1, 0, 3], [4, 5, 6]]));
+/// assert!(Coord::new(0, 5) < Coord::new(1, 0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coord {
+    /// Row index.
+    pub row: usize,
+    /// Column index.
+    pub col: usize,
+}
+
+impl Coord {
+    /// Creates a [`Coord`] with the given row and column index.
+    ///
+    /// Note that the row comes first, unlike the number of columns and rows in the constructors
+    /// of [`Array2`](crate::Array2).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Coord;
+    /// assert_eq!(Coord::new(1, 2), Coord { row: 1, col: 2 });
+    /// ```
+    pub const fn new(row: usize, col: usize) -> Self {
+        Coord { row, col }
+    }
+}
+
+/// Converts a `(row, col)` tuple into a [`Coord`].
+impl From<(usize, usize)> for Coord {
+    fn from((row, col): (usize, usize)) -> Self {
+        Coord { row, col }
+    }
+}
+
+/// Converts a [`Coord`] into a `(row, col)` tuple.
+impl From<Coord> for (usize, usize) {
+    fn from(coord: Coord) -> Self {
+        (coord.row, coord.col)
+    }
+}
+
+/// Dimensions of a two-dimensional array, given as the number of rows and columns.
+///
+/// Shapes of arrays are returned by their `shape` methods and can be compared directly
+/// to check that two arrays have the same dimensions.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{Array2, Coord, Shape};
+/// let a2 = Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]);
+/// let shape = a2.shape();
+/// assert_eq!(shape, Shape { rows: 2, cols: 3 });
+/// assert_eq!(shape, Array2::new(3, 2, 0).shape());
+/// assert!(shape.contains(Coord::new(1, 2)));
+/// assert!(!shape.contains(Coord::new(2, 0)));
+///
+/// let sum: i32 = shape.coords().filter(|c| c.row == c.col).map(|c| a2[c]).sum();
+/// assert_eq!(sum, 6);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Shape {
+    /// Number of rows.
+    pub rows: usize,
+    /// Number of columns.
+    pub cols: usize,
+}
+
+impl Shape {
+    /// Creates a [`Shape`] with the given number of rows and columns.
+    ///
+    /// Note that the number of rows comes first, unlike in the constructors of [`Array2`](crate::Array2),
+    /// which take `num_cols` and `num_rows` in this order. See [`cols_rows`](Shape::cols_rows)
+    /// for a constructor with the same order as [`Array2`](crate::Array2).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Shape};
+    /// assert_eq!(Shape::new(2, 3), Shape { rows: 2, cols: 3 });
+    /// assert_eq!(Array2::new(3, 2, 0).shape(), Shape::new(2, 3));
+    /// ```
+    pub const fn new(rows: usize, cols: usize) -> Self {
+        Shape { rows, cols }
+    }
+
+    /// Creates a [`Shape`] with the given number of columns and rows,
+    /// in the same order as the arguments of [`Array2::new`](crate::Array2::new).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Shape};
+    /// let (num_cols, num_rows) = (3, 2);
+    /// assert_eq!(Array2::new(num_cols, num_rows, 0).shape(), Shape::cols_rows(num_cols, num_rows));
+    /// assert_eq!(Shape::cols_rows(3, 2), Shape { rows: 2, cols: 3 });
+    /// ```
+    pub const fn cols_rows(cols: usize, rows: usize) -> Self {
+        Shape { rows, cols }
+    }
+
+    /// Returns the number of elements in an array of this shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::Shape;
+    /// assert_eq!(Shape::new(2, 3).num_elements(), 6);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements overflows `usize`, like [`Array2::new`](crate::Array2::new).
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::Shape;
+    /// Shape::new(2, 1 << (usize::BITS - 1)).num_elements();
+    /// ```
+    pub fn num_elements(&self) -> usize {
+        num_elements_or_panic(self.cols, self.rows)
+    }
+
+    /// Returns true if an array of this shape has no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.rows == 0 || self.cols == 0
+    }
+
+    /// Returns true if the given coordinates are within the bounds of this shape.
+    pub const fn contains(&self, coord: Coord) -> bool {
+        coord.row < self.rows && coord.col < self.cols
+    }
+
+    /// Returns an iterator over the coordinates of all elements of this shape,
+    /// in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Coord, Shape};
+    /// let coords: Vec<_> = Shape::new(2, 2).coords().map(<(usize, usize)>::from).collect();
+    /// assert_eq!(coords, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+    /// assert_eq!(Shape::new(3, 0).coords().len(), 0);
+    /// ```
+    pub fn coords(
+        &self,
+    ) -> impl ExactSizeIterator<Item = Coord> + DoubleEndedIterator + FusedIterator {
+        let cols = self.cols;
+        (0..self.num_elements()).map(move |i| Coord::new(i / cols, i % cols))
+    }
+}
@@ -0,0 +1,178 @@
+use std::{iter::FusedIterator, ops::Range};
+
+/// Directed graph in the compressed sparse row (CSR) format, stored as two flat vectors:
+/// the targets of all edges grouped by source node, and the offset of each node's group.
+///
+/// Nodes are identified by indexes in `0..num_nodes`. The graph is immutable once built,
+/// which makes looking up the neighbors of a node a single slice access, without
+/// a separate allocation per node as in an adjacency list of vectors.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::CsrGraph;
+/// let g = CsrGraph::from_edges(4, [(0, 1), (2, 3), (0, 2), (1, 2)]);
+/// assert_eq!(g.neighbors(0), [1, 2]);
+/// assert_eq!(g.neighbors(3), []);
+/// assert_eq!(g.num_edges(), 4);
+///
+/// // Breadth-first search
+/// let mut visited = vec![false; g.num_nodes()];
+/// let mut queue = std::collections::VecDeque::from([0]);
+/// let mut order = Vec::new();
+/// visited[0] = true;
+/// while let Some(node) = queue.pop_front() {
+///     order.push(node);
+///     for &next in g.neighbors(node) {
+///         if !std::mem::replace(&mut visited[next], true) {
+///             queue.push_back(next);
+///         }
+///     }
+/// }
+/// assert_eq!(order, vec![0, 1, 2, 3]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsrGraph {
+    offsets: Box<[usize]>,
+    targets: Box<[usize]>,
+}
+
+impl CsrGraph {
+    /// Creates a [`CsrGraph`] with the given number of nodes from a list of `(source, target)` edges.
+    /// The neighbors of each node are kept in the order in which their edges are listed.
+    ///
+    /// The graph is built in linear time with a counting sort of the edges by source node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::CsrGraph;
+    /// let g = CsrGraph::from_edges(3, [(1, 2), (0, 2), (1, 0)]);
+    /// assert_eq!(g.neighbors(1), [2, 0]);
+    /// assert_eq!(g.neighbors(2), []);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node of an edge is not less than `num_nodes`.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::CsrGraph;
+    /// CsrGraph::from_edges(2, [(0, 2)]);
+    /// ```
+    pub fn from_edges(num_nodes: usize, edges: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        let edges: Vec<(usize, usize)> = edges.into_iter().collect();
+        let mut offsets = vec![0; num_nodes + 1];
+        for &(source, target) in &edges {
+            assert!(
+                source < num_nodes && target < num_nodes,
+                "Edge ({}, {}) is out of bounds for {} nodes",
+                source,
+                target,
+                num_nodes
+            );
+            offsets[source + 1] += 1;
+        }
+        for i in 1..offsets.len() {
+            offsets[i] += offsets[i - 1];
+        }
+        let mut next = offsets.clone();
+        let mut targets = vec![0; edges.len()];
+        for (source, target) in edges {
+            targets[next[source]] = target;
+            next[source] += 1;
+        }
+        CsrGraph {
+            offsets: offsets.into_boxed_slice(),
+            targets: targets.into_boxed_slice(),
+        }
+    }
+
+    /// Returns the number of nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::CsrGraph;
+    /// let g = CsrGraph::from_edges(5, [(0, 1)]);
+    /// assert_eq!(g.num_nodes(), 5);
+    /// ```
+    pub fn num_nodes(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns the number of edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::CsrGraph;
+    /// let g = CsrGraph::from_edges(2, [(0, 1), (1, 0), (0, 1)]);
+    /// assert_eq!(g.num_edges(), 3);
+    /// ```
+    pub fn num_edges(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Returns the range of node indexes, `0..num_nodes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::CsrGraph;
+    /// let g = CsrGraph::from_edges(3, [(0, 1), (0, 2)]);
+    /// let sinks: Vec<_> = g.nodes().filter(|&n| g.degree(n) == 0).collect();
+    /// assert_eq!(sinks, vec![1, 2]);
+    /// ```
+    pub fn nodes(&self) -> Range<usize> {
+        0..self.num_nodes()
+    }
+
+    /// Returns a slice of the underlying buffer with the targets of the edges from the given node.
+    ///
+    /// Panics if the node is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::CsrGraph;
+    /// let g = CsrGraph::from_edges(3, [(0, 2), (0, 1)]);
+    /// assert_eq!(g.neighbors(0), [2, 1]);
+    /// assert!(g.neighbors(1).is_empty());
+    /// ```
+    pub fn neighbors(&self, node: usize) -> &[usize] {
+        &self.targets[self.offsets[node]..self.offsets[node + 1]]
+    }
+
+    /// Returns the number of edges from the given node.
+    ///
+    /// Panics if the node is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::CsrGraph;
+    /// let g = CsrGraph::from_edges(3, [(0, 1), (0, 2), (2, 0)]);
+    /// assert_eq!((g.degree(0), g.degree(1), g.degree(2)), (2, 0, 1));
+    /// ```
+    pub fn degree(&self, node: usize) -> usize {
+        self.offsets[node + 1] - self.offsets[node]
+    }
+
+    /// Returns an iterator over all edges as `(source, target)` pairs, ordered by source node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::CsrGraph;
+    /// let g = CsrGraph::from_edges(3, [(2, 0), (0, 1), (0, 2)]);
+    /// assert_eq!(g.edges().collect::<Vec<_>>(), vec![(0, 1), (0, 2), (2, 0)]);
+    /// ```
+    pub fn edges(&self) -> impl DoubleEndedIterator<Item = (usize, usize)> + FusedIterator + '_ {
+        self.nodes().flat_map(move |source| {
+            self.neighbors(source)
+                .iter()
+                .map(move |&target| (source, target))
+        })
+    }
+}
@@ -0,0 +1,142 @@
+use crate::Array2;
+
+/// Pair of same-shaped [`Array2`]s for simulations that compute each state from the previous one,
+/// such as cellular automata, without allocating a new array for every step.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{Array2, DoubleBuffer};
+/// // Game of Life blinker
+/// let mut life = DoubleBuffer::new(Array2::new_from_rows([
+///     [false, false, false],
+///     [true, true, true],
+///     [false, false, false],
+/// ]));
+/// let step = |src: &Array2<bool>, dst: &mut Array2<bool>| {
+///     for r in 0..src.num_rows() {
+///         for c in 0..src.num_cols() {
+///             let neighbors = (r.saturating_sub(1)..(r + 2).min(src.num_rows()))
+///                 .flat_map(|nr| (c.saturating_sub(1)..(c + 2).min(src.num_cols())).map(move |nc| (nr, nc)))
+///                 .filter(|&(nr, nc)| (nr, nc) != (r, c) && src[nr][nc])
+///                 .count();
+///             dst[r][c] = matches!((src[r][c], neighbors), (true, 2) | (_, 3));
+///         }
+///     }
+/// };
+/// life.step(step);
+/// assert_eq!(life.current().col(1).unwrap().copied().collect::<Vec<_>>(), vec![true, true, true]);
+/// life.step(step);
+/// assert_eq!(life.current()[1], [true, true, true]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DoubleBuffer<T> {
+    front: Array2<T>,
+    back: Array2<T>,
+}
+
+impl<T: Clone> DoubleBuffer<T> {
+    /// Creates a [`DoubleBuffer`] with the given initial state.
+    /// The second buffer is allocated once, as a copy of the initial state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, DoubleBuffer};
+    /// let buf = DoubleBuffer::new(Array2::new_from_rows([[1, 2], [3, 4]]));
+    /// assert_eq!(buf.current(), &Array2::new_from_rows([[1, 2], [3, 4]]));
+    /// ```
+    pub fn new(initial: Array2<T>) -> Self {
+        let back = initial.clone();
+        DoubleBuffer {
+            front: initial,
+            back,
+        }
+    }
+}
+
+impl<T> DoubleBuffer<T> {
+    /// Returns the current state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, DoubleBuffer};
+    /// let mut buf = DoubleBuffer::new(Array2::new(2, 1, 0));
+    /// buf.step(|src, dst| dst.elements_mut().iter_mut().zip(src.elements()).for_each(|(d, s)| *d = s + 1));
+    /// assert_eq!(buf.current()[0], [1, 1]);
+    /// ```
+    pub fn current(&self) -> &Array2<T> {
+        &self.front
+    }
+
+    /// Returns a mutable reference to the current state, e.g. to apply external input between steps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, DoubleBuffer};
+    /// let mut buf = DoubleBuffer::new(Array2::new(3, 1, 0));
+    /// buf.current_mut()[0][1] = 5;
+    /// buf.step(|src, dst| dst[0].copy_from_slice(&src[0]));
+    /// assert_eq!(buf.current()[0], [0, 5, 0]);
+    /// ```
+    pub fn current_mut(&mut self) -> &mut Array2<T> {
+        &mut self.front
+    }
+
+    /// Computes the next state by calling function `f` with the current state and the buffer
+    /// to write the next state into, then makes the latter the current state.
+    ///
+    /// The destination buffer holds the state from before the previous step,
+    /// so `f` should overwrite every element it does not want to carry over.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, DoubleBuffer};
+    /// // Running sum along each row
+    /// let mut buf = DoubleBuffer::new(Array2::new_from_rows([[1, 1, 1]]));
+    /// buf.step(|src, dst| {
+    ///     let mut sum = 0;
+    ///     for (d, s) in dst[0].iter_mut().zip(&src[0]) {
+    ///         sum += s;
+    ///         *d = sum;
+    ///     }
+    /// });
+    /// assert_eq!(buf.current()[0], [1, 2, 3]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `f` replaces the destination buffer with an array of a different shape.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::{Array2, DoubleBuffer};
+    /// let mut buf = DoubleBuffer::new(Array2::new(2, 2, 0));
+    /// buf.step(|_, dst| *dst = Array2::new(3, 2, 0));
+    /// ```
+    pub fn step(&mut self, f: impl FnOnce(&Array2<T>, &mut Array2<T>)) {
+        f(&self.front, &mut self.back);
+        assert!(
+            self.back.num_cols() == self.front.num_cols()
+                && self.back.num_elements() == self.front.num_elements(),
+            "Step must not change the shape of the array"
+        );
+        std::mem::swap(&mut self.front, &mut self.back);
+    }
+
+    /// Consumes the [`DoubleBuffer`] and returns the current state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, DoubleBuffer};
+    /// let mut buf = DoubleBuffer::new(Array2::new(2, 2, false));
+    /// buf.step(|_, dst| dst[1][1] = true);
+    /// assert_eq!(buf.into_current(), Array2::new_from_rows([[false, false], [false, true]]));
+    /// ```
+    pub fn into_current(self) -> Array2<T> {
+        self.front
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{Array2Error, DuplicateKeyError, NotSortedError, OrdVecKey, TransactionError};
+
+/// Error type covering the failures of fallible operations across the crate, for code that
+/// handles errors of several collections uniformly.
+///
+/// The specific error types returned by `try_*` methods convert into it with [`From`],
+/// so they can be propagated with `?`. Details that are kept by the variants can be inspected
+/// with `match`, while details that depend on type parameters, such as the rejected item
+/// of a [`DuplicateKeyError`], are dropped.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{Array2, Error, OrdVec, OrdVecKeyFst};
+/// fn load(rows: Vec<Vec<u32>>) -> Result<(Array2<u32>, OrdVec<(u32, usize), OrdVecKeyFst>), Error> {
+///     let table = Array2::try_new_from_rows(rows)?;
+///     let index = OrdVec::try_new_from_unsorted(table.rows().enumerate().map(|(i, row)| (row[0], i)).collect())?;
+///     Ok((table, index))
+/// }
+///
+/// assert!(load(vec![vec![2, 20], vec![1, 10]]).is_ok());
+/// assert!(matches!(load(vec![vec![1, 10], vec![2]]), Err(Error::ShapeMismatch(_))));
+/// assert_eq!(load(vec![vec![1, 10], vec![1, 20]]), Err(Error::DuplicateKey));
+///
+/// let err = Error::from(Array2::<u32>::try_from_sparse(2, 2, 0, [((0, 3), 1)]).unwrap_err());
+/// assert!(matches!(err, Error::OutOfBounds(_)));
+/// assert!(std::error::Error::source(&err).is_some());
+///
+/// let duplicate = Array2::try_from_sparse(1, 1, 0, [((0, 0), 1), ((0, 0), 2)]).unwrap_err();
+/// assert_eq!(Error::from(duplicate), Error::DuplicateKey);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// An item has the same key as another item.
+    DuplicateKey,
+    /// The dimensions of an [`Array2`](crate::Array2) or of its input are invalid or do not match.
+    /// Contains the error with details.
+    ShapeMismatch(Array2Error),
+    /// An index or position is out of bounds. Contains the error with details.
+    OutOfBounds(Array2Error),
+    /// An argument other than an index or a dimension is invalid, such as a list of indexes
+    /// that is not a permutation. Contains the error with details.
+    InvalidArgument(Array2Error),
+    /// Items are not sorted in strictly ascending order of keys.
+    NotSorted(NotSortedError),
+}
+
+impl From<Array2Error> for Error {
+    fn from(e: Array2Error) -> Self {
+        // No wildcard arm, so that new variants have to be classified here.
+        match e {
+            Array2Error::NumColsMismatch { .. }
+            | Array2Error::NumRowsMismatch { .. }
+            | Array2Error::ShapeMismatch { .. }
+            | Array2Error::RowLengthMismatch { .. }
+            | Array2Error::ZeroColumns { .. }
+            | Array2Error::SizeOverflow { .. }
+            | Array2Error::InvalidLength { .. }
+            | Array2Error::NotEnoughElements { .. }
+            | Array2Error::TooManyElements { .. } => Error::ShapeMismatch(e),
+            Array2Error::PositionOutOfBounds { .. }
+            | Array2Error::IndexOutOfBounds { .. }
+            | Array2Error::RangeOutOfBounds { .. }
+            | Array2Error::RegionOutOfBounds { .. } => Error::OutOfBounds(e),
+            Array2Error::DuplicatePosition { .. } => Error::DuplicateKey,
+            Array2Error::InvalidPermutation { .. }
+            | Array2Error::EmptyWindow { .. }
+            | Array2Error::ElementSizeMismatch { .. } => Error::InvalidArgument(e),
+        }
+    }
+}
+
+impl From<NotSortedError> for Error {
+    fn from(e: NotSortedError) -> Self {
+        Error::NotSorted(e)
+    }
+}
+
+impl<T, K: OrdVecKey<T>> From<DuplicateKeyError<T, K>> for Error {
+    fn from(_: DuplicateKeyError<T, K>) -> Self {
+        Error::DuplicateKey
+    }
+}
+
+impl From<TransactionError> for Error {
+    fn from(_: TransactionError) -> Self {
+        Error::DuplicateKey
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::DuplicateKey => write!(f, "Duplicate key"),
+            Error::ShapeMismatch(e) | Error::OutOfBounds(e) | Error::InvalidArgument(e) => e.fmt(f),
+            Error::NotSorted(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::DuplicateKey => None,
+            Error::ShapeMismatch(e) | Error::OutOfBounds(e) | Error::InvalidArgument(e) => Some(e),
+            Error::NotSorted(e) => Some(e),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{Array2, Array2ColMajor, Array2Fixed};
+
+/// Common interface of two-dimensional arrays, independent of their memory layout.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{Array2, Array2ColMajor, Grid};
+/// fn trace<G: Grid<Element = i32>>(grid: &G) -> i32 {
+///     (0..grid.num_rows().min(grid.num_cols()))
+///         .map(|i| grid.get(i, i).unwrap())
+///         .sum()
+/// }
+///
+/// let a2 = Array2::new_from_rows([[1, 2], [3, 4]]);
+/// assert_eq!(trace(&a2), 5);
+/// assert_eq!(trace(&Array2ColMajor::from(a2)), 5);
+/// ```
+pub trait Grid {
+    /// Type of the elements.
+    type Element;
+
+    /// Returns the number of columns.
+    fn num_cols(&self) -> usize;
+
+    /// Returns the number of rows.
+    fn num_rows(&self) -> usize;
+
+    /// Returns a reference to the element at the given row and column,
+    /// or None if either index is out of bounds.
+    fn get(&self, row_index: usize, col_index: usize) -> Option<&Self::Element>;
+
+    /// Returns a mutable reference to the element at the given row and column,
+    /// or None if either index is out of bounds.
+    fn get_mut(&mut self, row_index: usize, col_index: usize) -> Option<&mut Self::Element>;
+}
+
+impl<T> Grid for Array2<T> {
+    type Element = T;
+
+    fn num_cols(&self) -> usize {
+        Array2::num_cols(self)
+    }
+
+    fn num_rows(&self) -> usize {
+        Array2::num_rows(self)
+    }
+
+    fn get(&self, row_index: usize, col_index: usize) -> Option<&T> {
+        Array2::get(self, row_index, col_index)
+    }
+
+    fn get_mut(&mut self, row_index: usize, col_index: usize) -> Option<&mut T> {
+        Array2::get_mut(self, row_index, col_index)
+    }
+}
+
+impl<T> Grid for Array2ColMajor<T> {
+    type Element = T;
+
+    fn num_cols(&self) -> usize {
+        Array2ColMajor::num_cols(self)
+    }
+
+    fn num_rows(&self) -> usize {
+        Array2ColMajor::num_rows(self)
+    }
+
+    fn get(&self, row_index: usize, col_index: usize) -> Option<&T> {
+        Array2ColMajor::get(self, row_index, col_index)
+    }
+
+    fn get_mut(&mut self, row_index: usize, col_index: usize) -> Option<&mut T> {
+        Array2ColMajor::get_mut(self, row_index, col_index)
+    }
+}
+
+impl<T, const R: usize, const C: usize> Grid for Array2Fixed<T, R, C> {
+    type Element = T;
+
+    fn num_cols(&self) -> usize {
+        C
+    }
+
+    fn num_rows(&self) -> usize {
+        R
+    }
+
+    fn get(&self, row_index: usize, col_index: usize) -> Option<&T> {
+        Array2Fixed::get(self, row_index, col_index)
+    }
+
+    fn get_mut(&mut self, row_index: usize, col_index: usize) -> Option<&mut T> {
+        Array2Fixed::get_mut(self, row_index, col_index)
+    }
+}
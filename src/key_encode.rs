@@ -0,0 +1,258 @@
+use crate::{OrdVec, OrdVecKey};
+
+/// Keys that can be encoded into bytes whose lexicographic order matches the order of the keys
+/// (a "memcomparable" encoding), so that composite keys can be compared with a single `memcmp`.
+///
+/// Implementations must uphold `a.cmp(b) == a.to_encoded_key().cmp(&b.to_encoded_key())`.
+/// The encodings provided by this crate are also self-delimiting, which makes
+/// the encoding of a tuple the concatenation of the encodings of its fields:
+/// * unsigned integers are stored big-endian, signed integers additionally have their sign bit flipped,
+///   `usize` and `isize` are stored as 64-bit integers,
+/// * `bool` is stored as a single byte and `char` as a big-endian `u32`,
+/// * strings and byte strings have each zero byte escaped as `0x00 0xFF` and are terminated by `0x00 0x01`,
+/// * `None` is stored as `0x00` and `Some` as `0x01` followed by the encoding of the value.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::KeyEncode;
+/// let a = (7u32, "apple".to_string(), 2u16);
+/// let b = (7u32, "apple pie".to_string(), 1u16);
+/// assert!(a < b);
+/// assert!(a.to_encoded_key() < b.to_encoded_key());
+/// ```
+pub trait KeyEncode {
+    /// Appends the encoding of the key to the buffer.
+    fn encode_key(&self, out: &mut Vec<u8>);
+
+    /// Returns the encoding of the key in a new buffer.
+    fn to_encoded_key(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode_key(&mut out);
+        out
+    }
+}
+
+macro_rules! impl_key_encode_unsigned {
+    ($($int:ty),*) => {
+        $(
+            impl KeyEncode for $int {
+                fn encode_key(&self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_be_bytes());
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_key_encode_signed {
+    ($($int:ty => $uint:ty),*) => {
+        $(
+            impl KeyEncode for $int {
+                fn encode_key(&self, out: &mut Vec<u8>) {
+                    let flipped = (*self as $uint) ^ (1 << (<$uint>::BITS - 1));
+                    out.extend_from_slice(&flipped.to_be_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_key_encode_unsigned!(u8, u16, u32, u64, u128);
+impl_key_encode_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128);
+
+impl KeyEncode for usize {
+    fn encode_key(&self, out: &mut Vec<u8>) {
+        (*self as u64).encode_key(out)
+    }
+}
+
+impl KeyEncode for isize {
+    fn encode_key(&self, out: &mut Vec<u8>) {
+        (*self as i64).encode_key(out)
+    }
+}
+
+impl KeyEncode for bool {
+    fn encode_key(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+}
+
+impl KeyEncode for char {
+    fn encode_key(&self, out: &mut Vec<u8>) {
+        (*self as u32).encode_key(out)
+    }
+}
+
+impl KeyEncode for [u8] {
+    fn encode_key(&self, out: &mut Vec<u8>) {
+        for &b in self {
+            out.push(b);
+            if b == 0 {
+                out.push(0xFF);
+            }
+        }
+        out.extend_from_slice(&[0x00, 0x01]);
+    }
+}
+
+impl KeyEncode for str {
+    fn encode_key(&self, out: &mut Vec<u8>) {
+        self.as_bytes().encode_key(out)
+    }
+}
+
+impl KeyEncode for Vec<u8> {
+    fn encode_key(&self, out: &mut Vec<u8>) {
+        self[..].encode_key(out)
+    }
+}
+
+impl KeyEncode for String {
+    fn encode_key(&self, out: &mut Vec<u8>) {
+        self.as_bytes().encode_key(out)
+    }
+}
+
+impl<T: KeyEncode + ?Sized> KeyEncode for &T {
+    fn encode_key(&self, out: &mut Vec<u8>) {
+        (**self).encode_key(out)
+    }
+}
+
+impl<T: KeyEncode> KeyEncode for Option<T> {
+    fn encode_key(&self, out: &mut Vec<u8>) {
+        match self {
+            None => out.push(0x00),
+            Some(v) => {
+                out.push(0x01);
+                v.encode_key(out);
+            }
+        }
+    }
+}
+
+macro_rules! impl_key_encode_tuple {
+    ($(($($name:ident),+)),*) => {
+        $(
+            impl<$($name: KeyEncode),+> KeyEncode for ($($name,)+) {
+                #[allow(non_snake_case)]
+                fn encode_key(&self, out: &mut Vec<u8>) {
+                    let ($($name,)+) = self;
+                    $($name.encode_key(out);)+
+                }
+            }
+        )*
+    };
+}
+
+impl_key_encode_tuple!((A), (A, B), (A, B, C), (A, B, C, D));
+
+/// Encoded keys of an [`OrdVec`] stored contiguously in a single buffer, enabling binary search
+/// by comparing byte strings instead of comparing composite keys field by field.
+///
+/// The keys are a snapshot: [`EncodedKeys`] is not updated when the [`OrdVec`] is modified,
+/// so it is best suited for collections that are built once and queried many times.
+///
+/// Binary search relies on the encoded keys following the [`Ord`] order of the keys,
+/// so the key function of the [`OrdVec`] must not override
+/// [`OrdVecKey::cmp_keys`](trait.OrdVecKey.html#method.cmp_keys).
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{EncodedKeys, OrdVec, OrdVecKeyFst};
+/// let ov: OrdVec<_, OrdVecKeyFst> = vec![
+///     ((2u32, "b".to_string(), 1u16), "third"),
+///     ((1, "b".to_string(), 5), "second"),
+///     ((1, "a".to_string(), 9), "first"),
+/// ].into();
+/// let keys = EncodedKeys::new(&ov);
+/// assert_eq!(keys.len(), 3);
+/// let i = keys.get_index_by_key(&(1u32, "b".to_string(), 5u16)).unwrap();
+/// assert_eq!(ov[i].1, "second");
+/// // Borrowed fields have the same encoding as owned ones
+/// assert_eq!(keys.get_index_by_key(&(1u32, "b", 5u16)), Some(i));
+/// assert_eq!(keys.get_index_by_key(&(1u32, "b", 6u16)), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodedKeys {
+    bytes: Vec<u8>,
+    ends: Vec<usize>,
+}
+
+impl EncodedKeys {
+    /// Encodes the keys of all items of the [`OrdVec`], in order.
+    pub fn new<T, K: OrdVecKey<T>>(ordvec: &OrdVec<T, K>) -> Self
+    where
+        K::Key: KeyEncode,
+    {
+        let mut bytes = Vec::new();
+        let mut ends = Vec::with_capacity(ordvec.len());
+        for item in ordvec.iter() {
+            K::get_key(item).encode_key(&mut bytes);
+            ends.push(bytes.len());
+        }
+        EncodedKeys { bytes, ends }
+    }
+
+    /// Returns the number of keys.
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns `true` if there are no keys.
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Returns the encoded key of the item at the given index of the [`OrdVec`],
+    /// or None if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{EncodedKeys, OrdVec, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![((1u8, true), ()), ((0, false), ())].into();
+    /// let keys = EncodedKeys::new(&ov);
+    /// assert_eq!(keys.get(1), Some(&[1, 1][..]));
+    /// assert_eq!(keys.get(2), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&[u8]> {
+        let end = *self.ends.get(index)?;
+        let start = index.checked_sub(1).map_or(0, |i| self.ends[i]);
+        Some(&self.bytes[start..end])
+    }
+
+    /// Returns the index of the item with the given key in the [`OrdVec`] the keys were encoded from.
+    pub fn get_index_by_key<Q: KeyEncode + ?Sized>(&self, key: &Q) -> Option<usize> {
+        self.get_index_by_encoded_key(&key.to_encoded_key())
+    }
+
+    /// Returns the index of the item with the given encoded key in the [`OrdVec`]
+    /// the keys were encoded from. Reusing the encoding of a key avoids
+    /// an allocation when it is looked up repeatedly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{EncodedKeys, KeyEncode, OrdVec, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![((-1i32, 'x'), 10), ((-2, 'y'), 20)].into();
+    /// let keys = EncodedKeys::new(&ov);
+    /// let encoded = (-1i32, 'x').to_encoded_key();
+    /// assert_eq!(keys.get_index_by_encoded_key(&encoded), Some(1));
+    /// ```
+    pub fn get_index_by_encoded_key(&self, encoded: &[u8]) -> Option<usize> {
+        let (mut lo, mut hi) = (0, self.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match self.get(mid).unwrap().cmp(encoded) {
+                std::cmp::Ordering::Less => lo = mid + 1,
+                std::cmp::Ordering::Greater => hi = mid,
+                std::cmp::Ordering::Equal => return Some(mid),
+            }
+        }
+        None
+    }
+}
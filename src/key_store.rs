@@ -0,0 +1,122 @@
+use std::{collections::HashSet, hash::BuildHasher, rc::Rc, sync::Arc};
+
+use crate::{DuplicateKeyError, OrdVec, OrdVecKey};
+
+/// Interning hook that deduplicates the storage of keys with equal contents,
+/// used when items are added to an [`OrdVec`] with
+/// [`try_insert_interned`](struct.OrdVec.html#method.try_insert_interned) or
+/// [`try_from_iter_interned`](struct.OrdVec.html#method.try_from_iter_interned).
+///
+/// Since keys are borrowed from the items, the store cannot share them directly. Instead,
+/// [`intern`](KeyStore::intern) replaces the shareable parts of each item, such as an [`Rc<str>`],
+/// with a shared copy of equal contents seen before, so that only one allocation is kept per distinct value.
+///
+/// Implementations are provided for [`HashSet`]s of [`Rc<str>`] and [`Arc<str>`],
+/// interning items that are such strings or `(string, value)` tuples.
+/// Interning must not change the ordering of the key, otherwise lookups may fail to find items.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{KeyStore, OrdVec, OrdVecKey};
+/// # use std::{collections::HashSet, rc::Rc};
+/// struct Entry { path: (Rc<str>, String), size: u64 }
+///
+/// struct DirStore(HashSet<Rc<str>>);
+/// impl KeyStore<Entry> for DirStore {
+///     fn intern(&mut self, entry: Entry) -> Entry {
+///         let (dir, name) = entry.path;
+///         Entry { path: (self.0.intern(dir), name), size: entry.size }
+///     }
+/// }
+///
+/// struct PathKey;
+/// impl OrdVecKey<Entry> for PathKey {
+///     type Key = (Rc<str>, String);
+///     fn get_key(e: &Entry) -> &Self::Key { &e.path }
+/// }
+///
+/// let mut store = DirStore(HashSet::new());
+/// let entries = [("/usr/lib", "b.so", 20), ("/usr/lib", "a.so", 10)]
+///     .map(|(dir, name, size)| Entry { path: (dir.into(), name.into()), size });
+/// let ov: OrdVec<Entry, PathKey> = OrdVec::try_from_iter_interned(entries, &mut store).unwrap();
+/// assert_eq!(ov[0].size, 10);
+/// assert!(Rc::ptr_eq(&ov[0].path.0, &ov[1].path.0));
+/// ```
+pub trait KeyStore<T> {
+    /// Returns the item with the shareable parts of its key replaced by shared copies of equal contents
+    /// stored earlier, storing the parts that are new.
+    fn intern(&mut self, item: T) -> T;
+}
+
+macro_rules! impl_key_store_for_shared_str {
+    ($($ptr:ident),*) => {$(
+        impl<S: BuildHasher> KeyStore<$ptr<str>> for HashSet<$ptr<str>, S> {
+            fn intern(&mut self, s: $ptr<str>) -> $ptr<str> {
+                match self.get(&*s) {
+                    Some(shared) => shared.clone(),
+                    None => {
+                        self.insert(s.clone());
+                        s
+                    }
+                }
+            }
+        }
+
+        impl<V, S: BuildHasher> KeyStore<($ptr<str>, V)> for HashSet<$ptr<str>, S> {
+            fn intern(&mut self, (s, v): ($ptr<str>, V)) -> ($ptr<str>, V) {
+                (self.intern(s), v)
+            }
+        }
+    )*};
+}
+
+impl_key_store_for_shared_str!(Rc, Arc);
+
+impl<T, K: OrdVecKey<T>> OrdVec<T, K> {
+    /// Interns the item with the given [`KeyStore`] and inserts it, or returns it in an error
+    /// if an item with the same key is already present. See [`try_insert`](struct.OrdVec.html#method.try_insert).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+    /// # use std::{collections::HashSet, rc::Rc};
+    /// let mut store: HashSet<Rc<str>> = HashSet::new();
+    /// let mut ov: OrdVec<(Rc<str>, u32), OrdVecKeyFst> = OrdVec::new();
+    /// ov.try_insert_interned(("a".into(), 1), &mut store).unwrap();
+    /// let err = ov.try_insert_interned(("a".into(), 2), &mut store).unwrap_err();
+    /// assert!(Rc::ptr_eq(&err.into_item().0, &ov[0].0));
+    /// ```
+    pub fn try_insert_interned(
+        &mut self,
+        item: T,
+        store: &mut impl KeyStore<T>,
+    ) -> Result<(), DuplicateKeyError<T, K>> {
+        self.try_insert(store.intern(item))
+    }
+
+    /// Creates an [`OrdVec`] from the items of the iterator, interning each item with the given
+    /// [`KeyStore`] as it is collected, or returns an error with one of the items that have a duplicate key.
+    /// See [`try_new_from_unsorted`](struct.OrdVec.html#method.try_new_from_unsorted).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyIdentity};
+    /// # use std::{collections::HashSet, sync::Arc};
+    /// let mut store: HashSet<Arc<str>> = HashSet::new();
+    /// let tags: OrdVec<Arc<str>, OrdVecKeyIdentity> =
+    ///     OrdVec::try_from_iter_interned(["b", "a"].map(Arc::from), &mut store).unwrap();
+    /// let more: OrdVec<Arc<str>, OrdVecKeyIdentity> =
+    ///     OrdVec::try_from_iter_interned(["a", "c"].map(Arc::from), &mut store).unwrap();
+    /// assert!(Arc::ptr_eq(&tags[0], &more[0]));
+    /// assert_eq!(store.len(), 3);
+    /// ```
+    pub fn try_from_iter_interned(
+        iter: impl IntoIterator<Item = T>,
+        store: &mut impl KeyStore<T>,
+    ) -> Result<Self, DuplicateKeyError<T, K>> {
+        OrdVec::try_new_from_unsorted(iter.into_iter().map(|item| store.intern(item)).collect())
+    }
+}
@@ -3,8 +3,89 @@
 #![forbid(unsafe_code)]
 #![deny(missing_docs)]
 
-pub use array2::Array2;
-pub use ordvec::{OrdVec, OrdVecKey, OrdVecKeyFst};
+pub use array2::{Array2, Array2Error, ParseGridError};
+pub use array2_col_major::Array2ColMajor;
+pub use array2_col_mut::ColMut;
+pub use array2_display::Array2Display;
+pub use array2_fixed::Array2Fixed;
+pub use array2_pool::Array2Pool;
+pub use array2_view::Array2View;
+pub use atomic_array2::{AtomicArray2, AtomicElement};
+pub use bounded_ordvec::{BoundedOrdVec, EvictionPolicy};
+pub use cached_ordvec::CachedOrdVec;
+pub use coord::{Coord, Shape};
+pub use csr_graph::CsrGraph;
+pub use double_buffer::DoubleBuffer;
+pub use error::Error;
+pub use grid::Grid;
+pub use key_encode::{EncodedKeys, KeyEncode};
+pub use key_store::KeyStore;
+#[cfg(feature = "math")]
+pub use math::ElementwiseOp;
+pub use ordslice::OrdSlice;
+pub use ordvec::{
+    DuplicateKeyError, FromSortedIterator, NotSortedError, OrdVec, OrdVecDiff, OrdVecKey,
+    OrdVecKeyCol, OrdVecKeyFst, OrdVecKeyIdentity, OrdVecKeyRev,
+};
+pub use ordvec_2k::OrdVec2K;
+pub use ordvec_batch::BatchInsert;
+pub use ordvec_builder::OrdVecBuilder;
+pub use ordvec_cursor::CursorMut;
+pub use ordvec_index::OrdIndex;
+pub use ordvec_soa::OrdVecSoA;
+pub use partial_array2::{MissingRows, PartialArray2};
+pub use ring_array::RingArray;
+pub use slot_vec::{SlotKey, SlotVec};
+#[cfg(feature = "smallvec")]
+pub use small_ordvec::OrdSmallVec;
+pub use snapshot::{SnapshotCodec, SnapshotError};
+pub use sorted_rows::SortedRows;
+pub use sparse_grid::SparseGrid;
+pub use str_arena::{StrArena, StrId};
+pub use transaction::{OrdVecChanges, OrdVecTransaction, TransactionError};
 
 mod array2;
+mod array2_col_major;
+mod array2_col_mut;
+mod array2_display;
+mod array2_fixed;
+#[cfg(feature = "mint")]
+mod array2_mint;
+#[cfg(feature = "nalgebra")]
+mod array2_nalgebra;
+mod array2_ops;
+mod array2_pool;
+mod array2_reduce;
+mod array2_view;
+mod atomic_array2;
+mod bounded_ordvec;
+mod cached_ordvec;
+mod coord;
+mod csr_graph;
+mod double_buffer;
+mod error;
+mod grid;
+mod key_encode;
+mod key_store;
+#[cfg(feature = "math")]
+mod math;
+mod ordslice;
 mod ordvec;
+mod ordvec_2k;
+mod ordvec_batch;
+mod ordvec_builder;
+mod ordvec_cursor;
+mod ordvec_index;
+mod ordvec_soa;
+mod partial_array2;
+mod ring_array;
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
+mod slot_vec;
+#[cfg(feature = "smallvec")]
+mod small_ordvec;
+mod snapshot;
+mod sorted_rows;
+mod sparse_grid;
+mod str_arena;
+mod transaction;
@@ -0,0 +1,157 @@
+use std::ops::RangeInclusive;
+
+use crate::Array2;
+
+/// Elementwise operation on floating-point elements of an [`Array2`].
+///
+/// See [`apply_op`](struct.Array2.html#method.apply_op) and [`apply_ops`](struct.Array2.html#method.apply_ops).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ElementwiseOp<F> {
+    /// Replaces each element with its absolute value.
+    Abs,
+    /// Restricts each element to the interval `[min, max]`. Panics if `min > max` or either bound is NaN.
+    Clamp(F, F),
+    /// Replaces each element with the minimum of the element and the given value.
+    Min(F),
+    /// Replaces each element with the maximum of the element and the given value.
+    Max(F),
+    /// Replaces each element with `e` raised to the power of the element.
+    Exp,
+    /// Replaces each element with its square root.
+    Sqrt,
+}
+
+/// Number of elements processed by all operations in [`Array2::apply_ops`]
+/// before moving on, chosen to keep the block in L1 cache.
+const BLOCK_LEN: usize = 1024;
+
+/// Returns `(scale, offset)` such that `x * scale + offset` maps `src` onto `dst` linearly.
+macro_rules! remap_coefficients {
+    ($float:ident, $src:expr, $dst:expr) => {{
+        let (src_start, src_end) = $src.into_inner();
+        let (dst_start, dst_end) = $dst.into_inner();
+        assert!(src_start != src_end, "Source range must not be empty");
+        let scale: $float = (dst_end - dst_start) / (src_end - src_start);
+        (scale, dst_start - src_start * scale)
+    }};
+}
+
+macro_rules! impl_elementwise_ops {
+    ($($float:ident),*) => {
+        $(
+            impl ElementwiseOp<$float> {
+                /// Applies the operation to each element of the slice. The operation is matched once
+                /// per slice so that each arm compiles to a tight, vectorizable loop.
+                #[inline(always)]
+                fn apply_to_slice(self, data: &mut [$float]) {
+                    match self {
+                        ElementwiseOp::Abs => data.iter_mut().for_each(|x| *x = x.abs()),
+                        ElementwiseOp::Clamp(min, max) => data.iter_mut().for_each(|x| *x = x.clamp(min, max)),
+                        ElementwiseOp::Min(v) => data.iter_mut().for_each(|x| *x = x.min(v)),
+                        ElementwiseOp::Max(v) => data.iter_mut().for_each(|x| *x = x.max(v)),
+                        ElementwiseOp::Exp => data.iter_mut().for_each(|x| *x = x.exp()),
+                        ElementwiseOp::Sqrt => data.iter_mut().for_each(|x| *x = x.sqrt()),
+                    }
+                }
+            }
+
+            impl Array2<$float> {
+                /// Applies the operation to each element in place.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use contiguous_collections::{Array2, ElementwiseOp};
+                #[doc = concat!("let mut a2: Array2<", stringify!($float), "> = Array2::new_from_rows([[-4.0, 9.0], [1.0, -16.0]]);")]
+                /// a2.apply_op(ElementwiseOp::Abs);
+                /// assert_eq!(a2, Array2::new_from_rows([[4.0, 9.0], [1.0, 16.0]]));
+                /// ```
+                pub fn apply_op(&mut self, op: ElementwiseOp<$float>) {
+                    op.apply_to_slice(&mut self.data);
+                }
+
+                /// Applies the sequence of operations to each element in place,
+                /// equivalent to calling [`apply_op`](struct.Array2.html#method.apply_op)
+                /// for each operation in order.
+                ///
+                /// The operations are applied to small blocks of the underlying buffer at a time,
+                /// avoiding a separate pass over the whole array for every operation.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use contiguous_collections::{Array2, ElementwiseOp};
+                #[doc = concat!("let mut a2: Array2<", stringify!($float), "> = Array2::new_from_rows([[-4.0, 9.0], [1.0, -16.0]]);")]
+                /// a2.apply_ops(&[ElementwiseOp::Abs, ElementwiseOp::Sqrt, ElementwiseOp::Clamp(1.5, 3.5)]);
+                /// assert_eq!(a2, Array2::new_from_rows([[2.0, 3.0], [1.5, 3.5]]));
+                /// ```
+                pub fn apply_ops(&mut self, ops: &[ElementwiseOp<$float>]) {
+                    for block in self.data.chunks_mut(BLOCK_LEN) {
+                        for &op in ops {
+                            op.apply_to_slice(block);
+                        }
+                    }
+                }
+
+                /// Maps each element linearly from the source range to the destination range in place,
+                /// so that `src.start()` becomes `dst.start()` and `src.end()` becomes `dst.end()`.
+                /// Elements outside of the source range are extrapolated;
+                /// see [`remap_values_clamped`](struct.Array2.html#method.remap_values_clamped) to clamp them instead.
+                ///
+                /// Either range may be reversed to invert the values.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use contiguous_collections::Array2;
+                #[doc = concat!("let mut a2: Array2<", stringify!($float), "> = Array2::new_from_rows([[-20.0, 0.0], [40.0, 60.0]]);")]
+                /// a2.remap_values(0.0..=40.0, 0.0..=1.0);
+                /// assert_eq!(a2, Array2::new_from_rows([[-0.5, 0.0], [1.0, 1.5]]));
+                /// ```
+                ///
+                /// # Panics
+                ///
+                /// Panics if the source range is empty, i.e. its start is equal to its end.
+                pub fn remap_values(&mut self, src: RangeInclusive<$float>, dst: RangeInclusive<$float>) {
+                    let (scale, offset) = remap_coefficients!($float, src, dst);
+                    self.data.iter_mut().for_each(|x| *x = *x * scale + offset);
+                }
+
+                /// Maps each element linearly from the source range to the destination range in place,
+                /// like [`remap_values`](struct.Array2.html#method.remap_values), clamping the results
+                /// to the destination range.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use contiguous_collections::Array2;
+                #[doc = concat!("let mut a2: Array2<", stringify!($float), "> = Array2::new_from_rows([[-20.0, 0.0], [40.0, 60.0]]);")]
+                /// a2.remap_values_clamped(0.0..=40.0, 1.0..=0.0);
+                /// assert_eq!(a2, Array2::new_from_rows([[1.0, 1.0], [0.0, 0.0]]));
+                /// ```
+                ///
+                /// # Panics
+                ///
+                /// Panics if the source range is empty, i.e. its start is equal to its end,
+                /// or either bound of the destination range is NaN.
+                ///
+                /// ```should_panic
+                /// # use contiguous_collections::Array2;
+                #[doc = concat!("let mut a2: Array2<", stringify!($float), "> = Array2::new_from_rows([[1.0, 2.0]]);")]
+                #[doc = concat!("a2.remap_values_clamped(0.0..=1.0, 0.0..=", stringify!($float), "::NAN);")]
+                /// ```
+                pub fn remap_values_clamped(&mut self, src: RangeInclusive<$float>, dst: RangeInclusive<$float>) {
+                    assert!(
+                        !dst.start().is_nan() && !dst.end().is_nan(),
+                        "Destination range bounds must not be NaN"
+                    );
+                    let (min, max) = (dst.start().min(*dst.end()), dst.start().max(*dst.end()));
+                    let (scale, offset) = remap_coefficients!($float, src, dst);
+                    self.data.iter_mut().for_each(|x| *x = (*x * scale + offset).clamp(min, max));
+                }
+            }
+        )*
+    };
+}
+
+impl_elementwise_ops!(f32, f64);
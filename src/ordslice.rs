@@ -0,0 +1,281 @@
+use std::{
+    cmp::Ordering,
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Bound, Range, RangeBounds},
+};
+
+use crate::{ordvec::find_unsorted, NotSortedError, OrdVec, OrdVecKey};
+
+/// Borrowed slice of items sorted in strictly ascending order of keys, with the read-only
+/// lookup methods of [`OrdVec`].
+///
+/// An [`OrdSlice`] is returned by [`OrdVec::as_ord_slice`] or created from any slice that is
+/// sorted externally, so functions accepting it do not depend on where the items are stored.
+/// Range queries return an [`OrdSlice`] as well, without allocating.
+///
+/// Methods of the underlying slice are available through [`Deref`](std::ops::Deref).
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdSlice, OrdVec, OrdVecKeyFst};
+/// fn total_between(prices: OrdSlice<(u32, u64), OrdVecKeyFst>, from: u32, to: u32) -> u64 {
+///     prices.range_by_key(from..to).iter().map(|&(_, price)| price).sum()
+/// }
+///
+/// let ov: OrdVec<_, OrdVecKeyFst> = vec![(3, 30), (1, 10), (2, 20)].into();
+/// assert_eq!(total_between(ov.as_ord_slice(), 2, 4), 50);
+///
+/// let sorted = [(1, 5), (5, 7), (9, 11)];
+/// let slice = OrdSlice::new(&sorted).unwrap();
+/// assert_eq!(total_between(slice, 0, 9), 12);
+/// assert_eq!(slice.get_by_key(&9), Some(&(9, 11)));
+/// ```
+pub struct OrdSlice<'a, T, K: OrdVecKey<T>>(&'a [T], PhantomData<K>);
+
+impl<'a, T, K: OrdVecKey<T>> OrdSlice<'a, T, K> {
+    /// Wraps the given slice, which must be sorted in strictly ascending order of keys.
+    ///
+    /// The items are checked in a single linear pass. If the check fails,
+    /// the error contains the index of the first item that is not smaller than the next one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdSlice, OrdVecKeyFst};
+    /// assert!(OrdSlice::<_, OrdVecKeyFst>::new(&[(0, "A"), (1, "B")]).is_ok());
+    /// assert_eq!(OrdSlice::<_, OrdVecKeyFst>::new(&[(1, "B"), (0, "A")]).unwrap_err().index, 0);
+    /// ```
+    pub fn new(items: &'a [T]) -> Result<Self, NotSortedError> {
+        match find_unsorted::<T, K>(items) {
+            Some(index) => Err(NotSortedError { index }),
+            None => Ok(OrdSlice(items, PhantomData)),
+        }
+    }
+
+    /// Wraps the given slice without checking that it is sorted in strictly ascending order of keys.
+    ///
+    /// The order is verified in debug builds only. If the slice is not sorted,
+    /// lookups may fail to find existing items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdSlice, OrdVecKeyIdentity};
+    /// let items = [2, 4, 8];
+    /// let slice = OrdSlice::<_, OrdVecKeyIdentity>::new_unchecked(&items);
+    /// assert!(slice.contains_key(&4));
+    /// ```
+    pub fn new_unchecked(items: &'a [T]) -> Self {
+        debug_assert!(
+            find_unsorted::<T, K>(items).is_none(),
+            "Items must be sorted in strictly ascending order of keys"
+        );
+        OrdSlice(items, PhantomData)
+    }
+
+    /// Returns the underlying slice with the lifetime of the borrowed items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdSlice, OrdVecKeyIdentity};
+    /// let items = [1, 2, 3];
+    /// let tail: &[i32] = {
+    ///     let slice = OrdSlice::<_, OrdVecKeyIdentity>::new(&items).unwrap();
+    ///     slice.range_by_key(2..).as_slice()
+    /// };
+    /// assert_eq!(tail, [2, 3]);
+    /// ```
+    pub fn as_slice(&self) -> &'a [T] {
+        self.0
+    }
+
+    /// Returns an iterator over the keys of the items in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdSlice, OrdVecKeyFst};
+    /// let items = [(1, "A"), (4, "B")];
+    /// let slice = OrdSlice::<_, OrdVecKeyFst>::new(&items).unwrap();
+    /// assert_eq!(slice.keys().collect::<Vec<_>>(), vec![&1, &4]);
+    /// ```
+    pub fn keys(
+        &self,
+    ) -> impl ExactSizeIterator<Item = &'a K::Key> + DoubleEndedIterator + FusedIterator
+    where
+        K::Key: 'a,
+    {
+        self.0.iter().map(K::get_key)
+    }
+
+    /// Looks up an item by key using binary search.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdSlice, OrdVecKeyFst};
+    /// let items = [(1, "A"), (4, "B")];
+    /// let slice = OrdSlice::<_, OrdVecKeyFst>::new(&items).unwrap();
+    /// assert_eq!(slice.get_by_key(&4), Some(&(4, "B")));
+    /// assert_eq!(slice.get_by_key(&2), None);
+    /// ```
+    pub fn get_by_key(&self, k: &K::Key) -> Option<&'a T> {
+        self.get_index_by_key(k).map(|i| &self.0[i])
+    }
+
+    /// Returns the index of the item with the given key, or None if such an item is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdSlice, OrdVecKeyFst};
+    /// let items = [(1, "A"), (4, "B"), (6, "C")];
+    /// let slice = OrdSlice::<_, OrdVecKeyFst>::new(&items).unwrap();
+    /// assert_eq!(slice.get_index_by_key(&6), Some(2));
+    /// assert_eq!(slice.get_index_by_key(&5), None);
+    /// ```
+    pub fn get_index_by_key(&self, k: &K::Key) -> Option<usize> {
+        self.0
+            .binary_search_by(|item| K::cmp_keys(K::get_key(item), k))
+            .ok()
+    }
+
+    /// Returns true if an item with the given key is present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdSlice, OrdVecKeyIdentity};
+    /// let items = ["ant", "bee"];
+    /// let slice = OrdSlice::<_, OrdVecKeyIdentity>::new(&items).unwrap();
+    /// assert!(slice.contains_key(&"bee"));
+    /// assert!(!slice.contains_key(&"cat"));
+    /// ```
+    pub fn contains_key(&self, k: &K::Key) -> bool {
+        self.get_index_by_key(k).is_some()
+    }
+
+    /// Returns the items with keys in the given range. See [`OrdVec::range_by_key`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdSlice, OrdVecKeyIdentity};
+    /// let items = [1, 3, 5, 7, 9];
+    /// let slice: OrdSlice<_, OrdVecKeyIdentity> = OrdSlice::new(&items).unwrap();
+    /// let middle = slice.range_by_key(3..=7);
+    /// assert_eq!(middle[..], [3, 5, 7]);
+    /// assert_eq!(middle.range_by_key(..5)[..], [3]);
+    /// ```
+    pub fn range_by_key(&self, range: impl RangeBounds<K::Key>) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(k) => self
+                .0
+                .partition_point(|item| K::cmp_keys(K::get_key(item), k).is_lt()),
+            Bound::Excluded(k) => self
+                .0
+                .partition_point(|item| K::cmp_keys(K::get_key(item), k).is_le()),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(k) => self
+                .0
+                .partition_point(|item| K::cmp_keys(K::get_key(item), k).is_le()),
+            Bound::Excluded(k) => self
+                .0
+                .partition_point(|item| K::cmp_keys(K::get_key(item), k).is_lt()),
+            Bound::Unbounded => self.0.len(),
+        };
+        OrdSlice(&self.0[start..end.max(start)], PhantomData)
+    }
+
+    /// Returns the range of indexes of the items whose keys compare as [`Ordering::Equal`]
+    /// according to function `f`. See [`OrdVec::get_index_range_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdSlice, OrdVecKeyIdentity};
+    /// let items = [(1, 'a'), (2, 'a'), (2, 'b'), (3, 'a')];
+    /// let slice = OrdSlice::<_, OrdVecKeyIdentity>::new(&items).unwrap();
+    /// assert_eq!(slice.get_index_range_by(|&(major, _)| major.cmp(&2)), 1..3);
+    /// assert_eq!(slice.get_index_range_by(|&(major, _)| major.cmp(&5)), 4..4);
+    /// ```
+    pub fn get_index_range_by(&self, mut f: impl FnMut(&K::Key) -> Ordering) -> Range<usize> {
+        let start = self
+            .0
+            .partition_point(|item| f(K::get_key(item)) == Ordering::Less);
+        let len = self.0[start..].partition_point(|item| f(K::get_key(item)) == Ordering::Equal);
+        start..start + len
+    }
+
+    /// Returns the items whose keys compare as [`Ordering::Equal`] according to function `f`.
+    /// See [`OrdVec::range_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdSlice, OrdVecKeyIdentity};
+    /// let items = ["apple", "banana", "blueberry", "cherry"];
+    /// let slice = OrdSlice::<_, OrdVecKeyIdentity>::new(&items).unwrap();
+    /// let b = slice.range_by(|name| name[..1].cmp("b"));
+    /// assert_eq!(b[..], ["banana", "blueberry"]);
+    /// ```
+    pub fn range_by(&self, f: impl FnMut(&K::Key) -> Ordering) -> Self {
+        OrdSlice(&self.0[self.get_index_range_by(f)], PhantomData)
+    }
+}
+
+impl<T, K: OrdVecKey<T>> OrdVec<T, K> {
+    /// Returns a borrowed [`OrdSlice`] view of all items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyIdentity};
+    /// let ov: OrdVec<_, OrdVecKeyIdentity> = OrdVec::from_sorted_vec(vec![1, 2, 3]).unwrap();
+    /// let slice = ov.as_ord_slice();
+    /// assert_eq!(slice.range_by_key(2..)[..], [2, 3]);
+    /// ```
+    pub fn as_ord_slice(&self) -> OrdSlice<'_, T, K> {
+        OrdSlice(&self.0, PhantomData)
+    }
+}
+
+impl<'a, T, K: OrdVecKey<T>> From<&'a OrdVec<T, K>> for OrdSlice<'a, T, K> {
+    fn from(ordvec: &'a OrdVec<T, K>) -> Self {
+        ordvec.as_ord_slice()
+    }
+}
+
+impl<T, K: OrdVecKey<T>> Clone for OrdSlice<'_, T, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, K: OrdVecKey<T>> Copy for OrdSlice<'_, T, K> {}
+
+impl<T: std::fmt::Debug, K: OrdVecKey<T>> std::fmt::Debug for OrdSlice<'_, T, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_tuple("OrdSlice").field(&self.0).finish()
+    }
+}
+
+impl<T, K: OrdVecKey<T>> std::ops::Deref for OrdSlice<'_, T, K> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        self.0
+    }
+}
+
+impl<'a, T, K: OrdVecKey<T>> IntoIterator for OrdSlice<'a, T, K> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
@@ -1,17 +1,90 @@
-use std::marker::PhantomData;
+use std::{
+    cmp::Ordering,
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Range, RangeBounds},
+};
+
+use crate::{ordvec_index::Generation, CursorMut};
 
 /// Ordered [`Vec<T>`] intended for fast lookup of items by key.
 ///
 /// The key is stored inside `T` and extracted with the key function `K`.
 /// Different key functions may be created for the same `T`: see [`OrdVecKey`].
-/// A predefined key function for (K,V) tuples is available as [`OrdVecKeyFst`].
+/// A predefined key function for (K,V) tuples is available as [`OrdVecKeyFst`],
+/// and one for items that are keys themselves as [`OrdVecKeyIdentity`].
 ///
 /// Restrictions:
 /// * Multiple items with the same key are not allowed and will result
-/// in a panic on construction.
+///   in a panic on construction, unless a `try_` method is used (see below).
 /// * The items must not be modified in a way that changes their key
-/// ordering relative to other items. To modify the keys safely, use
-/// [`retain_map`](struct.OrdVec.html#method.retain_map).
+///   ordering relative to other items. To modify the keys safely, use
+///   [`modify_by_key`](struct.OrdVec.html#method.modify_by_key),
+///   [`with_mut_items`](struct.OrdVec.html#method.with_mut_items) or
+///   [`retain_map`](struct.OrdVec.html#method.retain_map).
+///
+/// Keys are always borrowed from the items, so [`OrdVec`] never stores
+/// a separate copy of them. If many keys share the same contents
+/// (e.g. repeated path prefixes), deduplicate their storage with a [`KeyStore`](crate::KeyStore)
+/// when adding items:
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+/// # use std::{collections::HashSet, rc::Rc};
+/// let mut dirs: HashSet<Rc<str>> = HashSet::new();
+/// let entries: OrdVec<(Rc<str>, u64), OrdVecKeyFst> =
+///     OrdVec::try_from_iter_interned([("/usr/lib".into(), 20), ("/usr/bin".into(), 10)], &mut dirs).unwrap();
+/// let mut more = OrdVec::<_, OrdVecKeyFst>::new();
+/// more.try_insert_interned(("/usr/lib".into(), 30), &mut dirs).unwrap();
+/// assert!(Rc::ptr_eq(&entries[1].0, &more[0].0));
+/// ```
+///
+/// # Avoiding panics
+///
+/// The following methods panic on invalid input and have non-panicking alternatives:
+///
+/// | Panicking | Alternative |
+/// |-----------|-------------|
+/// | [`new_from_unsorted`](struct.OrdVec.html#method.new_from_unsorted), `From<Vec<T>>`, `FromIterator` | [`try_new_from_unsorted`](struct.OrdVec.html#method.try_new_from_unsorted), [`from_sorted_vec`](struct.OrdVec.html#method.from_sorted_vec), [`from_sorted_iter`](struct.OrdVec.html#method.from_sorted_iter) |
+/// | [`insert`](struct.OrdVec.html#method.insert) | [`try_insert`](struct.OrdVec.html#method.try_insert) |
+/// | [`modify_by_key`](struct.OrdVec.html#method.modify_by_key) | [`try_modify_by_key`](struct.OrdVec.html#method.try_modify_by_key) |
+/// | [`with_mut_items`](struct.OrdVec.html#method.with_mut_items) | [`try_with_mut_items`](struct.OrdVec.html#method.try_with_mut_items) |
+/// | [`into_rekeyed`](struct.OrdVec.html#method.into_rekeyed) | [`try_into_rekeyed`](struct.OrdVec.html#method.try_into_rekeyed) |
+/// | [`map_items`](struct.OrdVec.html#method.map_items) | [`try_map_items`](struct.OrdVec.html#method.try_map_items) |
+/// | [`split_into`](struct.OrdVec.html#method.split_into) with zero parts | [`try_split_into`](struct.OrdVec.html#method.try_split_into) |
+/// | Indexing, e.g. `ov[i]` | [`get`](https://doc.rust-lang.org/std/primitive.slice.html#method.get) |
+/// | [`CursorMut::insert_before`] and [`CursorMut::insert_after`] | Checking the neighbors with [`CursorMut::peek_prev`] and [`CursorMut::peek_next`] |
+///
+/// In debug builds, some methods also check preconditions that are the caller's responsibility
+/// and panic if they are violated: [`get_at`](struct.OrdVec.html#method.get_at) with an [`OrdIndex`](crate::OrdIndex) invalidated by an insertion or removal,
+/// [`from_sorted_vec_unchecked`](struct.OrdVec.html#method.from_sorted_vec_unchecked) with unsorted items, [`retain_map`](struct.OrdVec.html#method.retain_map) producing duplicate keys,
+/// and [`predecessor_indices`](struct.OrdVec.html#method.predecessor_indices) with items not ordered by their end.
+///
+/// All other methods panic only if the key function or the [`Ord`] implementation of the key panics.
+///
+/// The `no_panic_api` feature removes the panicking methods and trait implementations in the first column
+/// of the table, except for indexing, so that code behind an FFI boundary can only construct and modify
+/// an [`OrdVec`] through the alternatives. Unlike other features, it is not additive: enabling it anywhere
+/// in a build removes the methods for every crate in it, so it should only be enabled by the final binary
+/// or library, not by crates meant to be used as dependencies. The examples in this documentation
+/// use the full API and are tested without the feature.
+///
+/// To enforce the panic-free subset in your own crate without affecting other crates,
+/// list the panicking methods in the `disallowed-methods` setting of `clippy.toml` instead:
+///
+/// ```toml
+/// disallowed-methods = [
+///     "contiguous_collections::OrdVec::new_from_unsorted",
+///     "contiguous_collections::OrdVec::insert",
+///     "contiguous_collections::OrdVec::modify_by_key",
+///     "contiguous_collections::OrdVec::with_mut_items",
+///     "contiguous_collections::OrdVec::into_rekeyed",
+///     "contiguous_collections::OrdVec::map_items",
+///     "contiguous_collections::OrdVec::split_into",
+///     "contiguous_collections::CursorMut::insert_before",
+///     "contiguous_collections::CursorMut::insert_after",
+/// ]
+/// ```
 ///
 /// # Examples
 ///
@@ -35,15 +108,84 @@ use std::marker::PhantomData;
 /// let by_zip = users.iter().cloned().collect::<OrdVec<User, ZipKey>>();
 /// assert_eq!(by_zip.get_by_key("10030"), Some(&users[0]));
 /// ```
-pub struct OrdVec<T, K: OrdVecKey<T>>(Vec<T>, PhantomData<K>);
+pub struct OrdVec<T, K: OrdVecKey<T>>(
+    pub(crate) Vec<T>,
+    pub(crate) PhantomData<K>,
+    pub(crate) Generation,
+);
 
 /// Trait for [`OrdVec`] key extraction functions.
 /// If the key is not stored alongside data, use a tuple of (key, data) as `T` and [`OrdVecKeyFst`] as `K`.
-pub trait OrdVecKey<T> {
+/// If the items are keys themselves, use [`OrdVecKeyIdentity`] as `K` to get a sorted set.
+///
+/// Key extraction functions for slices are used to look up rows of an [`Array2`](crate::Array2):
+/// see [`sort_rows_by_key`](struct.Array2.html#method.sort_rows_by_key) and [`OrdVecKeyCol`].
+///
+/// Items are kept in ascending order of keys according to [`cmp_keys`](trait.OrdVecKey.html#method.cmp_keys),
+/// which uses the [`Ord`] implementation of the key by default. Override it to sort in a different order,
+/// such as descending (see [`OrdVecKeyRev`]) or case-insensitive, without wrapping the keys stored in `T`.
+/// Keys that compare as [`Ordering::Equal`] are considered duplicates, and lookups find items
+/// by any key equal to theirs according to the comparator.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKey};
+/// # use std::cmp::Ordering;
+/// struct CaseInsensitive;
+/// impl OrdVecKey<String> for CaseInsensitive {
+///     type Key = str;
+///     fn get_key(s: &String) -> &str { s }
+///     fn cmp_keys(a: &str, b: &str) -> Ordering {
+///         a.bytes().map(|c| c.to_ascii_lowercase()).cmp(b.bytes().map(|c| c.to_ascii_lowercase()))
+///     }
+/// }
+///
+/// let names: OrdVec<String, CaseInsensitive> = vec!["bob".into(), "Carol".into(), "Alice".into()].into();
+/// assert_eq!(names[..], ["Alice", "bob", "Carol"]);
+/// assert_eq!(names.get_by_key("ALICE").map(String::as_str), Some("Alice"));
+/// assert!(OrdVec::<String, CaseInsensitive>::try_new_from_unsorted(vec!["a".into(), "A".into()]).is_err());
+/// ```
+pub trait OrdVecKey<T: ?Sized> {
     /// The type of keys extracted from values of type `T`. Must implement [`Ord`].
     type Key: Ord + ?Sized;
     /// Extracts the key from a value of type `T`.
     fn get_key(item: &T) -> &Self::Key;
+    /// Compares two keys, defining the order of items. Defaults to [`Ord::cmp`].
+    ///
+    /// Must be a total order, as required by [`Ord`].
+    #[inline(always)]
+    fn cmp_keys(a: &Self::Key, b: &Self::Key) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+/// Key extraction function adapter that reverses the order of keys extracted by `K`,
+/// keeping the items of an [`OrdVec`] in descending order of keys.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKeyFst, OrdVecKeyRev};
+/// let mut scores: OrdVec<_, OrdVecKeyRev<OrdVecKeyFst>> = vec![(10, "B"), (30, "A"), (20, "C")].into();
+/// scores.insert((25, "D"));
+/// assert_eq!(scores.first(), Some(&(30, "A")));
+/// assert_eq!(scores.get_by_key(&20), Some(&(20, "C")));
+/// // Ranges follow the order of the items, from higher to lower keys
+/// assert_eq!(scores.range_by_key(25..=10)[..], [(25, "D"), (20, "C"), (10, "B")]);
+/// ```
+pub struct OrdVecKeyRev<K>(PhantomData<K>);
+
+impl<T: ?Sized, K: OrdVecKey<T>> OrdVecKey<T> for OrdVecKeyRev<K> {
+    type Key = K::Key;
+    #[inline(always)]
+    fn get_key(item: &T) -> &Self::Key {
+        K::get_key(item)
+    }
+    #[inline(always)]
+    fn cmp_keys(a: &Self::Key, b: &Self::Key) -> Ordering {
+        K::cmp_keys(b, a)
+    }
 }
 
 /// Key extraction function for [`OrdVec`] that returns the first element of a two-element tuple.
@@ -55,232 +197,1536 @@ impl<K: Ord, D> OrdVecKey<(K, D)> for OrdVecKeyFst {
     fn get_key(item: &(K, D)) -> &Self::Key {
         &item.0
     }
-}
+}
+
+/// Key extraction function for [`OrdVec`] that returns the item itself,
+/// turning the [`OrdVec`] into a sorted set.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKeyIdentity};
+/// let mut ids: OrdVec<u32, OrdVecKeyIdentity> = vec![30, 10, 20].into();
+/// ids.insert(15);
+/// assert!(ids.contains_key(&15));
+/// assert_eq!(ids.remove_by_key(&30), Some(30));
+/// assert_eq!(ids.range_by_key(12..), [15, 20]);
+/// ```
+pub struct OrdVecKeyIdentity;
+
+impl<T: Ord + ?Sized> OrdVecKey<T> for OrdVecKeyIdentity {
+    type Key = T;
+    #[inline(always)]
+    fn get_key(item: &T) -> &Self::Key {
+        item
+    }
+}
+
+/// Key extraction function for rows of an [`Array2`](crate::Array2) that returns the element in column `COL`.
+///
+/// Panics if the row has fewer than `COL + 1` elements.
+pub struct OrdVecKeyCol<const COL: usize>;
+
+impl<T: Ord, const COL: usize> OrdVecKey<[T]> for OrdVecKeyCol<COL> {
+    type Key = T;
+    #[inline(always)]
+    fn get_key(row: &[T]) -> &Self::Key {
+        &row[COL]
+    }
+}
+
+impl<T, K: OrdVecKey<T>> OrdVec<T, K> {
+    /// Creates an empty [`OrdVec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let ov: OrdVec<(u32, String), OrdVecKeyFst> = OrdVec::new();
+    /// assert_eq!(ov.len(), 0);
+    /// ```
+    pub const fn new() -> Self {
+        OrdVec(Vec::new(), PhantomData, Generation::new())
+    }
+
+    /// Creates an empty [`OrdVec`] with space for at least `capacity` items without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let ov: OrdVec<(u32, String), OrdVecKeyFst> = OrdVec::with_capacity(10);
+    /// assert!(ov.capacity() >= 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        OrdVec(Vec::with_capacity(capacity), PhantomData, Generation::new())
+    }
+
+    /// Creates an [`OrdVec`] by taking ownership of the given vector
+    /// and sorting it according to the key extraction function.
+    ///
+    /// Prefer this method if you want to be explicit about the
+    /// underlying operations. Otherwise, use the more consice
+    /// [`From<Vec<T>>`](struct.OrdVec.html#impl-From<Vec<T,+Global>>-for-OrdVec<T,+K>).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let v = vec![(1, "B"), (0, "A"), (3, "D"), (2, "C")];
+    /// let ov: OrdVec<_, OrdVecKeyFst> = OrdVec::new_from_unsorted(v);
+    /// assert_eq!(ov[..], [(0, "A"), (1, "B"), (2, "C"), (3, "D")]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let duplicate_keys = vec![(0, "A"), (0, "B")];
+    /// let v: OrdVec<_, OrdVecKeyFst> = OrdVec::new_from_unsorted(duplicate_keys);
+    /// ```
+    #[cfg(not(feature = "no_panic_api"))]
+    pub fn new_from_unsorted(vec: Vec<T>) -> Self {
+        match Self::try_new_from_unsorted(vec) {
+            Ok(ordvec) => ordvec,
+            Err(_) => panic!("Duplicate keys are not allowed"),
+        }
+    }
+
+    /// Creates an [`OrdVec`] by taking ownership of the given vector and sorting it
+    /// according to the key extraction function, or returns an error with one of the items
+    /// that have a duplicate key. Unlike [`new_from_unsorted`](struct.OrdVec.html#method.new_from_unsorted),
+    /// the error can report the offending key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let v = vec![(1, "B"), (0, "A"), (1, "C")];
+    /// let err = OrdVec::<_, OrdVecKeyFst>::try_new_from_unsorted(v).unwrap_err();
+    /// assert_eq!(err.to_string(), "Duplicate key 1");
+    /// assert_eq!(err.key(), &1);
+    /// ```
+    pub fn try_new_from_unsorted(mut vec: Vec<T>) -> Result<Self, DuplicateKeyError<T, K>> {
+        vec.sort_unstable_by(|a, b| K::cmp_keys(K::get_key(a), K::get_key(b)));
+        match vec
+            .windows(2)
+            .position(|pair| K::cmp_keys(K::get_key(&pair[0]), K::get_key(&pair[1])).is_eq())
+        {
+            Some(i) => Err(DuplicateKeyError::new(vec.swap_remove(i + 1))),
+            None => Ok(OrdVec(vec, PhantomData, Generation::new())),
+        }
+    }
+
+    /// Sorts the vector according to the key extraction function and keeps only the first item
+    /// of each run of items with equal keys.
+    #[cfg(any(feature = "arbitrary", feature = "proptest", feature = "serde"))]
+    pub(crate) fn new_from_unsorted_dedup(mut vec: Vec<T>) -> Self {
+        vec.sort_by(|a, b| K::cmp_keys(K::get_key(a), K::get_key(b)));
+        vec.dedup_by(|b, a| K::cmp_keys(K::get_key(a), K::get_key(b)).is_eq());
+        OrdVec(vec, PhantomData, Generation::new())
+    }
+
+    /// Creates an [`OrdVec`] by taking ownership of the given vector,
+    /// which must already be sorted in strictly ascending order of keys.
+    ///
+    /// Unlike [`new_from_unsorted`](struct.OrdVec.html#method.new_from_unsorted), the items
+    /// are not sorted, only checked in a single linear pass. If the check fails,
+    /// the error contains the index of the first item that is not smaller than the next one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = OrdVec::from_sorted_vec(vec![(0, "A"), (1, "B")]).unwrap();
+    /// assert_eq!(ov[..], [(0, "A"), (1, "B")]);
+    ///
+    /// let unsorted = OrdVec::<_, OrdVecKeyFst>::from_sorted_vec(vec![(0, "A"), (2, "C"), (1, "B")]);
+    /// assert_eq!(unsorted.unwrap_err().index, 1);
+    /// let duplicate_keys = OrdVec::<_, OrdVecKeyFst>::from_sorted_vec(vec![(0, "A"), (0, "B")]);
+    /// assert_eq!(duplicate_keys.unwrap_err().index, 0);
+    /// ```
+    pub fn from_sorted_vec(vec: Vec<T>) -> Result<Self, NotSortedError> {
+        match find_unsorted::<T, K>(&vec) {
+            Some(index) => Err(NotSortedError { index }),
+            None => Ok(OrdVec(vec, PhantomData, Generation::new())),
+        }
+    }
+
+    /// Creates an [`OrdVec`] by taking ownership of the given vector without
+    /// checking that it is sorted in strictly ascending order of keys.
+    ///
+    /// Use this method only for trusted inputs that are known to be sorted.
+    /// The order is verified in debug builds only. If the vector is not sorted,
+    /// the behavior of the collection is unspecified (but memory-safe): for instance,
+    /// lookups may fail to find existing items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = OrdVec::from_sorted_vec_unchecked(vec![(0, "A"), (1, "B")]);
+    /// assert_eq!(ov.get_by_key(&1), Some(&(1, "B")));
+    /// ```
+    pub fn from_sorted_vec_unchecked(vec: Vec<T>) -> Self {
+        debug_assert!(
+            find_unsorted::<T, K>(&vec).is_none(),
+            "Items must be sorted in strictly ascending order of keys"
+        );
+        OrdVec(vec, PhantomData, Generation::new())
+    }
+
+    /// Returns true if the items are sorted in strictly ascending order of keys,
+    /// i.e. they can be passed to [`from_sorted_vec`](struct.OrdVec.html#method.from_sorted_vec) without an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+    /// assert!(OrdVec::<_, OrdVecKeyFst>::is_sorted_and_unique(&[(0, "A"), (1, "B")]));
+    /// assert!(!OrdVec::<_, OrdVecKeyFst>::is_sorted_and_unique(&[(1, "B"), (0, "A")]));
+    /// assert!(!OrdVec::<_, OrdVecKeyFst>::is_sorted_and_unique(&[(0, "A"), (0, "B")]));
+    /// ```
+    pub fn is_sorted_and_unique(items: &[T]) -> bool {
+        find_unsorted::<T, K>(items).is_none()
+    }
+
+    /// Checks that the items are still sorted in strictly ascending order of keys,
+    /// returning the position of the first violation otherwise.
+    ///
+    /// The order can only be broken by changing keys through
+    /// [`get_mut_by_key`](struct.OrdVec.html#method.get_mut_by_key) or interior mutability,
+    /// or by a key whose [`Ord`] implementation is inconsistent. Call this method in tests
+    /// and fuzz targets after such mutations; it runs in linear time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{NotSortedError, OrdVec, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(0, "A"), (1, "B"), (2, "C")].into();
+    /// ov.get_mut_by_key(&1).unwrap().1 = "Z";
+    /// assert_eq!(ov.debug_validate(), Ok(()));
+    ///
+    /// ov.get_mut_by_key(&1).unwrap().0 = 5;
+    /// assert_eq!(ov.debug_validate(), Err(NotSortedError { index: 1 }));
+    /// ```
+    pub fn debug_validate(&self) -> Result<(), NotSortedError> {
+        match find_unsorted::<T, K>(&self.0) {
+            Some(index) => Err(NotSortedError { index }),
+            None => Ok(()),
+        }
+    }
+
+    /// Creates an [`OrdVec`] from an iterator that yields items in strictly ascending order of keys.
+    ///
+    /// The items are checked, but not sorted; see [`from_sorted_vec`](struct.OrdVec.html#method.from_sorted_vec).
+    /// To skip the check for trusted inputs, use
+    /// [`FromSortedIterator::from_sorted_iter_unchecked`](trait.FromSortedIterator.html#tymethod.from_sorted_iter_unchecked).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let ov = OrdVec::<_, OrdVecKeyFst>::from_sorted_iter((0..3).map(|i| (i, i * 10))).unwrap();
+    /// assert_eq!(ov[..], [(0, 0), (1, 10), (2, 20)]);
+    /// assert!(OrdVec::<_, OrdVecKeyFst>::from_sorted_iter([(1, 0), (0, 0)]).is_err());
+    /// ```
+    pub fn from_sorted_iter(iter: impl IntoIterator<Item = T>) -> Result<Self, NotSortedError> {
+        Self::from_sorted_vec(Vec::from_iter(iter))
+    }
+
+    /// Splits the [`OrdVec`] into `n` parts covering contiguous key ranges, with lengths differing by at most one.
+    /// The first part reuses the allocation of this collection.
+    ///
+    /// Panics if `n` is zero. See [`try_split_into`](struct.OrdVec.html#method.try_split_into) for a non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = (0..5).map(|i| (i, ())).collect();
+    /// let parts = ov.split_into(2);
+    /// assert_eq!(parts[0].keys().collect::<Vec<_>>(), vec![&0, &1, &2]);
+    /// assert_eq!(parts[1].keys().collect::<Vec<_>>(), vec![&3, &4]);
+    /// ```
+    #[cfg(not(feature = "no_panic_api"))]
+    pub fn split_into(self, n: usize) -> Vec<Self> {
+        match self.try_split_into(n) {
+            Ok(parts) => parts,
+            Err(_) => panic!("Number of parts must not be zero"),
+        }
+    }
+
+    /// Splits the [`OrdVec`] into `n` parts like [`split_into`](struct.OrdVec.html#method.split_into),
+    /// or returns it unchanged in an error if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyIdentity};
+    /// let ov: OrdVec<_, OrdVecKeyIdentity> = (0..5).collect();
+    /// let ov = ov.try_split_into(0).unwrap_err();
+    /// let parts = ov.try_split_into(2).unwrap();
+    /// assert_eq!((parts[0][..].len(), parts[1][..].len()), (3, 2));
+    /// ```
+    pub fn try_split_into(self, n: usize) -> Result<Vec<Self>, Self> {
+        if n == 0 {
+            return Err(self);
+        }
+        let mut vec = self.0;
+        let (base_len, num_longer) = (vec.len() / n, vec.len() % n);
+        let mut parts = Vec::with_capacity(n);
+        for i in (1..n).rev() {
+            let start = i * base_len + i.min(num_longer);
+            parts.push(OrdVec(vec.split_off(start), PhantomData, Generation::new()));
+        }
+        parts.push(OrdVec(vec, PhantomData, Generation::new()));
+        parts.reverse();
+        Ok(parts)
+    }
+
+    /// Concatenates parts covering disjoint key ranges, given in any order, into a single [`OrdVec`]
+    /// without sorting their items. This is the inverse of [`split_into`](struct.OrdVec.html#method.split_into).
+    ///
+    /// Returns an error if the key ranges of the parts overlap, with the index
+    /// (in the concatenated items) of the first item whose key is not smaller than the key of the next one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{NotSortedError, OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let a: OrdVec<_, OrdVecKeyFst> = vec![(3, "D"), (4, "E")].into();
+    /// let b: OrdVec<_, OrdVecKeyFst> = vec![(0, "A"), (1, "B")].into();
+    /// let ov = OrdVec::reassemble([a, b]).unwrap();
+    /// assert_eq!(ov.keys().collect::<Vec<_>>(), vec![&0, &1, &3, &4]);
+    ///
+    /// let c: OrdVec<_, OrdVecKeyFst> = vec![(0, "A"), (2, "C")].into();
+    /// let d: OrdVec<_, OrdVecKeyFst> = vec![(1, "B")].into();
+    /// assert_eq!(OrdVec::reassemble([c, d]), Err(NotSortedError { index: 1 }));
+    /// ```
+    pub fn reassemble(parts: impl IntoIterator<Item = Self>) -> Result<Self, NotSortedError> {
+        let mut parts: Vec<Vec<T>> = parts
+            .into_iter()
+            .map(|part| part.0)
+            .filter(|part| !part.is_empty())
+            .collect();
+        parts.sort_unstable_by(|a, b| K::cmp_keys(K::get_key(&a[0]), K::get_key(&b[0])));
+        let mut len = 0;
+        for pair in parts.windows(2) {
+            len += pair[0].len();
+            if K::cmp_keys(
+                K::get_key(&pair[0][pair[0].len() - 1]),
+                K::get_key(&pair[1][0]),
+            )
+            .is_ge()
+            {
+                return Err(NotSortedError { index: len - 1 });
+            }
+        }
+        let total_len = parts.iter().map(Vec::len).sum::<usize>();
+        let mut parts = parts.into_iter();
+        let mut vec = parts.next().unwrap_or_default();
+        vec.reserve_exact(total_len - vec.len());
+        for part in parts {
+            vec.extend(part);
+        }
+        Ok(OrdVec(vec, PhantomData, Generation::new()))
+    }
+
+    /// Returns the underlying [`Vec`] for an insertion or removal,
+    /// invalidating the [`OrdIndex`](crate::OrdIndex) handles returned so far.
+    pub(crate) fn vec_mut(&mut self) -> &mut Vec<T> {
+        self.2.bump();
+        &mut self.0
+    }
+
+    /// Returns the number of items in [`OrdVec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A")].into();
+    /// assert_eq!(ov.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the [`OrdVec`] contains no items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<(u32, &str), OrdVecKeyFst> = OrdVec::new();
+    /// assert!(ov.is_empty());
+    /// ov.insert((1, "A"));
+    /// assert!(!ov.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of items the [`OrdVec`] can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// Reserves space for at least `additional` more items, possibly more to avoid frequent reallocations.
+    /// See [`Vec::reserve`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "A")].into();
+    /// ov.reserve(10);
+    /// assert!(ov.capacity() >= 11);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional)
+    }
+
+    /// Reserves space for exactly `additional` more items. See [`Vec::reserve_exact`].
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.0.reserve_exact(additional)
+    }
+
+    /// Shrinks the capacity as close to the number of items as possible. See [`Vec::shrink_to_fit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<(u32, &str), OrdVecKeyFst> = OrdVec::with_capacity(10);
+    /// ov.insert((1, "A"));
+    /// ov.shrink_to_fit();
+    /// assert!(ov.capacity() >= 1);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    /// Removes all items, keeping the allocated capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "A"), (2, "B")].into();
+    /// ov.clear();
+    /// assert!(ov.is_empty());
+    /// assert!(ov.capacity() >= 2);
+    /// ```
+    pub fn clear(&mut self) {
+        self.vec_mut().clear()
+    }
+
+    /// Returns an iterator over items in the order of their keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A")].into();
+    /// assert_eq!(ov.iter().collect::<Vec<_>>(), vec![&(0, "A"), &(1, "B")]);
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.0.iter()
+    }
+
+    /// Returns an iterator over keys of items in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A")].into();
+    /// assert_eq!(ov.keys().collect::<Vec<_>>(), vec![&0, &1]);
+    /// ```
+    pub fn keys(
+        &self,
+    ) -> impl ExactSizeIterator<Item = &K::Key> + DoubleEndedIterator + FusedIterator {
+        self.0.iter().map(K::get_key)
+    }
+
+    /// Returns the item with the smallest key, or None if the [`OrdVec`] is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A"), (2, "C")].into();
+    /// assert_eq!(ov.first(), Some(&(0, "A")));
+    /// ```
+    #[doc(alias = "peek_first")]
+    pub fn first(&self) -> Option<&T> {
+        self.0.first()
+    }
+
+    /// Returns the item with the largest key, or None if the [`OrdVec`] is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A"), (2, "C")].into();
+    /// assert_eq!(ov.last(), Some(&(2, "C")));
+    /// ```
+    #[doc(alias = "peek_last")]
+    pub fn last(&self) -> Option<&T> {
+        self.0.last()
+    }
+
+    /// Removes the item with the smallest key and returns it, or None if the [`OrdVec`] is empty.
+    ///
+    /// This shifts all remaining items, so when the [`OrdVec`] is used as a priority queue,
+    /// prefer ordering items so that the next one to be processed has the largest key
+    /// and use [`pop_last`](struct.OrdVec.html#method.pop_last) instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A")].into();
+    /// assert_eq!(ov.pop_first(), Some((0, "A")));
+    /// assert_eq!(ov.pop_first(), Some((1, "B")));
+    /// assert_eq!(ov.pop_first(), None);
+    /// ```
+    pub fn pop_first(&mut self) -> Option<T> {
+        if self.0.is_empty() {
+            None
+        } else {
+            Some(self.vec_mut().remove(0))
+        }
+    }
+
+    /// Removes the item with the largest key and returns it, or None if the [`OrdVec`] is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A")].into();
+    /// assert_eq!(ov.pop_last(), Some((1, "B")));
+    /// assert_eq!(ov.pop_last(), Some((0, "A")));
+    /// assert_eq!(ov.pop_last(), None);
+    /// ```
+    pub fn pop_last(&mut self) -> Option<T> {
+        self.vec_mut().pop()
+    }
+
+    /// Returns a [`CursorMut`] pointing at the item with the smallest key,
+    /// or past the last item if the [`OrdVec`] is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A")].into();
+    /// let cursor = ov.cursor_mut();
+    /// assert_eq!(cursor.current(), Some(&(0, "A")));
+    /// ```
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, T, K> {
+        CursorMut::new(self, 0)
+    }
+
+    /// Returns a [`CursorMut`] pointing at the first item with a key greater than or equal to the given key,
+    /// or past the last item if there is no such item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "A"), (3, "C")].into();
+    /// let mut cursor = ov.cursor_mut_at_key(&2);
+    /// assert_eq!(cursor.current(), Some(&(3, "C")));
+    /// cursor.insert_before((2, "B"));
+    /// assert_eq!(ov[..], [(1, "A"), (2, "B"), (3, "C")]);
+    /// ```
+    pub fn cursor_mut_at_key(&mut self, k: &<K as OrdVecKey<T>>::Key) -> CursorMut<'_, T, K> {
+        let index = self
+            .0
+            .partition_point(|item| K::cmp_keys(K::get_key(item), k).is_lt());
+        CursorMut::new(self, index)
+    }
+
+    /// Inserts a new item into [`OrdVec`].
+    /// Panics if there is an existing item with the same key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<(u32, &str), OrdVecKeyFst> = OrdVec::new();
+    /// ov.insert((5, "B"));
+    /// ov.insert((3, "A"));
+    /// ov.insert((7, "C"));
+    /// assert_eq!(ov[..], [(3, "A"), (5, "B"), (7, "C")]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<(u32, &str), OrdVecKeyFst> = OrdVec::new();
+    /// ov.insert((5, "B"));
+    /// ov.insert((5, "A"));
+    /// ```
+    #[cfg(not(feature = "no_panic_api"))]
+    pub fn insert(&mut self, item: T) {
+        self.insert_or_panic(item);
+    }
+
+    /// Same as [`insert`](struct.OrdVec.html#method.insert), for the other collections
+    /// whose methods are not affected by the `no_panic_api` feature.
+    pub(crate) fn insert_or_panic(&mut self, item: T) {
+        if self.try_insert(item).is_err() {
+            panic!("Cannot insert an item with a duplicate key");
+        }
+    }
+
+    /// Inserts an item into [`OrdVec`], or returns it in an error if an item with the same key is already present.
+    /// Unlike [`insert`](struct.OrdVec.html#method.insert), the error can report the offending key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<(u32, &str), OrdVecKeyFst> = OrdVec::new();
+    /// assert!(ov.try_insert((5, "B")).is_ok());
+    /// let err = ov.try_insert((5, "A")).unwrap_err();
+    /// assert_eq!(err.to_string(), "Duplicate key 5");
+    /// assert_eq!(err.into_item(), (5, "A"));
+    /// ```
+    pub fn try_insert(&mut self, item: T) -> Result<(), DuplicateKeyError<T, K>> {
+        let insert_idx = if let Some(last_item) = self.0.last() {
+            let k = K::get_key(&item);
+            if K::cmp_keys(k, K::get_key(last_item)).is_le() {
+                match self
+                    .0
+                    .binary_search_by(|other| K::cmp_keys(K::get_key(other), k))
+                {
+                    Ok(_) => return Err(DuplicateKeyError::new(item)),
+                    Err(i) => i,
+                }
+            } else {
+                self.0.len()
+            }
+        } else {
+            self.0.len()
+        };
+        self.vec_mut().insert(insert_idx, item);
+        Ok(())
+    }
+
+    /// Looks up an item by key.
+    /// See also [`get_mut_by_key`](struct.OrdVec.html#method.get_mut_by_key).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A")].into();
+    /// assert_eq!(ov.get_by_key(&0), Some(&(0, "A")));
+    /// assert_eq!(ov.get_by_key(&2), None);
+    /// ```
+    pub fn get_by_key(&self, k: &<K as OrdVecKey<T>>::Key) -> Option<&T> {
+        self.get_index_by_key(k).map(|i| &self.0[i])
+    }
+
+    /// Returns a mutable reference to an item looked up by key.
+    ///
+    /// Warning: the behavior of the collection is undefined if the item's key
+    /// is changed in a way that affects its ordering relative to other items.
+    /// Use [`modify_by_key`](struct.OrdVec.html#method.modify_by_key) to change keys safely.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A")].into();
+    /// assert_eq!(ov.get_mut_by_key(&0), Some(&mut (0, "A")));
+    /// assert_eq!(ov.get_mut_by_key(&2), None);
+    /// ```
+    pub fn get_mut_by_key(&mut self, k: &<K as OrdVecKey<T>>::Key) -> Option<&mut T> {
+        self.get_index_by_key(k).map(|i| &mut self.0[i])
+    }
+
+    /// Calls function `f` with a mutable reference to the item with the given key,
+    /// then moves the item to its new position if `f` changed its key.
+    /// Returns the result of `f`, or None if such an item is not found.
+    ///
+    /// Unlike [`get_mut_by_key`](struct.OrdVec.html#method.get_mut_by_key), this keeps the items
+    /// ordered by key no matter how the item is modified. Moving the item shifts the items between
+    /// its old and new positions, which is cheaper than re-sorting with [`retain_map`](struct.OrdVec.html#method.retain_map).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "A"), (2, "B"), (3, "C")].into();
+    /// assert_eq!(ov.modify_by_key(&1, |item| item.1 = "Z"), Some(()));
+    /// assert_eq!(ov.modify_by_key(&1, |item| { item.0 = 5; item.1 }), Some("Z"));
+    /// assert_eq!(ov[..], [(2, "B"), (3, "C"), (5, "Z")]);
+    /// assert_eq!(ov.modify_by_key(&1, |item| item.0 = 0), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new key of the item is equal to the key of another item.
+    /// The modified item is removed from the collection before panicking.
+    /// See [`try_modify_by_key`](struct.OrdVec.html#method.try_modify_by_key) for a non-panicking version.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "A"), (2, "B")].into();
+    /// ov.modify_by_key(&1, |item| item.0 = 2);
+    /// ```
+    #[cfg(not(feature = "no_panic_api"))]
+    pub fn modify_by_key<R>(
+        &mut self,
+        k: &<K as OrdVecKey<T>>::Key,
+        f: impl FnOnce(&mut T) -> R,
+    ) -> Option<R> {
+        match self.try_modify_by_key(k, f) {
+            Ok(result) => result,
+            Err(_) => panic!("Cannot change the key of an item to a duplicate key"),
+        }
+    }
+
+    /// Calls function `f` with a mutable reference to the item with the given key,
+    /// then moves the item to its new position if `f` changed its key, like
+    /// [`modify_by_key`](struct.OrdVec.html#method.modify_by_key).
+    ///
+    /// If the new key of the item is equal to the key of another item, the modified item
+    /// is removed from the collection and returned in an error, and the result of `f` is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "A"), (2, "B")].into();
+    /// assert_eq!(ov.try_modify_by_key(&1, |item| item.0 = 3).unwrap(), Some(()));
+    /// let err = ov.try_modify_by_key(&3, |item| item.0 = 2).unwrap_err();
+    /// assert_eq!(err.into_item(), (2, "A"));
+    /// assert_eq!(ov[..], [(2, "B")]);
+    /// ```
+    pub fn try_modify_by_key<R>(
+        &mut self,
+        k: &<K as OrdVecKey<T>>::Key,
+        f: impl FnOnce(&mut T) -> R,
+    ) -> Result<Option<R>, DuplicateKeyError<T, K>> {
+        let index = match self.get_index_by_key(k) {
+            Some(index) => index,
+            None => return Ok(None),
+        };
+        let result = f(&mut self.0[index]);
+        let new_key = K::get_key(&self.0[index]);
+        let (before, after) = (&self.0[..index], &self.0[index + 1..]);
+        if before
+            .last()
+            .is_some_and(|prev| K::cmp_keys(new_key, K::get_key(prev)).is_le())
+        {
+            let new_index =
+                before.partition_point(|item| K::cmp_keys(K::get_key(item), new_key).is_lt());
+            if K::cmp_keys(K::get_key(&before[new_index]), new_key).is_eq() {
+                return Err(DuplicateKeyError::new(self.vec_mut().remove(index)));
+            }
+            self.vec_mut()[new_index..=index].rotate_right(1);
+        } else if after
+            .first()
+            .is_some_and(|next| K::cmp_keys(new_key, K::get_key(next)).is_ge())
+        {
+            let offset =
+                after.partition_point(|item| K::cmp_keys(K::get_key(item), new_key).is_lt());
+            if after
+                .get(offset)
+                .is_some_and(|item| K::cmp_keys(K::get_key(item), new_key).is_eq())
+            {
+                return Err(DuplicateKeyError::new(self.vec_mut().remove(index)));
+            }
+            self.vec_mut()[index..=index + offset].rotate_left(1);
+        }
+        debug_assert!(self.debug_validate().is_ok());
+        Ok(Some(result))
+    }
+
+    /// Calls function `f` with a mutable slice of all items, then restores the order of items
+    /// by sorting them by key. Returns the result of `f`.
+    ///
+    /// Unlike [`get_mut_by_key`](struct.OrdVec.html#method.get_mut_by_key), `f` may change the keys freely.
+    /// This is cheaper than [`retain_map`](struct.OrdVec.html#method.retain_map) for bulk in-place updates,
+    /// since the items are not moved out and back in, and the sort takes linear time
+    /// if the order of keys is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "A"), (2, "B"), (3, "C")].into();
+    /// let changed = ov.with_mut_items(|items| {
+    ///     items[0].0 = 10;
+    ///     items[2].1 = "Z";
+    ///     2
+    /// });
+    /// assert_eq!(changed, 2);
+    /// assert_eq!(ov[..], [(2, "B"), (3, "Z"), (10, "A")]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `f` leaves several items with the same key. Of each run of items with equal keys,
+    /// only the one that comes first in the slice is kept; the others are removed before panicking.
+    /// See [`try_with_mut_items`](struct.OrdVec.html#method.try_with_mut_items) for a non-panicking version.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "A"), (2, "B")].into();
+    /// ov.with_mut_items(|items| items[1].0 = 1);
+    /// ```
+    #[cfg(not(feature = "no_panic_api"))]
+    pub fn with_mut_items<R>(&mut self, f: impl FnOnce(&mut [T]) -> R) -> R {
+        match self.try_with_mut_items(f) {
+            Ok(result) => result,
+            Err(_) => panic!("Cannot change the key of an item to a duplicate key"),
+        }
+    }
+
+    /// Calls function `f` with a mutable slice of all items, then restores the order of items
+    /// by sorting them by key, like [`with_mut_items`](struct.OrdVec.html#method.with_mut_items).
+    ///
+    /// If `f` leaves several items with the same key, only the one that comes first in the slice
+    /// is kept of each run of items with equal keys. The first removed item is returned in an error,
+    /// the other removed items and the result of `f` are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "A"), (2, "B"), (3, "C")].into();
+    /// let err = ov.try_with_mut_items(|items| items[2].0 = 1).unwrap_err();
+    /// assert_eq!(err.into_item(), (1, "C"));
+    /// assert_eq!(ov[..], [(1, "A"), (2, "B")]);
+    /// ```
+    pub fn try_with_mut_items<R>(
+        &mut self,
+        f: impl FnOnce(&mut [T]) -> R,
+    ) -> Result<R, DuplicateKeyError<T, K>> {
+        let result = f(self.vec_mut());
+        self.0
+            .sort_by(|a, b| K::cmp_keys(K::get_key(a), K::get_key(b)));
+        if self
+            .0
+            .windows(2)
+            .all(|w| K::cmp_keys(K::get_key(&w[0]), K::get_key(&w[1])).is_lt())
+        {
+            return Ok(result);
+        }
+        let len = self.0.len();
+        let items = std::mem::replace(&mut self.0, Vec::with_capacity(len));
+        let mut removed = None;
+        for item in items {
+            match self.0.last() {
+                Some(last) if K::cmp_keys(K::get_key(last), K::get_key(&item)).is_eq() => {
+                    removed.get_or_insert(item);
+                }
+                _ => self.0.push(item),
+            }
+        }
+        Err(DuplicateKeyError::new(
+            removed.expect("try_with_mut_items() must find a duplicate key"),
+        ))
+    }
+
+    /// Looks up items by each of the given keys, returning an iterator that yields
+    /// the item with each key or None if such an item is not found.
+    ///
+    /// Keys given in ascending order are looked up in a single forward pass, searching
+    /// with exponentially growing steps from the previous match, which is considerably faster than
+    /// separate binary searches when there are many keys. Keys in any other order are still looked up correctly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = (0..100).map(|i| (i * 2, i)).collect();
+    /// let found: Vec<_> = ov.get_many_by_keys(&[4, 5, 150, 198, 10]).collect();
+    /// assert_eq!(found, vec![Some(&(4, 2)), None, Some(&(150, 75)), Some(&(198, 99)), Some(&(10, 5))]);
+    /// ```
+    pub fn get_many_by_keys<'a, I>(&'a self, keys: I) -> impl Iterator<Item = Option<&'a T>> + 'a
+    where
+        I: IntoIterator<Item = &'a K::Key>,
+        I::IntoIter: 'a,
+    {
+        let mut start = 0;
+        keys.into_iter().map(move |k| {
+            if start > 0 && K::cmp_keys(k, K::get_key(&self.0[start - 1])).is_le() {
+                start = 0;
+            }
+            let rest = &self.0[start..];
+            let mut bound = 1;
+            while bound < rest.len() && K::cmp_keys(K::get_key(&rest[bound]), k).is_lt() {
+                bound *= 2;
+            }
+            let lo = bound / 2;
+            let hi = rest.len().min(bound + 1);
+            start +=
+                lo + rest[lo..hi].partition_point(|item| K::cmp_keys(K::get_key(item), k).is_lt());
+            self.0
+                .get(start)
+                .filter(|item| K::cmp_keys(K::get_key(item), k).is_eq())
+        })
+    }
+
+    /// Returns true if an item with the given key is present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A")].into();
+    /// assert!(ov.contains_key(&1));
+    /// assert!(!ov.contains_key(&2));
+    /// ```
+    pub fn contains_key(&self, k: &<K as OrdVecKey<T>>::Key) -> bool {
+        self.get_index_by_key(k).is_some()
+    }
+
+    /// Returns a slice of the items with keys within the given range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A"), (3, "D"), (2, "C")].into();
+    /// assert_eq!(ov.range_by_key(1..3), [(1, "B"), (2, "C")]);
+    /// assert_eq!(ov.range_by_key(..=1), [(0, "A"), (1, "B")]);
+    /// assert_eq!(ov.range_by_key(4..), []);
+    /// ```
+    pub fn range_by_key(&self, range: impl RangeBounds<K::Key>) -> &[T] {
+        self.as_ord_slice().range_by_key(range).as_slice()
+    }
+
+    /// Returns a new [`OrdVec`] with copies of the items with keys within the given range.
+    ///
+    /// Only the items in the range are cloned, since they are stored contiguously.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+    /// let log: OrdVec<_, OrdVecKeyFst> = vec![(10, "start"), (25, "tick"), (40, "tick"), (55, "stop")].into();
+    /// let window = log.clone_range_by_key(20..50);
+    /// assert_eq!(window[..], [(25, "tick"), (40, "tick")]);
+    /// assert_eq!(window.get_by_key(&40), Some(&(40, "tick")));
+    /// ```
+    pub fn clone_range_by_key(&self, range: impl RangeBounds<K::Key>) -> OrdVec<T, K>
+    where
+        T: Clone,
+    {
+        OrdVec::from_sorted_vec_unchecked(self.to_vec_range(range))
+    }
+
+    /// Returns a [`Vec`] with copies of the items with keys within the given range, in ascending order of keys.
+    /// See [`clone_range_by_key`](struct.OrdVec.html#method.clone_range_by_key).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A"), (3, "D"), (2, "C")].into();
+    /// assert_eq!(ov.to_vec_range(2..), vec![(2, "C"), (3, "D")]);
+    /// ```
+    pub fn to_vec_range(&self, range: impl RangeBounds<K::Key>) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.range_by_key(range).to_vec()
+    }
+
+    /// Returns the range of indexes of the items whose keys compare as [`Ordering::Equal`]
+    /// according to function `f`, e.g. the items whose tuple key has a given first component.
+    ///
+    /// Function `f` must be consistent with the ordering of keys: it must return [`Ordering::Less`]
+    /// for keys before the range and [`Ordering::Greater`] for keys after it, like the comparator
+    /// of [`slice::binary_search_by`]. The range is found with two binary searches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![((1, 5), "A"), ((2, 1), "B"), ((2, 7), "C"), ((3, 0), "D")].into();
+    /// assert_eq!(ov.get_index_range_by(|key| key.0.cmp(&2)), 1..3);
+    /// assert_eq!(ov.get_index_range_by(|key| key.0.cmp(&4)), 4..4);
+    /// ```
+    pub fn get_index_range_by(&self, f: impl FnMut(&K::Key) -> Ordering) -> Range<usize> {
+        self.as_ord_slice().get_index_range_by(f)
+    }
+
+    /// Returns a slice of the items whose keys compare as [`Ordering::Equal`] according to function `f`.
+    /// See [`get_index_range_by`](struct.OrdVec.html#method.get_index_range_by) for the requirements on `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyIdentity};
+    /// let ov: OrdVec<(u32, u32), OrdVecKeyIdentity> = vec![(2, 7), (1, 5), (2, 1), (3, 0)].into();
+    /// assert_eq!(ov.range_by(|&(date, _)| date.cmp(&2)), [(2, 1), (2, 7)]);
+    /// ```
+    #[doc(alias = "position_range_by_key_prefix")]
+    pub fn range_by(&self, f: impl FnMut(&K::Key) -> Ordering) -> &[T] {
+        &self.0[self.get_index_range_by(f)]
+    }
+
+    /// Returns an iterator over runs of consecutive items whose keys have the same projection,
+    /// e.g. items keyed by `(date, id)` grouped by date.
+    ///
+    /// Since items are ordered by key, each run contains all items with a given projection
+    /// if the projection preserves the ordering of keys, such as a prefix of a tuple key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyIdentity};
+    /// let events: OrdVec<(u32, u32), OrdVecKeyIdentity> = vec![(2, 1), (1, 7), (2, 0), (1, 3), (3, 5)].into();
+    /// let by_date: Vec<&[(u32, u32)]> = events.chunk_by_key(|&(date, _)| date).collect();
+    /// assert_eq!(by_date, vec![&[(1, 3), (1, 7)][..], &[(2, 0), (2, 1)], &[(3, 5)]]);
+    /// ```
+    #[doc(alias = "group_by_key_prefix")]
+    pub fn chunk_by_key<P: PartialEq>(
+        &self,
+        mut project: impl FnMut(&K::Key) -> P,
+    ) -> impl DoubleEndedIterator<Item = &[T]> + FusedIterator {
+        self.0
+            .chunk_by(move |a, b| project(K::get_key(a)) == project(K::get_key(b)))
+    }
+
+    /// Returns the index of the item with the given key
+    /// in the underlying ordered array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![(20, "B"), (10, "A")].into();
+    /// assert_eq!(ov.get_index_by_key(&0), None);
+    /// assert_eq!(ov.get_index_by_key(&10), Some(0));
+    /// assert_eq!(ov.get_index_by_key(&10).map(|i| ov[i]), Some((10, "A")));
+    /// ```
+    pub fn get_index_by_key(&self, k: &<K as OrdVecKey<T>>::Key) -> Option<usize> {
+        self.as_ord_slice().get_index_by_key(k)
+    }
+
+    /// Removes an item with the given key from [`OrdVec`] and returns it,
+    /// or None if such an item is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(20, "B"), (10, "A")].into();
+    /// assert_eq!(ov.remove_by_key(&10), Some((10, "A")));
+    /// assert_eq!(ov.remove_by_key(&10), None);
+    /// ```
+    pub fn remove_by_key(&mut self, k: &<K as OrdVecKey<T>>::Key) -> Option<T> {
+        self.0
+            .binary_search_by(|item| K::cmp_keys(K::get_key(item), k))
+            .ok()
+            .map(|i| self.vec_mut().remove(i))
+    }
+
+    /// Apply the function to each [`OrdVec`] item and depending on the return value:
+    /// * Replace the item with the new value if the function returns Some(T),
+    /// * Remove the item if the function returns None.
+    ///
+    /// If the new value has a different key from the old item, its position in
+    /// the [`OrdVec`] will change accordingly. The new keys must be unique,
+    /// which is checked in debug builds only.
+    ///
+    /// The order of iteration may not follow the order of keys. Compare to
+    /// [`Vec::retain_mut`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.retain_mut).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![
+    ///     (0, "0"), (1, "1"), (2, "2"), (3, "3"),
+    ///     (4, "4"), (5, "5"), (6, "6"), (7, "7")
+    /// ].into();
+    /// let mut retain_map_order = Vec::new();
+    /// ov.retain_map(|(k, v)| {
+    ///     retain_map_order.push(k);
+    ///     if k % 2 == 0 { Some((6 - k, v)) } else { None }
+    /// });
+    /// assert_eq!(ov[..], [(0, "6"), (2, "4"), (4, "2"), (6, "0")]);
+    /// assert_eq!(retain_map_order[..], [0, 1, 7, 6, 2, 3, 5, 4]);
+    /// ```
+    pub fn retain_map(&mut self, mut f: impl FnMut(T) -> Option<T>) {
+        self.2.bump();
+        let mut i = 0;
+        while i < self.0.len() {
+            if let Some(new_item) = f(self.0.swap_remove(i)) {
+                if i < self.0.len() {
+                    let last = std::mem::replace(&mut self.0[i], new_item);
+                    self.0.push(last);
+                } else {
+                    self.0.push(new_item);
+                }
+                i += 1;
+            }
+        }
+        self.0
+            .sort_unstable_by(|a, b| K::cmp_keys(K::get_key(a), K::get_key(b)));
+        debug_assert!(
+            self.debug_validate().is_ok(),
+            "Function passed to retain_map must not produce duplicate keys"
+        );
+    }
+
+    /// Consumes the [`OrdVec`] and returns the underlying vector of items in ascending order of keys.
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+
+    /// Consumes the [`OrdVec`] and sorts its items by the key function `K2`, reusing the allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// struct SndKey;
+    /// impl OrdVecKey<(u32, char)> for SndKey { type Key = char; fn get_key(item: &(u32, char)) -> &char { &item.1 } }
+    ///
+    /// let by_id: OrdVec<_, OrdVecKeyFst> = vec![(2, 'a'), (1, 'c'), (3, 'b')].into();
+    /// let by_letter: OrdVec<_, SndKey> = by_id.into_rekeyed();
+    /// assert_eq!(by_letter[..], [(2, 'a'), (3, 'b'), (1, 'c')]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if multiple items have the same key according to `K2`.
+    /// See [`try_into_rekeyed`](struct.OrdVec.html#method.try_into_rekeyed) for a non-panicking version.
+    #[cfg(not(feature = "no_panic_api"))]
+    pub fn into_rekeyed<K2: OrdVecKey<T>>(self) -> OrdVec<T, K2> {
+        OrdVec::new_from_unsorted(self.0)
+    }
 
-impl<T, K: OrdVecKey<T>> OrdVec<T, K> {
-    /// Creates an empty [`OrdVec`].
+    /// Consumes the [`OrdVec`] and sorts its items by the key function `K2`, reusing the allocation,
+    /// or returns an error with one of the items that have a duplicate key according to `K2`.
     ///
     /// # Examples
     ///
     /// ```
     /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
-    /// let ov: OrdVec<(u32, String), OrdVecKeyFst> = OrdVec::new();
-    /// assert_eq!(ov.len(), 0);
+    /// struct SndKey;
+    /// impl OrdVecKey<(u32, char)> for SndKey { type Key = char; fn get_key(item: &(u32, char)) -> &char { &item.1 } }
+    ///
+    /// let by_id: OrdVec<_, OrdVecKeyFst> = vec![(1, 'a'), (2, 'a')].into();
+    /// let err = by_id.try_into_rekeyed::<SndKey>().unwrap_err();
+    /// assert_eq!(err.key(), &'a');
     /// ```
-    pub const fn new() -> Self {
-        OrdVec(Vec::new(), PhantomData)
+    pub fn try_into_rekeyed<K2: OrdVecKey<T>>(
+        self,
+    ) -> Result<OrdVec<T, K2>, DuplicateKeyError<T, K2>> {
+        OrdVec::try_new_from_unsorted(self.0)
     }
 
-    /// Creates an [`OrdVec`] by taking ownership of the given vector
-    /// and sorting it according to the key extraction function.
-    ///
-    /// Prefer this method if you want to be explicit about the
-    /// underlying operations. Otherwise, use the more consice
-    /// [`From<Vec<T>>`](struct.OrdVec.html#impl-From<Vec<T,+Global>>-for-OrdVec<T,+K>).
+    /// Consumes the [`OrdVec`], applies function `f` to each item, and sorts the results
+    /// by the key function `K2`. The allocation is reused if `U` has the same size and alignment as `T`.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
-    /// let v = vec![(1, "B"), (0, "A"), (3, "D"), (2, "C")];
-    /// let ov: OrdVec<_, OrdVecKeyFst> = OrdVec::new_from_unsorted(v);
-    /// assert_eq!(ov[..], [(0, "A"), (1, "B"), (2, "C"), (3, "D")]);
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+    /// let by_name: OrdVec<_, OrdVecKeyFst> = vec![("Maya", 30), ("Ben", 25), ("Ariel", 35)].into();
+    /// let by_age: OrdVec<_, OrdVecKeyFst> = by_name.map_items(|(name, age)| (age, name));
+    /// assert_eq!(by_age[..], [(25, "Ben"), (30, "Maya"), (35, "Ariel")]);
     /// ```
     ///
     /// # Panics
     ///
-    /// ```should_panic
-    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
-    /// let duplicate_keys = vec![(0, "A"), (0, "B")];
-    /// let v: OrdVec<_, OrdVecKeyFst> = OrdVec::new_from_unsorted(duplicate_keys);
+    /// Panics if multiple results have the same key.
+    /// See [`try_map_items`](struct.OrdVec.html#method.try_map_items) for a non-panicking version.
+    #[cfg(not(feature = "no_panic_api"))]
+    pub fn map_items<U, K2: OrdVecKey<U>>(self, f: impl FnMut(T) -> U) -> OrdVec<U, K2> {
+        OrdVec::new_from_unsorted(self.0.into_iter().map(f).collect())
+    }
+
+    /// Consumes the [`OrdVec`], applies function `f` to each item, and sorts the results
+    /// by the key function `K2` like [`map_items`](struct.OrdVec.html#method.map_items),
+    /// or returns an error with one of the results that have a duplicate key.
+    ///
+    /// # Examples
+    ///
     /// ```
-    pub fn new_from_unsorted(mut vec: Vec<T>) -> Self {
-        vec.sort_unstable_by(|a, b| K::get_key(a).cmp(K::get_key(b)));
-        assert!(
-            vec.windows(2)
-                .all(|pair| K::get_key(&pair[0]) != K::get_key(&pair[1])),
-            "Duplicate keys are not allowed"
-        );
-        OrdVec(vec, PhantomData)
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+    /// let by_name: OrdVec<_, OrdVecKeyFst> = vec![("Maya", 30), ("Ben", 25), ("Ariel", 30)].into();
+    /// let err = by_name.try_map_items::<_, OrdVecKeyFst>(|(name, age)| (age, name)).unwrap_err();
+    /// assert_eq!(err.key(), &30);
+    /// ```
+    pub fn try_map_items<U, K2: OrdVecKey<U>>(
+        self,
+        f: impl FnMut(T) -> U,
+    ) -> Result<OrdVec<U, K2>, DuplicateKeyError<U, K2>> {
+        OrdVec::try_new_from_unsorted(self.0.into_iter().map(f).collect())
     }
 
-    /// Returns the number of items in [`OrdVec`].
+    /// For each item, returns the index of the last preceding item that ends no later than
+    /// the item starts, or None if there is no such item (the `p(i)` array of weighted interval scheduling).
+    ///
+    /// Function `interval` returns the `(start, end)` pair of an item. Items must be ordered
+    /// by their end, e.g. by using the end as the key or as the first component of the key.
     ///
     /// # Examples
     ///
     /// ```
     /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
-    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A")].into();
-    /// assert_eq!(ov.len(), 2);
+    /// // (end, (start, weight))
+    /// let jobs: OrdVec<(u32, (u32, u64)), OrdVecKeyFst> =
+    ///     vec![(3, (1, 5)), (5, (2, 6)), (6, (4, 5)), (7, (6, 4)), (9, (5, 11))].into();
+    /// let p = jobs.predecessor_indices(|&(end, (start, _))| (start, end));
+    /// assert_eq!(p, vec![None, None, Some(0), Some(2), Some(1)]);
+    ///
+    /// // Maximum total weight of non-overlapping jobs
+    /// let mut best = vec![0; jobs.len() + 1];
+    /// for (i, &(_, (_, weight))) in jobs.iter().enumerate() {
+    ///     best[i + 1] = best[i].max(weight + p[i].map_or(0, |j| best[j + 1]));
+    /// }
+    /// assert_eq!(best[jobs.len()], 17);
     /// ```
-    pub fn len(&self) -> usize {
-        self.0.len()
+    pub fn predecessor_indices<'a, Q: Ord>(
+        &'a self,
+        interval: impl Fn(&'a T) -> (Q, Q),
+    ) -> Vec<Option<usize>> {
+        let (starts, ends): (Vec<Q>, Vec<Q>) = self.0.iter().map(interval).unzip();
+        debug_assert!(
+            ends.windows(2).all(|pair| pair[0] <= pair[1]),
+            "Items must be ordered by their end"
+        );
+        starts
+            .iter()
+            .enumerate()
+            .map(|(i, start)| ends[..i].partition_point(|end| end <= start).checked_sub(1))
+            .collect()
     }
 
-    /// Returns `true` if the [`OrdVec`] contains no items.
+    /// Compares this [`OrdVec`] with another one in a single linear pass, returning the keys
+    /// present only in this collection, the keys present only in the other collection,
+    /// and the keys present in both whose items are not equal.
     ///
     /// # Examples
     ///
     /// ```
     /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
-    /// let mut ov: OrdVec<(u32, &str), OrdVecKeyFst> = OrdVec::new();
-    /// assert!(ov.is_empty());
-    /// ov.insert((1, "A"));
-    /// assert!(!ov.is_empty());
+    /// let old: OrdVec<_, OrdVecKeyFst> = vec![(0, "A"), (1, "B"), (2, "C")].into();
+    /// let new: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (2, "Z"), (3, "D")].into();
+    /// let diff = old.diff(&new);
+    /// assert_eq!(diff.only_in_self, vec![&0]);
+    /// assert_eq!(diff.only_in_other, vec![&3]);
+    /// assert_eq!(diff.changed, vec![&2]);
     /// ```
-    pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+    pub fn diff<'a>(&'a self, other: &'a Self) -> OrdVecDiff<'a, K::Key>
+    where
+        T: PartialEq,
+    {
+        let mut diff = OrdVecDiff {
+            only_in_self: Vec::new(),
+            only_in_other: Vec::new(),
+            changed: Vec::new(),
+        };
+        for item in MergeByKey::<T, K>::new(self, other) {
+            match item {
+                MergedItem::Left(a) => diff.only_in_self.push(K::get_key(a)),
+                MergedItem::Right(b) => diff.only_in_other.push(K::get_key(b)),
+                MergedItem::Both(a, b) if a != b => diff.changed.push(K::get_key(a)),
+                MergedItem::Both(..) => {}
+            }
+        }
+        diff
     }
 
-    /// Inserts a new item into [`OrdVec`].
-    /// Panics if there is an existing item with the same key.
+    /// Returns an iterator over items of this collection whose keys are also present in the other one,
+    /// in ascending order of keys.
     ///
     /// # Examples
     ///
     /// ```
     /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
-    /// let mut ov: OrdVec<(u32, &str), OrdVecKeyFst> = OrdVec::new();
-    /// ov.insert((5, "B"));
-    /// ov.insert((3, "A"));
-    /// ov.insert((7, "C"));
-    /// assert_eq!(ov[..], [(3, "A"), (5, "B"), (7, "C")]);
+    /// let a: OrdVec<_, OrdVecKeyFst> = vec![(1, "a"), (2, "b"), (3, "c")].into();
+    /// let b: OrdVec<_, OrdVecKeyFst> = vec![(2, "B"), (3, "C"), (4, "D")].into();
+    /// assert_eq!(a.intersection(&b).collect::<Vec<_>>(), vec![&(2, "b"), &(3, "c")]);
     /// ```
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a T> + 'a {
+        MergeByKey::<T, K>::new(self, other).filter_map(|item| match item {
+            MergedItem::Both(a, _) => Some(a),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over items of both collections in ascending order of keys.
+    /// When both collections have an item with the same key, only the item of this collection is included.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// ```should_panic
+    /// ```
     /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
-    /// let mut ov: OrdVec<(u32, &str), OrdVecKeyFst> = OrdVec::new();
-    /// ov.insert((5, "B"));
-    /// ov.insert((5, "A"));
+    /// let a: OrdVec<_, OrdVecKeyFst> = vec![(1, "a"), (3, "c")].into();
+    /// let b: OrdVec<_, OrdVecKeyFst> = vec![(2, "B"), (3, "C")].into();
+    /// assert_eq!(a.union(&b).collect::<Vec<_>>(), vec![&(1, "a"), &(2, "B"), &(3, "c")]);
     /// ```
-    pub fn insert(&mut self, item: T) {
-        let insert_idx = if let Some(last_item) = self.0.last() {
-            let k = K::get_key(&item);
-            if k <= K::get_key(last_item) {
-                match self.0.binary_search_by_key(&k, K::get_key) {
-                    Ok(_) => panic!("Cannot insert an item with a duplicate key"),
-                    Err(i) => i,
-                }
-            } else {
-                self.0.len()
-            }
-        } else {
-            self.0.len()
-        };
-        self.0.insert(insert_idx, item);
+    pub fn union<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a T> + 'a {
+        MergeByKey::<T, K>::new(self, other).map(|item| match item {
+            MergedItem::Left(a) | MergedItem::Both(a, _) => a,
+            MergedItem::Right(b) => b,
+        })
     }
 
-    /// Looks up an item by key.
-    /// See also [`get_mut_by_key`](struct.OrdVec.html#method.get_mut_by_key).
+    /// Returns an iterator over items of this collection whose keys are not present in the other one,
+    /// in ascending order of keys.
     ///
     /// # Examples
     ///
     /// ```
     /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
-    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A")].into();
-    /// assert_eq!(ov.get_by_key(&0), Some(&(0, "A")));
-    /// assert_eq!(ov.get_by_key(&2), None);
+    /// let a: OrdVec<_, OrdVecKeyFst> = vec![(1, "a"), (2, "b"), (3, "c")].into();
+    /// let b: OrdVec<_, OrdVecKeyFst> = vec![(2, "B")].into();
+    /// assert_eq!(a.difference(&b).collect::<Vec<_>>(), vec![&(1, "a"), &(3, "c")]);
     /// ```
-    pub fn get_by_key(&self, k: &<K as OrdVecKey<T>>::Key) -> Option<&T> {
-        self.get_index_by_key(k).map(|i| &self.0[i])
+    pub fn difference<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a T> + 'a {
+        MergeByKey::<T, K>::new(self, other).filter_map(|item| match item {
+            MergedItem::Left(a) => Some(a),
+            _ => None,
+        })
     }
 
-    /// Returns a mutable reference to an item looked up by key.
-    ///
-    /// Warning: the behavior of the collection is undefined if the item's key
-    /// is changed in a way that affects its ordering relative to other items.
+    /// Returns an iterator over items whose keys are present in exactly one of the collections,
+    /// in ascending order of keys.
     ///
     /// # Examples
     ///
     /// ```
     /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
-    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A")].into();
-    /// assert_eq!(ov.get_mut_by_key(&0), Some(&mut (0, "A")));
-    /// assert_eq!(ov.get_mut_by_key(&2), None);
+    /// let a: OrdVec<_, OrdVecKeyFst> = vec![(1, "a"), (2, "b")].into();
+    /// let b: OrdVec<_, OrdVecKeyFst> = vec![(2, "B"), (3, "C")].into();
+    /// assert_eq!(a.symmetric_difference(&b).collect::<Vec<_>>(), vec![&(1, "a"), &(3, "C")]);
     /// ```
-    pub fn get_mut_by_key(&mut self, k: &<K as OrdVecKey<T>>::Key) -> Option<&mut T> {
-        self.get_index_by_key(k).map(|i| &mut self.0[i])
+    pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a T> + 'a {
+        MergeByKey::<T, K>::new(self, other).filter_map(|item| match item {
+            MergedItem::Left(a) => Some(a),
+            MergedItem::Right(b) => Some(b),
+            MergedItem::Both(..) => None,
+        })
     }
 
-    /// Returns the index of the item with the given key
-    /// in the underlying ordered array.
+    /// Returns `true` if the keys of all items of this collection are present in the other one.
     ///
     /// # Examples
     ///
     /// ```
     /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
-    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![(20, "B"), (10, "A")].into();
-    /// assert_eq!(ov.get_index_by_key(&0), None);
-    /// assert_eq!(ov.get_index_by_key(&10), Some(0));
-    /// assert_eq!(ov.get_index_by_key(&10).map(|i| ov[i]), Some((10, "A")));
+    /// let a: OrdVec<_, OrdVecKeyFst> = vec![(1, "a"), (3, "c")].into();
+    /// let b: OrdVec<_, OrdVecKeyFst> = vec![(1, "A"), (2, "B"), (3, "C")].into();
+    /// assert!(a.is_subset(&b));
+    /// assert!(!b.is_subset(&a));
     /// ```
-    pub fn get_index_by_key(&self, k: &<K as OrdVecKey<T>>::Key) -> Option<usize> {
-        self.0.binary_search_by_key(&k, K::get_key).ok()
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.len() <= other.len() && self.difference(other).next().is_none()
     }
 
-    /// Removes an item with the given key from [`OrdVec`] and returns it,
-    /// or None if such an item is not found.
+    /// Returns `true` if none of the keys of this collection are present in the other one.
     ///
     /// # Examples
     ///
     /// ```
     /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
-    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(20, "B"), (10, "A")].into();
-    /// assert_eq!(ov.remove_by_key(&10), Some((10, "A")));
-    /// assert_eq!(ov.remove_by_key(&10), None);
+    /// let a: OrdVec<_, OrdVecKeyFst> = vec![(1, "a"), (3, "c")].into();
+    /// let b: OrdVec<_, OrdVecKeyFst> = vec![(2, "B")].into();
+    /// assert!(a.is_disjoint(&b));
     /// ```
-    pub fn remove_by_key(&mut self, k: &<K as OrdVecKey<T>>::Key) -> Option<T> {
-        self.0
-            .binary_search_by_key(&k, K::get_key)
-            .ok()
-            .map(|i| self.0.remove(i))
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).next().is_none()
     }
+}
 
-    /// Apply the function to each [`OrdVec`] item and depending on the return value:
-    /// * Replace the item with the new value if the function returns Some(T),
-    /// * Remove the item if the function returns None.
+/// Keys that differ between two [`OrdVec`]s, as returned by [`diff`](struct.OrdVec.html#method.diff).
+/// All lists are sorted in ascending order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrdVecDiff<'a, Key: ?Sized> {
+    /// Keys of items present only in the collection `diff` was called on.
+    pub only_in_self: Vec<&'a Key>,
+    /// Keys of items present only in the collection passed to `diff`.
+    pub only_in_other: Vec<&'a Key>,
+    /// Keys of items present in both collections whose items are not equal.
+    pub changed: Vec<&'a Key>,
+}
+
+impl<K: Ord, V> OrdVec<(K, V), OrdVecKeyFst> {
+    /// Returns an iterator over values (second elements of items) in the order of their keys.
     ///
-    /// If the new value has a different key from the old item, its position in
-    /// the [`OrdVec`] will change accordingly.
+    /// # Examples
     ///
-    /// The order of iteration may not follow the order of keys. Compare to
-    /// [`Vec::retain_mut`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.retain_mut).
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A")].into();
+    /// assert_eq!(ov.values().collect::<Vec<_>>(), vec![&"A", &"B"]);
+    /// ```
+    pub fn values(
+        &self,
+    ) -> impl ExactSizeIterator<Item = &V> + DoubleEndedIterator + FusedIterator {
+        self.0.iter().map(|(_, v)| v)
+    }
+
+    /// Returns an iterator over mutable references to values (second elements of items)
+    /// in the order of their keys. Unlike [`get_mut_by_key`](struct.OrdVec.html#method.get_mut_by_key),
+    /// the keys cannot be modified, so the ordering of items is always preserved.
     ///
     /// # Examples
     ///
     /// ```
     /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
-    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![
-    ///     (0, "0"), (1, "1"), (2, "2"), (3, "3"),
-    ///     (4, "4"), (5, "5"), (6, "6"), (7, "7")
-    /// ].into();
-    /// let mut retain_map_order = Vec::new();
-    /// ov.retain_map(|(k, v)| {
-    ///     retain_map_order.push(k);
-    ///     if k % 2 == 0 { Some((6 - k, v)) } else { None }
-    /// });
-    /// assert_eq!(ov[..], [(0, "6"), (2, "4"), (4, "2"), (6, "0")]);
-    /// assert_eq!(retain_map_order[..], [0, 1, 7, 6, 2, 3, 5, 4]);
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, 10), (0, 20)].into();
+    /// ov.values_mut().for_each(|v| *v += 1);
+    /// assert_eq!(ov[..], [(0, 21), (1, 11)]);
     /// ```
-    pub fn retain_map(&mut self, mut f: impl FnMut(T) -> Option<T>) {
-        let mut i = 0;
-        while i < self.0.len() {
-            if let Some(new_item) = f(self.0.swap_remove(i)) {
-                if i < self.0.len() {
-                    let last = std::mem::replace(&mut self.0[i], new_item);
-                    self.0.push(last);
-                } else {
-                    self.0.push(new_item);
-                }
-                i += 1;
+    pub fn values_mut(
+        &mut self,
+    ) -> impl ExactSizeIterator<Item = &mut V> + DoubleEndedIterator + FusedIterator {
+        self.0.iter_mut().map(|(_, v)| v)
+    }
+}
+
+/// Returns the index of the first item whose key is not smaller than the key of the next item.
+pub(crate) fn find_unsorted<T, K: OrdVecKey<T>>(items: &[T]) -> Option<usize> {
+    items
+        .windows(2)
+        .position(|pair| K::cmp_keys(K::get_key(&pair[0]), K::get_key(&pair[1])).is_ge())
+}
+
+/// Item of two [`OrdVec`]s merged by key.
+enum MergedItem<'a, T> {
+    Left(&'a T),
+    Right(&'a T),
+    Both(&'a T, &'a T),
+}
+
+/// Iterator over two [`OrdVec`]s in ascending order of keys, pairing up items with equal keys.
+struct MergeByKey<'a, T, K> {
+    left: &'a [T],
+    right: &'a [T],
+    key: PhantomData<K>,
+}
+
+impl<'a, T, K: OrdVecKey<T>> MergeByKey<'a, T, K> {
+    fn new(left: &'a [T], right: &'a [T]) -> Self {
+        MergeByKey {
+            left,
+            right,
+            key: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, K: OrdVecKey<T>> Iterator for MergeByKey<'a, T, K> {
+    type Item = MergedItem<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ordering = match (self.left.first(), self.right.first()) {
+            (None, None) => return None,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(a), Some(b)) => K::cmp_keys(K::get_key(a), K::get_key(b)),
+        };
+        let (left, right) = (self.left, self.right);
+        Some(match ordering {
+            Ordering::Less => {
+                self.left = &left[1..];
+                MergedItem::Left(&left[0])
+            }
+            Ordering::Greater => {
+                self.right = &right[1..];
+                MergedItem::Right(&right[0])
             }
+            Ordering::Equal => {
+                self.left = &left[1..];
+                self.right = &right[1..];
+                MergedItem::Both(&left[0], &right[0])
+            }
+        })
+    }
+}
+
+/// Error returned by fallible [`OrdVec`] operations when an item has the same key as another item.
+/// The error owns the rejected item, and its [`Display`](std::fmt::Display) and [`Debug`]
+/// implementations show the key when it implements [`Debug`].
+pub struct DuplicateKeyError<T, K: OrdVecKey<T>> {
+    item: T,
+    key: PhantomData<K>,
+}
+
+impl<T, K: OrdVecKey<T>> DuplicateKeyError<T, K> {
+    pub(crate) fn new(item: T) -> Self {
+        DuplicateKeyError {
+            item,
+            key: PhantomData,
         }
-        self.0
-            .sort_unstable_by(|a, b| K::get_key(a).cmp(K::get_key(b)));
+    }
+
+    /// Returns the key of the rejected item.
+    pub fn key(&self) -> &K::Key {
+        K::get_key(&self.item)
+    }
+
+    /// Returns the rejected item.
+    pub fn into_item(self) -> T {
+        self.item
+    }
+}
+
+impl<T, K: OrdVecKey<T>> std::fmt::Debug for DuplicateKeyError<T, K>
+where
+    K::Key: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("DuplicateKeyError")
+            .field("key", &self.key())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, K: OrdVecKey<T>> std::fmt::Display for DuplicateKeyError<T, K>
+where
+    K::Key: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Duplicate key {:?}", self.key())
+    }
+}
+
+impl<T, K: OrdVecKey<T>> std::error::Error for DuplicateKeyError<T, K> where K::Key: std::fmt::Debug {}
+
+/// Error returned when items are expected to be sorted in strictly ascending order of keys, but are not.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotSortedError {
+    /// Index of the first item whose key is not smaller than the key of the next item.
+    pub index: usize,
+}
+
+impl std::fmt::Display for NotSortedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Items at indexes {} and {} are not in strictly ascending order of keys",
+            self.index,
+            self.index + 1
+        )
     }
 }
 
+impl std::error::Error for NotSortedError {}
+
 /// Creates an [`OrdVec`] by taking ownership of the given vector
 /// and sorting it according to the key extraction function.
 ///
@@ -294,6 +1740,7 @@ impl<T, K: OrdVecKey<T>> OrdVec<T, K> {
 /// let ov: OrdVec<_, OrdVecKeyFst> = v.into();
 /// assert_eq!(ov[..], [(0, "A"), (1, "B"), (2, "C"), (3, "D")]);
 /// ```
+#[cfg(not(feature = "no_panic_api"))]
 impl<T, K: OrdVecKey<T>> From<Vec<T>> for OrdVec<T, K> {
     fn from(value: Vec<T>) -> Self {
         Self::new_from_unsorted(value)
@@ -310,12 +1757,65 @@ impl<T, K: OrdVecKey<T>> From<Vec<T>> for OrdVec<T, K> {
 /// let ov = iter.collect::<OrdVec<_, OrdVecKeyFst>>();
 /// assert_eq!(ov[..], [(0, "A"), (1, "B"), (2, "C"), (3, "D")]);
 /// ```
+#[cfg(not(feature = "no_panic_api"))]
 impl<T, K: OrdVecKey<T>> FromIterator<T> for OrdVec<T, K> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         Self::new_from_unsorted(Vec::from_iter(iter))
     }
 }
 
+/// Conversion from an iterator that yields items in strictly ascending order of keys,
+/// such as the output of a k-way merge of sorted sequences, without sorting them again.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{FromSortedIterator, OrdVec, OrdVecKey, OrdVecKeyFst};
+/// let low: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A")].into();
+/// let high: OrdVec<_, OrdVecKeyFst> = vec![(3, "D"), (2, "C")].into();
+/// let all: OrdVec<_, OrdVecKeyFst> = OrdVec::from_sorted_iter_unchecked(low.into_iter().chain(high));
+/// assert_eq!(all[..], [(0, "A"), (1, "B"), (2, "C"), (3, "D")]);
+/// ```
+pub trait FromSortedIterator<T>: Sized {
+    /// Creates a collection from an iterator that yields items in strictly ascending order of keys.
+    ///
+    /// The order is verified in debug builds only. If the items are not sorted,
+    /// the behavior of the collection is unspecified (but memory-safe).
+    fn from_sorted_iter_unchecked<I: IntoIterator<Item = T>>(iter: I) -> Self;
+}
+
+impl<T, K: OrdVecKey<T>> FromSortedIterator<T> for OrdVec<T, K> {
+    fn from_sorted_iter_unchecked<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_sorted_vec_unchecked(Vec::from_iter(iter))
+    }
+}
+
+/// Creates a consuming iterator that yields items in the order of their keys.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+/// let ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "B"), (0, "A")].into();
+/// assert_eq!(ov.into_iter().collect::<Vec<_>>(), vec![(0, "A"), (1, "B")]);
+/// ```
+impl<T, K: OrdVecKey<T>> IntoIterator for OrdVec<T, K> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Creates an iterator that yields references to items in the order of their keys.
+impl<'a, T, K: OrdVecKey<T>> IntoIterator for &'a OrdVec<T, K> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
 /// Creates an empty [`OrdVec`].
 impl<T, K: OrdVecKey<T>> Default for OrdVec<T, K> {
     fn default() -> Self {
@@ -334,7 +1834,7 @@ impl<T, K: OrdVecKey<T>> std::ops::Deref for OrdVec<T, K> {
 
 impl<T: Clone, K: OrdVecKey<T>> Clone for OrdVec<T, K> {
     fn clone(&self) -> Self {
-        OrdVec(self.0.clone(), PhantomData)
+        OrdVec(self.0.clone(), PhantomData, self.2)
     }
 }
 
@@ -346,9 +1846,58 @@ impl<T: PartialEq, K: OrdVecKey<T>> PartialEq for OrdVec<T, K> {
     }
 }
 
+/// Compares items lexicographically, like slices.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+/// let a: OrdVec<_, OrdVecKeyFst> = vec![(1, "A"), (2, "B")].into();
+/// let b: OrdVec<_, OrdVecKeyFst> = vec![(1, "A"), (3, "C")].into();
+/// assert!(a < b);
+/// ```
+impl<T: PartialOrd, K: OrdVecKey<T>> PartialOrd for OrdVec<T, K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self[..].partial_cmp(&other[..])
+    }
+}
+
+impl<T: Ord, K: OrdVecKey<T>> Ord for OrdVec<T, K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self[..].cmp(&other[..])
+    }
+}
+
+/// Hashes items like a slice, consistent with [`PartialEq`].
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+/// # use std::collections::HashMap;
+/// let mut counts: HashMap<OrdVec<(u32, char), OrdVecKeyFst>, u32> = HashMap::new();
+/// *counts.entry(vec![(2, 'B'), (1, 'A')].into()).or_default() += 1;
+/// *counts.entry(vec![(1, 'A'), (2, 'B')].into()).or_default() += 1;
+/// assert_eq!(counts.len(), 1);
+/// ```
+impl<T: std::hash::Hash, K: OrdVecKey<T>> std::hash::Hash for OrdVec<T, K> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self[..].hash(state)
+    }
+}
+
+/// Formats items as a list.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+/// let ov: OrdVec<_, OrdVecKeyFst> = vec![(2, 'B'), (1, 'A')].into();
+/// assert_eq!(format!("{:?}", ov), "[(1, 'A'), (2, 'B')]");
+/// ```
 impl<T: std::fmt::Debug, K: OrdVecKey<T>> std::fmt::Debug for OrdVec<T, K> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        std::fmt::Debug::fmt(&self, f)
+        f.debug_list().entries(self.iter()).finish()
     }
 }
 
@@ -359,10 +1908,92 @@ impl<T: serde::Serialize, K: OrdVecKey<T>> serde::Serialize for OrdVec<T, K> {
     }
 }
 
+/// Deserializes an [`OrdVec`] from a sequence of items in any order, sorting them by key.
+/// Fails if several items have the same key, naming the positions of two such items
+/// in the sequence. To drop such items instead, see
+/// [`serde_helpers::ordvec_keep_first`](crate::serde_helpers::ordvec_keep_first) and
+/// [`serde_helpers::ordvec_keep_last`](crate::serde_helpers::ordvec_keep_last).
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+/// let ov: OrdVec<(u32, char), OrdVecKeyFst> = serde_json::from_str(r#"[[2,"B"],[1,"A"]]"#).unwrap();
+/// assert_eq!(ov[..], [(1, 'A'), (2, 'B')]);
+///
+/// let err = serde_json::from_str::<OrdVec<(u32, char), OrdVecKeyFst>>(r#"[[1,"A"],[2,"B"],[1,"C"]]"#).unwrap_err();
+/// assert!(err.to_string().starts_with("Items contain duplicate keys: item 2 has the same key as item 0"));
+/// ```
 #[cfg(feature = "serde")]
 impl<'de, T: serde::Deserialize<'de>, K: OrdVecKey<T>> serde::Deserialize<'de> for OrdVec<T, K> {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let vec = Vec::deserialize(deserializer)?;
-        Ok(OrdVec::new_from_unsorted(vec))
+        use serde::de::Error;
+        // Items are tagged with their positions so that a duplicate can be reported
+        // without requiring the key to implement Debug
+        let mut items: Vec<(usize, T)> = Vec::deserialize(deserializer)?
+            .into_iter()
+            .enumerate()
+            .collect();
+        items.sort_unstable_by(|(i, a), (j, b)| {
+            K::cmp_keys(K::get_key(a), K::get_key(b)).then(i.cmp(j))
+        });
+        if let Some(pair) = items
+            .windows(2)
+            .find(|pair| K::cmp_keys(K::get_key(&pair[0].1), K::get_key(&pair[1].1)).is_eq())
+        {
+            return Err(D::Error::custom(format_args!(
+                "Items contain duplicate keys: item {} has the same key as item {}",
+                pair[1].0, pair[0].0
+            )));
+        }
+        let vec = items.into_iter().map(|(_, item)| item).collect();
+        Ok(OrdVec(vec, PhantomData, Generation::new()))
+    }
+}
+
+/// Generates an [`OrdVec`] from arbitrary items, discarding items with duplicate keys.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+/// use arbitrary::{Arbitrary, Unstructured};
+/// let bytes = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9, 3];
+/// let ov = OrdVec::<(u8, u8), OrdVecKeyFst>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+/// assert!(ov.windows(2).all(|pair| pair[0].0 < pair[1].0));
+/// ```
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>, K: OrdVecKey<T>> arbitrary::Arbitrary<'a> for OrdVec<T, K> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(OrdVec::new_from_unsorted_dedup(Vec::arbitrary(u)?))
+    }
+}
+
+/// Generates an [`OrdVec`] from up to 32 items generated by the item type's strategy,
+/// discarding items with duplicate keys.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+/// use proptest::prelude::*;
+/// proptest!(|(ov in any::<OrdVec<(u8, bool), OrdVecKeyFst>>())| {
+///     prop_assert!(ov.windows(2).all(|pair| pair[0].0 < pair[1].0));
+/// });
+/// ```
+#[cfg(feature = "proptest")]
+impl<T, K> proptest::arbitrary::Arbitrary for OrdVec<T, K>
+where
+    T: proptest::arbitrary::Arbitrary + 'static,
+    K: OrdVecKey<T> + 'static,
+{
+    type Parameters = T::Parameters;
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+        proptest::collection::vec(proptest::arbitrary::any_with::<T>(params), 0..=32)
+            .prop_map(OrdVec::new_from_unsorted_dedup)
+            .boxed()
     }
 }
@@ -0,0 +1,288 @@
+use std::{iter::FusedIterator, marker::PhantomData};
+
+use crate::{OrdVec, OrdVecKey};
+
+/// [`OrdVec`] ordered by the primary key `K1`, with a secondary index for lookups by the key `K2`.
+///
+/// The secondary index is a flat sorted array of positions in the primary storage,
+/// kept in sync on every insert and removal. Both keys must be unique.
+///
+/// Read-only methods of the underlying [`OrdVec`], which is ordered by `K1`,
+/// are available through [`Deref`](std::ops::Deref).
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec2K, OrdVecKey};
+/// #[derive(Debug, PartialEq)]
+/// struct User { uid: u32, email: String }
+///
+/// struct UidKey;
+/// impl OrdVecKey<User> for UidKey { type Key = u32; fn get_key(u: &User) -> &u32 { &u.uid } }
+/// struct EmailKey;
+/// impl OrdVecKey<User> for EmailKey { type Key = str; fn get_key(u: &User) -> &str { &u.email } }
+///
+/// let mut users: OrdVec2K<User, UidKey, EmailKey> = OrdVec2K::new();
+/// users.insert(User { uid: 2, email: "ariel@example.com".into() });
+/// users.insert(User { uid: 1, email: "maya@example.com".into() });
+/// assert_eq!(users.get_by_key1(&1).unwrap().email, "maya@example.com");
+/// assert_eq!(users.get_by_key2("ariel@example.com").unwrap().uid, 2);
+///
+/// users.remove_by_key2("maya@example.com");
+/// assert_eq!(users.get_by_key1(&1), None);
+/// assert_eq!(users.len(), 1);
+/// ```
+pub struct OrdVec2K<T, K1: OrdVecKey<T>, K2: OrdVecKey<T>> {
+    items: OrdVec<T, K1>,
+    /// Indexes into `items`, ordered by `K2` keys of the items.
+    index2: Vec<usize>,
+    key2: PhantomData<K2>,
+}
+
+impl<T, K1: OrdVecKey<T>, K2: OrdVecKey<T>> OrdVec2K<T, K1, K2> {
+    /// Creates an empty [`OrdVec2K`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec2K, OrdVecKey, OrdVecKeyFst};
+    /// # struct SndKey;
+    /// # impl OrdVecKey<(u32, char)> for SndKey { type Key = char; fn get_key(item: &(u32, char)) -> &char { &item.1 } }
+    /// let ov2k: OrdVec2K<(u32, char), OrdVecKeyFst, SndKey> = OrdVec2K::new();
+    /// assert!(ov2k.is_empty());
+    /// assert_eq!(ov2k.iter_by_key2().count(), 0);
+    /// ```
+    pub fn new() -> Self {
+        OrdVec2K {
+            items: OrdVec::new(),
+            index2: Vec::new(),
+            key2: PhantomData,
+        }
+    }
+
+    /// Creates an [`OrdVec2K`] from the given items, building the secondary index.
+    ///
+    /// Panics if multiple items have the same secondary key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVec2K, OrdVecKey, OrdVecKeyFst};
+    /// struct SndKey;
+    /// impl OrdVecKey<(u32, char)> for SndKey { type Key = char; fn get_key(item: &(u32, char)) -> &char { &item.1 } }
+    ///
+    /// let ov: OrdVec<_, OrdVecKeyFst> = vec![(2, 'a'), (1, 'c'), (3, 'b')].into();
+    /// let ov2k: OrdVec2K<_, _, SndKey> = OrdVec2K::new_from_ordvec(ov);
+    /// assert_eq!(ov2k.iter_by_key2().collect::<Vec<_>>(), vec![&(2, 'a'), &(3, 'b'), &(1, 'c')]);
+    /// ```
+    pub fn new_from_ordvec(items: OrdVec<T, K1>) -> Self {
+        let mut index2: Vec<usize> = (0..items.len()).collect();
+        index2.sort_unstable_by(|&a, &b| {
+            K2::cmp_keys(K2::get_key(&items[a]), K2::get_key(&items[b]))
+        });
+        assert!(
+            index2.windows(2).all(|pair| K2::cmp_keys(
+                K2::get_key(&items[pair[0]]),
+                K2::get_key(&items[pair[1]])
+            )
+            .is_ne()),
+            "Duplicate keys are not allowed"
+        );
+        OrdVec2K {
+            items,
+            index2,
+            key2: PhantomData,
+        }
+    }
+
+    /// Inserts an item, updating the secondary index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec2K, OrdVecKey, OrdVecKeyFst};
+    /// # struct SndKey;
+    /// # impl OrdVecKey<(u32, char)> for SndKey { type Key = char; fn get_key(item: &(u32, char)) -> &char { &item.1 } }
+    /// let mut ov2k: OrdVec2K<_, OrdVecKeyFst, SndKey> = OrdVec2K::new();
+    /// ov2k.insert((2, 'a'));
+    /// ov2k.insert((1, 'b'));
+    /// assert_eq!(ov2k[..], [(1, 'b'), (2, 'a')]);
+    /// assert_eq!(ov2k.iter_by_key2().collect::<Vec<_>>(), vec![&(2, 'a'), &(1, 'b')]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if an item with the same primary or secondary key is already present.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::{OrdVec2K, OrdVecKey, OrdVecKeyFst};
+    /// struct SndKey;
+    /// impl OrdVecKey<(u32, char)> for SndKey { type Key = char; fn get_key(item: &(u32, char)) -> &char { &item.1 } }
+    ///
+    /// let mut ov2k: OrdVec2K<_, OrdVecKeyFst, SndKey> = OrdVec2K::new();
+    /// ov2k.insert((1, 'a'));
+    /// ov2k.insert((2, 'a'));
+    /// ```
+    pub fn insert(&mut self, item: T) {
+        let pos2 = match self.search_key2(K2::get_key(&item)) {
+            Ok(_) => panic!("Cannot insert an item with a duplicate key"),
+            Err(pos2) => pos2,
+        };
+        let pos1 = match self
+            .items
+            .binary_search_by(|other| K1::cmp_keys(K1::get_key(other), K1::get_key(&item)))
+        {
+            Ok(_) => panic!("Cannot insert an item with a duplicate key"),
+            Err(pos1) => pos1,
+        };
+        self.items.vec_mut().insert(pos1, item);
+        for i in self.index2.iter_mut().filter(|i| **i >= pos1) {
+            *i += 1;
+        }
+        self.index2.insert(pos2, pos1);
+    }
+
+    /// Looks up an item by the primary key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec2K, OrdVecKey, OrdVecKeyFst};
+    /// # struct SndKey;
+    /// # impl OrdVecKey<(u32, char)> for SndKey { type Key = char; fn get_key(item: &(u32, char)) -> &char { &item.1 } }
+    /// let mut ov2k: OrdVec2K<_, OrdVecKeyFst, SndKey> = OrdVec2K::new();
+    /// ov2k.insert((1, 'x'));
+    /// assert_eq!(ov2k.get_by_key1(&1), Some(&(1, 'x')));
+    /// assert_eq!(ov2k.get_by_key1(&2), None);
+    /// ```
+    pub fn get_by_key1(&self, k: &<K1 as OrdVecKey<T>>::Key) -> Option<&T> {
+        self.items.get_by_key(k)
+    }
+
+    /// Looks up an item by the secondary key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec2K, OrdVecKey, OrdVecKeyFst};
+    /// # struct SndKey;
+    /// # impl OrdVecKey<(u32, char)> for SndKey { type Key = char; fn get_key(item: &(u32, char)) -> &char { &item.1 } }
+    /// let mut ov2k: OrdVec2K<_, OrdVecKeyFst, SndKey> = OrdVec2K::new();
+    /// ov2k.insert((1, 'x'));
+    /// assert_eq!(ov2k.get_by_key2(&'x'), Some(&(1, 'x')));
+    /// assert_eq!(ov2k.get_by_key2(&'y'), None);
+    /// ```
+    pub fn get_by_key2(&self, k: &<K2 as OrdVecKey<T>>::Key) -> Option<&T> {
+        let pos2 = self.search_key2(k).ok()?;
+        Some(&self.items[self.index2[pos2]])
+    }
+
+    /// Removes an item with the given primary key and returns it, or None if such an item is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec2K, OrdVecKey, OrdVecKeyFst};
+    /// # struct SndKey;
+    /// # impl OrdVecKey<(u32, char)> for SndKey { type Key = char; fn get_key(item: &(u32, char)) -> &char { &item.1 } }
+    /// let mut ov2k: OrdVec2K<_, OrdVecKeyFst, SndKey> = OrdVec2K::new();
+    /// ov2k.insert((1, 'b'));
+    /// ov2k.insert((2, 'a'));
+    /// assert_eq!(ov2k.remove_by_key1(&1), Some((1, 'b')));
+    /// assert_eq!(ov2k.remove_by_key1(&1), None);
+    /// assert_eq!(ov2k.get_by_key2(&'b'), None);
+    /// assert_eq!(ov2k.get_by_key2(&'a'), Some(&(2, 'a')));
+    /// ```
+    pub fn remove_by_key1(&mut self, k: &<K1 as OrdVecKey<T>>::Key) -> Option<T> {
+        let pos1 = self.items.get_index_by_key(k)?;
+        let pos2 = self
+            .search_key2(K2::get_key(&self.items[pos1]))
+            .expect("secondary index must contain every item");
+        Some(self.remove_at(pos1, pos2))
+    }
+
+    /// Removes an item with the given secondary key and returns it, or None if such an item is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec2K, OrdVecKey, OrdVecKeyFst};
+    /// # struct SndKey;
+    /// # impl OrdVecKey<(u32, char)> for SndKey { type Key = char; fn get_key(item: &(u32, char)) -> &char { &item.1 } }
+    /// let mut ov2k: OrdVec2K<_, OrdVecKeyFst, SndKey> = OrdVec2K::new();
+    /// ov2k.insert((1, 'b'));
+    /// ov2k.insert((2, 'a'));
+    /// assert_eq!(ov2k.remove_by_key2(&'b'), Some((1, 'b')));
+    /// assert_eq!(ov2k.remove_by_key2(&'b'), None);
+    /// assert_eq!(ov2k[..], [(2, 'a')]);
+    /// ```
+    pub fn remove_by_key2(&mut self, k: &<K2 as OrdVecKey<T>>::Key) -> Option<T> {
+        let pos2 = self.search_key2(k).ok()?;
+        Some(self.remove_at(self.index2[pos2], pos2))
+    }
+
+    /// Returns an iterator over the items in the order of the secondary key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec2K, OrdVecKey, OrdVecKeyFst};
+    /// # struct SndKey;
+    /// # impl OrdVecKey<(u32, char)> for SndKey { type Key = char; fn get_key(item: &(u32, char)) -> &char { &item.1 } }
+    /// let mut ov2k: OrdVec2K<_, OrdVecKeyFst, SndKey> = OrdVec2K::new();
+    /// ov2k.insert((1, 'c'));
+    /// ov2k.insert((2, 'a'));
+    /// ov2k.insert((3, 'b'));
+    /// let by_key2: Vec<u32> = ov2k.iter_by_key2().rev().map(|item| item.0).collect();
+    /// assert_eq!(by_key2, vec![1, 3, 2]);
+    /// ```
+    pub fn iter_by_key2(
+        &self,
+    ) -> impl ExactSizeIterator<Item = &T> + DoubleEndedIterator + FusedIterator {
+        self.index2.iter().map(|&i| &self.items[i])
+    }
+
+    /// Consumes the [`OrdVec2K`] and returns the underlying [`OrdVec`] ordered by the primary key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec2K, OrdVecKey, OrdVecKeyFst};
+    /// # struct SndKey;
+    /// # impl OrdVecKey<(u32, char)> for SndKey { type Key = char; fn get_key(item: &(u32, char)) -> &char { &item.1 } }
+    /// let mut ov2k: OrdVec2K<_, OrdVecKeyFst, SndKey> = OrdVec2K::new();
+    /// ov2k.insert((2, 'a'));
+    /// ov2k.insert((1, 'b'));
+    /// let ov = ov2k.into_inner();
+    /// assert_eq!(ov.get_by_key(&2), Some(&(2, 'a')));
+    /// ```
+    pub fn into_inner(self) -> OrdVec<T, K1> {
+        self.items
+    }
+
+    fn search_key2(&self, k: &<K2 as OrdVecKey<T>>::Key) -> Result<usize, usize> {
+        self.index2
+            .binary_search_by(|&i| K2::cmp_keys(K2::get_key(&self.items[i]), k))
+    }
+
+    fn remove_at(&mut self, pos1: usize, pos2: usize) -> T {
+        self.index2.remove(pos2);
+        for i in self.index2.iter_mut().filter(|i| **i > pos1) {
+            *i -= 1;
+        }
+        self.items.vec_mut().remove(pos1)
+    }
+}
+
+impl<T, K1: OrdVecKey<T>, K2: OrdVecKey<T>> Default for OrdVec2K<T, K1, K2> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, K1: OrdVecKey<T>, K2: OrdVecKey<T>> std::ops::Deref for OrdVec2K<T, K1, K2> {
+    type Target = OrdVec<T, K1>;
+    fn deref(&self) -> &OrdVec<T, K1> {
+        &self.items
+    }
+}
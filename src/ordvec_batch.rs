@@ -0,0 +1,211 @@
+use std::cmp::Ordering;
+
+use crate::{DuplicateKeyError, OrdVec, OrdVecKey};
+
+/// Guard for inserting many items into an [`OrdVec`] at once, returned by [`OrdVec::begin_batch`].
+///
+/// Inserted items are appended to a pending buffer and only sorted and merged into the [`OrdVec`]
+/// on [`commit`](struct.BatchInsert.html#method.commit), which takes `O((n + m) log m)` time
+/// for `m` pending items instead of `O(n)` per insert. Dropping the guard without committing
+/// discards the pending items and leaves the [`OrdVec`] unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+/// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(5, "five")].into();
+/// let mut batch = ov.begin_batch();
+/// for i in (0..10).rev().filter(|&i| i != 5) {
+///     batch.insert((i, "other"));
+/// }
+/// // Pending items are found with a linear scan
+/// assert_eq!(batch.get_by_key(&7), Some(&(7, "other")));
+/// batch.commit().unwrap();
+/// assert_eq!(ov.keys().copied().collect::<Vec<_>>(), (0..10).collect::<Vec<_>>());
+/// ```
+pub struct BatchInsert<'a, T, K: OrdVecKey<T>> {
+    target: &'a mut OrdVec<T, K>,
+    pending: Vec<T>,
+}
+
+impl<'a, T, K: OrdVecKey<T>> BatchInsert<'a, T, K> {
+    pub(crate) fn new(target: &'a mut OrdVec<T, K>) -> Self {
+        BatchInsert {
+            target,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Returns the number of pending items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyIdentity};
+    /// let mut ov: OrdVec<u32, OrdVecKeyIdentity> = OrdVec::new();
+    /// let mut batch = ov.begin_batch();
+    /// batch.extend([3, 1, 2]);
+    /// assert_eq!(batch.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns true if there are no pending items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyIdentity};
+    /// let mut ov: OrdVec<u32, OrdVecKeyIdentity> = OrdVec::new();
+    /// let mut batch = ov.begin_batch();
+    /// assert!(batch.is_empty());
+    /// batch.insert(1);
+    /// assert!(!batch.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Reserves capacity for at least `additional` more pending items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyIdentity};
+    /// let mut ov: OrdVec<u32, OrdVecKeyIdentity> = OrdVec::new();
+    /// let mut batch = ov.begin_batch();
+    /// batch.reserve(100);
+    /// batch.extend((0..100).rev());
+    /// batch.commit().unwrap();
+    /// assert_eq!(ov.len(), 100);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.pending.reserve(additional);
+    }
+
+    /// Adds an item to the batch without checking its key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyIdentity};
+    /// let mut ov: OrdVec<u32, OrdVecKeyIdentity> = OrdVec::new();
+    /// let mut batch = ov.begin_batch();
+    /// batch.insert(2);
+    /// batch.insert(2);
+    /// assert_eq!(batch.len(), 2);
+    /// assert!(batch.commit().is_err());
+    /// assert!(ov.is_empty());
+    /// ```
+    pub fn insert(&mut self, item: T) {
+        self.pending.push(item);
+    }
+
+    /// Looks up an item by key, first among the items of the [`OrdVec`] using binary search,
+    /// then among the pending items using linear search.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = OrdVec::from_sorted_vec(vec![(1, "A")]).unwrap();
+    /// let mut batch = ov.begin_batch();
+    /// batch.insert((2, "B"));
+    /// assert_eq!(batch.get_by_key(&1), Some(&(1, "A")));
+    /// assert_eq!(batch.get_by_key(&2), Some(&(2, "B")));
+    /// assert_eq!(batch.get_by_key(&3), None);
+    /// ```
+    pub fn get_by_key(&self, k: &K::Key) -> Option<&T> {
+        self.target.get_by_key(k).or_else(|| {
+            self.pending
+                .iter()
+                .find(|item| K::cmp_keys(K::get_key(item), k).is_eq())
+        })
+    }
+
+    /// Sorts the pending items and merges them into the [`OrdVec`].
+    ///
+    /// If a pending item has the same key as an item of the [`OrdVec`] or another pending item,
+    /// returns an error with that item and leaves the [`OrdVec`] unchanged.
+    /// The other pending items are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "A")].into();
+    /// let mut batch = ov.begin_batch();
+    /// batch.extend([(2, "B"), (1, "C")]);
+    /// assert_eq!(batch.commit().unwrap_err().into_item(), (1, "C"));
+    /// assert_eq!(ov[..], [(1, "A")]);
+    /// ```
+    pub fn commit(self) -> Result<(), DuplicateKeyError<T, K>> {
+        let BatchInsert {
+            target,
+            mut pending,
+        } = self;
+        pending.sort_by(|a, b| K::cmp_keys(K::get_key(a), K::get_key(b)));
+        if let Some(i) = pending
+            .windows(2)
+            .position(|pair| K::cmp_keys(K::get_key(&pair[0]), K::get_key(&pair[1])).is_eq())
+        {
+            return Err(DuplicateKeyError::new(pending.swap_remove(i + 1)));
+        }
+        let (mut i, mut j) = (0, 0);
+        while i < target.0.len() && j < pending.len() {
+            match K::cmp_keys(K::get_key(&target.0[i]), K::get_key(&pending[j])) {
+                Ordering::Less => i += 1,
+                Ordering::Greater => j += 1,
+                Ordering::Equal => return Err(DuplicateKeyError::new(pending.swap_remove(j))),
+            }
+        }
+
+        let existing = std::mem::take(target.vec_mut());
+        let mut merged = Vec::with_capacity(existing.len() + pending.len());
+        let mut existing = existing.into_iter().peekable();
+        let mut pending = pending.into_iter().peekable();
+        loop {
+            let take_existing = match (existing.peek(), pending.peek()) {
+                (Some(a), Some(b)) => K::cmp_keys(K::get_key(a), K::get_key(b)).is_lt(),
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+            let next = if take_existing {
+                existing.next()
+            } else {
+                pending.next()
+            };
+            merged.extend(next);
+        }
+        target.0 = merged;
+        Ok(())
+    }
+}
+
+impl<T, K: OrdVecKey<T>> Extend<T> for BatchInsert<'_, T, K> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.pending.extend(iter);
+    }
+}
+
+impl<T, K: OrdVecKey<T>> OrdVec<T, K> {
+    /// Starts inserting items in a batch, which sorts them once on commit instead of
+    /// shifting the items on every insert. See [`BatchInsert`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyIdentity};
+    /// let mut ov: OrdVec<_, OrdVecKeyIdentity> = OrdVec::from_sorted_vec(vec![10, 20]).unwrap();
+    /// let mut batch = ov.begin_batch();
+    /// batch.extend([15, 5]);
+    /// batch.commit().unwrap();
+    /// assert_eq!(ov[..], [5, 10, 15, 20]);
+    /// ```
+    #[must_use = "pending items are discarded unless the batch is committed"]
+    pub fn begin_batch(&mut self) -> BatchInsert<'_, T, K> {
+        BatchInsert::new(self)
+    }
+}
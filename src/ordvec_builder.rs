@@ -0,0 +1,111 @@
+use std::marker::PhantomData;
+
+use crate::{OrdVec, OrdVecKey};
+
+/// Buffer for collecting items in any order and building an [`OrdVec`] from them,
+/// merging items with duplicate keys instead of panicking.
+///
+/// Items are appended to a [`Vec`] without any checks, and sorted once by [`build`](struct.OrdVecBuilder.html#method.build).
+/// This makes it a good fit for loading data from several sources that may overlap.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVecBuilder, OrdVecKeyFst};
+/// let mut builder: OrdVecBuilder<(&str, u32), OrdVecKeyFst> = OrdVecBuilder::new();
+/// builder.extend([("pears", 3), ("apples", 5)]);
+/// builder.push(("apples", 2));
+/// builder.extend([("plums", 1), ("pears", 4)]);
+///
+/// let totals = builder.build(|(name, a), (_, b)| (name, a + b));
+/// assert_eq!(totals[..], [("apples", 7), ("pears", 7), ("plums", 1)]);
+/// ```
+pub struct OrdVecBuilder<T, K: OrdVecKey<T>> {
+    items: Vec<T>,
+    key: PhantomData<K>,
+}
+
+impl<T, K: OrdVecKey<T>> OrdVecBuilder<T, K> {
+    /// Creates an empty [`OrdVecBuilder`].
+    pub const fn new() -> Self {
+        OrdVecBuilder {
+            items: Vec::new(),
+            key: PhantomData,
+        }
+    }
+
+    /// Creates an empty [`OrdVecBuilder`] with space for at least `capacity` items.
+    pub fn with_capacity(capacity: usize) -> Self {
+        OrdVecBuilder {
+            items: Vec::with_capacity(capacity),
+            key: PhantomData,
+        }
+    }
+
+    /// Returns the number of buffered items, including items with duplicate keys.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if no items have been added.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Adds an item to the buffer.
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    /// Sorts the buffered items by key and builds an [`OrdVec`], combining items with equal keys
+    /// by calling `merge(existing, new)`. Items with equal keys are merged in the order they were added,
+    /// so `|_, new| new` keeps the last item and `|existing, _| existing` keeps the first one.
+    ///
+    /// The merged item must have the same key as the items it was built from. This is not checked directly,
+    /// since the original key is not available once both items are passed to `merge`: a merge that changes
+    /// the key is only detected in debug builds, and only if it breaks the order of the items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVecBuilder, OrdVecKeyFst};
+    /// let builder: OrdVecBuilder<_, OrdVecKeyFst> = [(1, "a"), (0, "b"), (1, "c")].into_iter().collect();
+    /// assert_eq!(builder.build(|_, new| new)[..], [(0, "b"), (1, "c")]);
+    /// ```
+    pub fn build(self, mut merge: impl FnMut(T, T) -> T) -> OrdVec<T, K> {
+        let mut items = self.items;
+        items.sort_by(|a, b| K::cmp_keys(K::get_key(a), K::get_key(b)));
+        let mut merged: Vec<T> = Vec::with_capacity(items.len());
+        for item in items {
+            match merged.pop() {
+                Some(last) if K::cmp_keys(K::get_key(&last), K::get_key(&item)).is_eq() => {
+                    merged.push(merge(last, item))
+                }
+                Some(last) => merged.extend([last, item]),
+                None => merged.push(item),
+            }
+        }
+        OrdVec::from_sorted_vec_unchecked(merged)
+    }
+}
+
+impl<T, K: OrdVecKey<T>> Default for OrdVecBuilder<T, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, K: OrdVecKey<T>> Extend<T> for OrdVecBuilder<T, K> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.items.extend(iter);
+    }
+}
+
+impl<T, K: OrdVecKey<T>> FromIterator<T> for OrdVecBuilder<T, K> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        OrdVecBuilder {
+            items: iter.into_iter().collect(),
+            key: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use crate::{OrdVec, OrdVecKey};
+
+/// Cursor over the items of an [`OrdVec`] in key order that supports inserting and removing items
+/// at the current position without looking it up again.
+///
+/// The cursor points either at an item or past the last item, where there is no current item.
+///
+/// Inserting or removing an item shifts all following items, like [`Vec::insert`] and [`Vec::remove`],
+/// but no binary search is performed, which makes the cursor well suited to merging sorted sequences.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+/// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, 10), (3, 30), (5, 50)].into();
+/// let other: OrdVec<_, OrdVecKeyFst> = vec![(2, 20), (3, 33), (6, 60)].into();
+///
+/// // Merge other into ov, replacing items with equal keys
+/// let mut cursor = ov.cursor_mut();
+/// for item in other {
+///     cursor.seek(&item.0);
+///     if cursor.current().map(|c| c.0) == Some(item.0) {
+///         cursor.remove_current();
+///     }
+///     cursor.insert_before(item);
+/// }
+/// assert_eq!(ov[..], [(1, 10), (2, 20), (3, 33), (5, 50), (6, 60)]);
+/// ```
+pub struct CursorMut<'a, T, K: OrdVecKey<T>> {
+    ordvec: &'a mut OrdVec<T, K>,
+    index: usize,
+}
+
+impl<'a, T, K: OrdVecKey<T>> CursorMut<'a, T, K> {
+    pub(crate) fn new(ordvec: &'a mut OrdVec<T, K>, index: usize) -> Self {
+        CursorMut { ordvec, index }
+    }
+
+    /// Returns the index of the current item in the underlying ordered array,
+    /// or the number of items if the cursor points past the last item.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the current item, or None if the cursor points past the last item.
+    pub fn current(&self) -> Option<&T> {
+        self.ordvec.0.get(self.index)
+    }
+
+    /// Returns the item before the current item, or None if the cursor points at the first item.
+    pub fn peek_prev(&self) -> Option<&T> {
+        self.index.checked_sub(1).map(|i| &self.ordvec.0[i])
+    }
+
+    /// Returns the item after the current item, or None if there is no such item.
+    pub fn peek_next(&self) -> Option<&T> {
+        self.ordvec.0.get(self.index + 1)
+    }
+
+    /// Moves the cursor to the next item, or past the last item if the cursor points at the last item.
+    /// Returns `false` if the cursor already points past the last item and could not be moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "A"), (2, "B")].into();
+    /// let mut cursor = ov.cursor_mut();
+    /// assert!(cursor.move_next());
+    /// assert_eq!(cursor.current(), Some(&(2, "B")));
+    /// assert!(cursor.move_next());
+    /// assert_eq!(cursor.current(), None);
+    /// assert!(!cursor.move_next());
+    /// ```
+    pub fn move_next(&mut self) -> bool {
+        if self.index < self.ordvec.len() {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Moves the cursor to the previous item.
+    /// Returns `false` if the cursor already points at the first item and could not be moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "A"), (2, "B")].into();
+    /// let mut cursor = ov.cursor_mut_at_key(&2);
+    /// assert!(cursor.move_prev());
+    /// assert_eq!(cursor.current(), Some(&(1, "A")));
+    /// assert!(!cursor.move_prev());
+    /// ```
+    pub fn move_prev(&mut self) -> bool {
+        if self.index > 0 {
+            self.index -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Moves the cursor forward to the first item with a key greater than or equal to the given key,
+    /// or past the last item if there is no such item. Only the current and following items are searched,
+    /// so the cursor is not moved if the current item's key is already greater than or equal to the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "A"), (3, "C"), (5, "E")].into();
+    /// let mut cursor = ov.cursor_mut();
+    /// cursor.seek(&2);
+    /// assert_eq!(cursor.current(), Some(&(3, "C")));
+    /// cursor.seek(&0);
+    /// assert_eq!(cursor.current(), Some(&(3, "C")));
+    /// cursor.seek(&6);
+    /// assert_eq!(cursor.current(), None);
+    /// ```
+    pub fn seek(&mut self, key: &K::Key) {
+        self.index += self.ordvec.0[self.index..]
+            .partition_point(|item| K::cmp_keys(K::get_key(item), key).is_lt());
+    }
+
+    /// Removes the current item and returns it, or None if the cursor points past the last item.
+    /// The cursor then points at the item that followed the removed one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "A"), (2, "B"), (3, "C")].into();
+    /// let mut cursor = ov.cursor_mut_at_key(&2);
+    /// assert_eq!(cursor.remove_current(), Some((2, "B")));
+    /// assert_eq!(cursor.current(), Some(&(3, "C")));
+    /// assert_eq!(ov[..], [(1, "A"), (3, "C")]);
+    /// ```
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.index < self.ordvec.len() {
+            Some(self.ordvec.vec_mut().remove(self.index))
+        } else {
+            None
+        }
+    }
+
+    /// Inserts the item before the current item (or as the last item if the cursor points past the last item).
+    /// The cursor keeps pointing at the current item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the item's key is not greater than the key of the previous item
+    /// and less than the key of the current item.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "A"), (3, "C")].into();
+    /// let mut cursor = ov.cursor_mut_at_key(&3);
+    /// cursor.insert_before((0, "?"));
+    /// ```
+    #[cfg(not(feature = "no_panic_api"))]
+    pub fn insert_before(&mut self, item: T) {
+        let key = K::get_key(&item);
+        assert!(
+            self.peek_prev()
+                .is_none_or(|prev| K::cmp_keys(K::get_key(prev), key).is_lt())
+                && self
+                    .current()
+                    .is_none_or(|cur| K::cmp_keys(key, K::get_key(cur)).is_lt()),
+            "Cannot insert an item out of order"
+        );
+        self.ordvec.vec_mut().insert(self.index, item);
+        self.index += 1;
+    }
+
+    /// Inserts the item after the current item. The cursor keeps pointing at the current item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst};
+    /// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "A"), (3, "C")].into();
+    /// let mut cursor = ov.cursor_mut();
+    /// cursor.insert_after((2, "B"));
+    /// assert_eq!(cursor.current(), Some(&(1, "A")));
+    /// assert_eq!(ov[..], [(1, "A"), (2, "B"), (3, "C")]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cursor points past the last item, or if the item's key is not greater than
+    /// the key of the current item and less than the key of the next item.
+    #[cfg(not(feature = "no_panic_api"))]
+    pub fn insert_after(&mut self, item: T) {
+        let current = self
+            .current()
+            .expect("Cannot insert after the end of the collection");
+        let key = K::get_key(&item);
+        assert!(
+            K::cmp_keys(K::get_key(current), key).is_lt()
+                && self
+                    .peek_next()
+                    .is_none_or(|next| K::cmp_keys(key, K::get_key(next)).is_lt()),
+            "Cannot insert an item out of order"
+        );
+        self.ordvec.vec_mut().insert(self.index + 1, item);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{OrdVec, OrdVecKey};
+
+/// Counter of structural modifications of an [`OrdVec`], used to detect stale [`OrdIndex`] handles.
+/// Compiled out in release builds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Generation {
+    #[cfg(debug_assertions)]
+    count: u64,
+}
+
+impl Generation {
+    pub(crate) const fn new() -> Self {
+        Generation {
+            #[cfg(debug_assertions)]
+            count: 0,
+        }
+    }
+
+    pub(crate) fn bump(&mut self) {
+        #[cfg(debug_assertions)]
+        {
+            self.count = self.count.wrapping_add(1);
+        }
+    }
+}
+
+/// Position of an item in an [`OrdVec`], returned by [`OrdVec::index_of_key`] and accepted by
+/// [`OrdVec::get_at`] to access the item again without repeating the binary search.
+///
+/// An index is invalidated by any operation that inserts or removes items, since the items
+/// after the insertion or removal point shift to other positions. This includes
+/// [`insert`](struct.OrdVec.html#method.insert), [`remove_by_key`](struct.OrdVec.html#method.remove_by_key),
+/// [`modify_by_key`](struct.OrdVec.html#method.modify_by_key) and
+/// [`with_mut_items`](struct.OrdVec.html#method.with_mut_items), even when they do not move
+/// the indexed item. Modifying items through [`get_mut_by_key`](struct.OrdVec.html#method.get_mut_by_key)
+/// does not invalidate indexes.
+///
+/// In debug builds, [`OrdVec::get_at`] panics when given an invalidated index. In release builds,
+/// the check is omitted and an invalidated index may refer to a different item.
+/// Using an index with an [`OrdVec`] other than the one that returned it is not detected.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+/// let mut ov: OrdVec<_, OrdVecKeyFst> = vec![(1, "A"), (3, "C")].into();
+/// let idx = ov.index_of_key(&3).unwrap();
+/// assert_eq!(idx.index(), 1);
+/// assert_eq!(ov.get_at(idx), Some(&(3, "C")));
+///
+/// ov.insert((2, "B"));
+/// // `ov.get_at(idx)` would panic in debug builds here, so look the key up again
+/// let idx = ov.index_of_key(&3).unwrap();
+/// assert_eq!(idx.index(), 2);
+/// assert_eq!(ov.get_at(idx), Some(&(3, "C")));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct OrdIndex {
+    index: usize,
+    #[cfg(debug_assertions)]
+    generation: u64,
+}
+
+impl OrdIndex {
+    /// Returns the position of the item in the underlying slice.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl<T, K: OrdVecKey<T>> OrdVec<T, K> {
+    /// Returns an [`OrdIndex`] handle to the item with the given key,
+    /// or None if such an item is not found.
+    pub fn index_of_key(&self, k: &K::Key) -> Option<OrdIndex> {
+        self.get_index_by_key(k).map(|index| OrdIndex {
+            index,
+            #[cfg(debug_assertions)]
+            generation: self.2.count,
+        })
+    }
+
+    /// Returns a reference to the item at the given [`OrdIndex`], or None if the index
+    /// is out of bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the index was invalidated by an insertion or removal
+    /// after it was returned by [`index_of_key`](struct.OrdVec.html#method.index_of_key).
+    pub fn get_at(&self, index: OrdIndex) -> Option<&T> {
+        #[cfg(debug_assertions)]
+        assert_eq!(
+            index.generation, self.2.count,
+            "OrdIndex was invalidated by an insertion or removal"
+        );
+        self.0.get(index.index)
+    }
+}
@@ -0,0 +1,437 @@
+use std::{
+    iter::FusedIterator,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{DuplicateKeyError, OrdSlice, OrdVec, OrdVecKeyFst, OrdVecKeyIdentity};
+
+/// Ordered map that stores keys and values in two parallel [`Vec`]s,
+/// with the lookup methods of an [`OrdVec`] of `(K, V)` tuples.
+///
+/// Binary search only reads the compact array of keys, so fewer cache lines are touched
+/// per lookup than with an [`OrdVec`] of tuples, where each key is followed by its value.
+/// This is most noticeable when values are large. Inserts and removals shift both arrays.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::OrdVecSoA;
+/// let mut sizes: OrdVecSoA<u32, [u8; 64]> = vec![(3, [3; 64]), (1, [1; 64])].into_iter().collect();
+/// sizes.insert(2, [2; 64]);
+/// assert_eq!(sizes.keys(), [1, 2, 3]);
+/// assert_eq!(sizes.get_by_key(&2), Some(&[2; 64]));
+/// assert_eq!(sizes.remove_by_key(&1), Some([1; 64]));
+/// assert_eq!(sizes.range_by_key(3..).0, [3]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OrdVecSoA<K: Ord, V> {
+    keys: Vec<K>,
+    values: Vec<V>,
+}
+
+impl<K: Ord, V> OrdVecSoA<K, V> {
+    /// Creates an empty [`OrdVecSoA`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let ov: OrdVecSoA<u32, String> = OrdVecSoA::new();
+    /// assert!(ov.is_empty());
+    /// ```
+    pub const fn new() -> Self {
+        OrdVecSoA {
+            keys: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Creates an empty [`OrdVecSoA`] with space for at least `capacity` entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let mut ov = OrdVecSoA::with_capacity(2);
+    /// ov.insert(1, 'a');
+    /// ov.insert(2, 'b');
+    /// assert_eq!(ov.len(), 2);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        OrdVecSoA {
+            keys: Vec::with_capacity(capacity),
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Creates an [`OrdVecSoA`] from the given entries, sorting them by key.
+    /// Panics if there are duplicate keys.
+    /// See [`OrdVec::new_from_unsorted`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let ov = OrdVecSoA::new_from_unsorted(vec![(3, 'c'), (1, 'a'), (2, 'b')]);
+    /// assert_eq!(ov.keys(), [1, 2, 3]);
+    /// assert_eq!(ov.values(), ['a', 'b', 'c']);
+    /// ```
+    pub fn new_from_unsorted(entries: Vec<(K, V)>) -> Self {
+        match Self::try_new_from_unsorted(entries) {
+            Ok(ordvec) => ordvec,
+            Err(_) => panic!("Duplicate keys are not allowed"),
+        }
+    }
+
+    /// Creates an [`OrdVecSoA`] from the given entries, sorting them by key, or returns an error
+    /// with one of the entries if there are duplicate keys.
+    /// See [`OrdVec::try_new_from_unsorted`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let ov = OrdVecSoA::try_new_from_unsorted(vec![(2, 'b'), (1, 'a')]).unwrap();
+    /// assert_eq!(ov.keys(), [1, 2]);
+    /// let err = OrdVecSoA::try_new_from_unsorted(vec![(1, 'a'), (2, 'b'), (1, 'c')]).unwrap_err();
+    /// assert_eq!(err.into_item().0, 1);
+    /// ```
+    pub fn try_new_from_unsorted(
+        entries: Vec<(K, V)>,
+    ) -> Result<Self, DuplicateKeyError<(K, V), OrdVecKeyFst>> {
+        OrdVec::try_new_from_unsorted(entries).map(Into::into)
+    }
+
+    /// Returns the number of entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let ov: OrdVecSoA<_, _> = vec![(1, 'a'), (2, 'b')].into_iter().collect();
+    /// assert_eq!(ov.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns true if there are no entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let mut ov = OrdVecSoA::new();
+    /// assert!(ov.is_empty());
+    /// ov.insert(1, 'a');
+    /// assert!(!ov.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the keys in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let ov: OrdVecSoA<_, _> = vec![(2, 'b'), (1, 'a')].into_iter().collect();
+    /// assert_eq!(ov.keys(), [1, 2]);
+    /// ```
+    pub fn keys(&self) -> &[K] {
+        &self.keys
+    }
+
+    /// Returns the values in ascending order of their keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let ov: OrdVecSoA<_, _> = vec![(2, 'b'), (1, 'a')].into_iter().collect();
+    /// assert_eq!(ov.values(), ['a', 'b']);
+    /// ```
+    pub fn values(&self) -> &[V] {
+        &self.values
+    }
+
+    /// Returns the values in ascending order of their keys as a mutable slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let mut ov: OrdVecSoA<_, _> = vec![(2, 20), (1, 10)].into_iter().collect();
+    /// ov.values_mut().iter_mut().for_each(|v| *v += 1);
+    /// assert_eq!(ov.values(), [11, 21]);
+    /// ```
+    pub fn values_mut(&mut self) -> &mut [V] {
+        &mut self.values
+    }
+
+    /// Returns the keys as an [`OrdSlice`] for use with functions that accept sorted keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let ov: OrdVecSoA<_, _> = vec![(5, 'e'), (1, 'a'), (3, 'c')].into_iter().collect();
+    /// let keys = ov.keys_as_ord_slice();
+    /// assert!(keys.contains_key(&3));
+    /// assert_eq!(keys.range_by_key(2..)[..], [3, 5]);
+    /// ```
+    pub fn keys_as_ord_slice(&self) -> OrdSlice<'_, K, OrdVecKeyIdentity> {
+        OrdSlice::new_unchecked(&self.keys)
+    }
+
+    /// Returns the index of the entry with the given key, or None if such an entry is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let ov: OrdVecSoA<_, _> = vec![(5, 'e'), (1, 'a')].into_iter().collect();
+    /// assert_eq!(ov.get_index_by_key(&5), Some(1));
+    /// assert_eq!(ov.get_index_by_key(&2), None);
+    /// ```
+    pub fn get_index_by_key(&self, k: &K) -> Option<usize> {
+        self.keys.binary_search(k).ok()
+    }
+
+    /// Looks up a value by key using binary search over the keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let ov: OrdVecSoA<_, _> = vec![(5, 'e'), (1, 'a')].into_iter().collect();
+    /// assert_eq!(ov.get_by_key(&5), Some(&'e'));
+    /// assert_eq!(ov.get_by_key(&2), None);
+    /// ```
+    pub fn get_by_key(&self, k: &K) -> Option<&V> {
+        self.get_index_by_key(k).map(|i| &self.values[i])
+    }
+
+    /// Looks up a value by key using binary search over the keys, and returns a mutable reference to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let mut ov: OrdVecSoA<_, _> = vec![(1, 'a')].into_iter().collect();
+    /// *ov.get_mut_by_key(&1).unwrap() = 'A';
+    /// assert_eq!(ov.values(), ['A']);
+    /// assert_eq!(ov.get_mut_by_key(&2), None);
+    /// ```
+    pub fn get_mut_by_key(&mut self, k: &K) -> Option<&mut V> {
+        self.get_index_by_key(k).map(|i| &mut self.values[i])
+    }
+
+    /// Returns true if an entry with the given key is present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let ov: OrdVecSoA<_, _> = vec![(1, 'a')].into_iter().collect();
+    /// assert!(ov.contains_key(&1));
+    /// assert!(!ov.contains_key(&2));
+    /// ```
+    pub fn contains_key(&self, k: &K) -> bool {
+        self.get_index_by_key(k).is_some()
+    }
+
+    /// Inserts a new entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let mut ov = OrdVecSoA::new();
+    /// ov.insert(5, "B");
+    /// ov.insert(1, "A");
+    /// assert_eq!((ov.keys(), ov.values()), (&[1, 5][..], &["A", "B"][..]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is an existing entry with the same key.
+    /// See [`try_insert`](struct.OrdVecSoA.html#method.try_insert) for a non-panicking version.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let mut ov = OrdVecSoA::new();
+    /// ov.insert(5, "B");
+    /// ov.insert(5, "A");
+    /// ```
+    pub fn insert(&mut self, k: K, v: V) {
+        if self.try_insert(k, v).is_err() {
+            panic!("Cannot insert an item with a duplicate key");
+        }
+    }
+
+    /// Inserts a new entry, or returns it in an error if an entry with the same key is already present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let mut ov = OrdVecSoA::new();
+    /// assert!(ov.try_insert(5, "B").is_ok());
+    /// assert_eq!(ov.try_insert(5, "A").unwrap_err().into_item(), (5, "A"));
+    /// ```
+    pub fn try_insert(
+        &mut self,
+        k: K,
+        v: V,
+    ) -> Result<(), DuplicateKeyError<(K, V), OrdVecKeyFst>> {
+        let index = match self.keys.last() {
+            Some(last) if &k <= last => match self.keys.binary_search(&k) {
+                Ok(_) => return Err(DuplicateKeyError::new((k, v))),
+                Err(i) => i,
+            },
+            _ => self.keys.len(),
+        };
+        self.keys.insert(index, k);
+        self.values.insert(index, v);
+        Ok(())
+    }
+
+    /// Removes the entry with the given key and returns its value, or None if such an entry is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let mut ov: OrdVecSoA<_, _> = vec![(1, 'a'), (2, 'b')].into_iter().collect();
+    /// assert_eq!(ov.remove_by_key(&1), Some('a'));
+    /// assert_eq!(ov.remove_by_key(&1), None);
+    /// assert_eq!((ov.keys(), ov.values()), (&[2][..], &['b'][..]));
+    /// ```
+    pub fn remove_by_key(&mut self, k: &K) -> Option<V> {
+        let index = self.get_index_by_key(k)?;
+        self.keys.remove(index);
+        Some(self.values.remove(index))
+    }
+
+    /// Returns the keys and the values of the entries with keys in the given range.
+    /// See [`OrdVec::range_by_key`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let ov: OrdVecSoA<_, _> = vec![(1, 'a'), (3, 'c'), (5, 'e'), (7, 'g')].into_iter().collect();
+    /// assert_eq!(ov.range_by_key(2..=5), (&[3, 5][..], &['c', 'e'][..]));
+    /// assert_eq!(ov.range_by_key(8..).0, []);
+    /// ```
+    pub fn range_by_key(&self, range: impl RangeBounds<K>) -> (&[K], &[V]) {
+        let start = match range.start_bound() {
+            Bound::Included(k) => self.keys.partition_point(|key| key < k),
+            Bound::Excluded(k) => self.keys.partition_point(|key| key <= k),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(k) => self.keys.partition_point(|key| key <= k),
+            Bound::Excluded(k) => self.keys.partition_point(|key| key < k),
+            Bound::Unbounded => self.keys.len(),
+        }
+        .max(start);
+        (&self.keys[start..end], &self.values[start..end])
+    }
+
+    /// Returns an iterator over the keys and values in ascending order of keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let ov: OrdVecSoA<_, _> = vec![(2, 'b'), (1, 'a')].into_iter().collect();
+    /// assert_eq!(ov.iter().collect::<Vec<_>>(), vec![(&1, &'a'), (&2, &'b')]);
+    /// ```
+    pub fn iter(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (&K, &V)> + DoubleEndedIterator + FusedIterator {
+        self.keys.iter().zip(self.values.iter())
+    }
+
+    /// Returns an iterator over the keys and mutable references to the values in ascending order of keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let mut ov: OrdVecSoA<_, _> = vec![(2, 0), (1, 0)].into_iter().collect();
+    /// for (k, v) in ov.iter_mut() {
+    ///     *v = k * 10;
+    /// }
+    /// assert_eq!(ov.values(), [10, 20]);
+    /// ```
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl ExactSizeIterator<Item = (&K, &mut V)> + DoubleEndedIterator + FusedIterator {
+        self.keys.iter().zip(self.values.iter_mut())
+    }
+
+    /// Removes all entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecSoA;
+    /// let mut ov: OrdVecSoA<_, _> = vec![(1, 'a')].into_iter().collect();
+    /// ov.clear();
+    /// assert!(ov.is_empty());
+    /// assert!(ov.values().is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.keys.clear();
+        self.values.clear();
+    }
+}
+
+impl<K: Ord, V> Default for OrdVecSoA<K, V> {
+    fn default() -> Self {
+        OrdVecSoA::new()
+    }
+}
+
+/// Splits the tuples of an [`OrdVec`] into parallel arrays of keys and values.
+impl<K: Ord, V> From<OrdVec<(K, V), OrdVecKeyFst>> for OrdVecSoA<K, V> {
+    fn from(ordvec: OrdVec<(K, V), OrdVecKeyFst>) -> Self {
+        let (keys, values) = ordvec.into_vec().into_iter().unzip();
+        OrdVecSoA { keys, values }
+    }
+}
+
+/// Joins the keys and values into the tuples of an [`OrdVec`].
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKeyFst, OrdVecSoA};
+/// let soa: OrdVecSoA<_, _> = vec![(2, "B"), (1, "A")].into_iter().collect();
+/// let aos: OrdVec<_, OrdVecKeyFst> = soa.into();
+/// assert_eq!(aos[..], [(1, "A"), (2, "B")]);
+/// ```
+impl<K: Ord, V> From<OrdVecSoA<K, V>> for OrdVec<(K, V), OrdVecKeyFst> {
+    fn from(soa: OrdVecSoA<K, V>) -> Self {
+        OrdVec::from_sorted_vec_unchecked(soa.keys.into_iter().zip(soa.values).collect())
+    }
+}
+
+/// Collects the entries and sorts them by key. Panics if there are duplicate keys.
+impl<K: Ord, V> FromIterator<(K, V)> for OrdVecSoA<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        OrdVecSoA::new_from_unsorted(iter.into_iter().collect())
+    }
+}
+
+impl<K: Ord, V> IntoIterator for OrdVecSoA<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::iter::Zip<std::vec::IntoIter<K>, std::vec::IntoIter<V>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys.into_iter().zip(self.values)
+    }
+}
@@ -0,0 +1,261 @@
+use crate::{array2::num_elements_or_panic, Array2};
+
+/// Builder for an [`Array2`] whose rows are set individually and in any order,
+/// e.g. as tiles of a grid arrive from the network.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{Array2, PartialArray2};
+/// let mut partial = PartialArray2::new(2, 3);
+/// partial.set_row(2, [5, 6]);
+/// partial.set_row(0, [1, 2]);
+/// assert_eq!(partial.missing_rows().collect::<Vec<_>>(), vec![1]);
+///
+/// let mut partial = partial.finish().unwrap_err().into_partial();
+/// partial.set_row(1, [3, 4]);
+/// assert_eq!(partial.finish().unwrap(), Array2::new_from_rows([[1, 2], [3, 4], [5, 6]]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PartialArray2<T> {
+    data: Box<[Option<T>]>,
+    num_cols: usize,
+    is_row_set: Box<[bool]>,
+}
+
+impl<T> PartialArray2<T> {
+    /// Creates a [`PartialArray2`] of the given dimensions with no rows set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::PartialArray2;
+    /// let partial = PartialArray2::<u8>::new(4, 2);
+    /// assert_eq!((partial.num_cols(), partial.num_rows()), (4, 2));
+    /// assert_eq!(partial.missing_rows().collect::<Vec<_>>(), vec![0, 1]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements overflows `usize`, like [`Array2::new`].
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::PartialArray2;
+    /// let partial = PartialArray2::<u8>::new(1 << (usize::BITS - 1), 2);
+    /// ```
+    pub fn new(num_cols: usize, num_rows: usize) -> Self {
+        PartialArray2 {
+            data: (0..num_elements_or_panic(num_cols, num_rows))
+                .map(|_| None)
+                .collect(),
+            num_cols,
+            is_row_set: vec![false; num_rows].into_boxed_slice(),
+        }
+    }
+
+    /// Returns the number of columns (elements per row).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::PartialArray2;
+    /// let partial = PartialArray2::<u8>::new(4, 2);
+    /// assert_eq!(partial.num_cols(), 4);
+    /// ```
+    pub const fn num_cols(&self) -> usize {
+        self.num_cols
+    }
+
+    /// Returns the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::PartialArray2;
+    /// let partial = PartialArray2::<u8>::new(4, 2);
+    /// assert_eq!(partial.num_rows(), 2);
+    /// ```
+    pub const fn num_rows(&self) -> usize {
+        self.is_row_set.len()
+    }
+
+    /// Sets the elements of the row at the given index, replacing the previous elements if the row is already set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, PartialArray2};
+    /// let mut partial = PartialArray2::new(2, 3);
+    /// // Rows may arrive in any order
+    /// partial.set_row(2, [5, 6]);
+    /// partial.set_row(0, [1, 2]);
+    /// partial.set_row(1, [0, 0]);
+    /// // Setting a row again overwrites it
+    /// partial.set_row(1, [3, 4]);
+    /// assert_eq!(partial.finish().unwrap(), Array2::new_from_rows([[1, 2], [3, 4], [5, 6]]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row index is out of bounds or the number of elements is not equal to the number of columns.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::PartialArray2;
+    /// let mut partial = PartialArray2::new(2, 2);
+    /// partial.set_row(0, [1, 2, 3]);
+    /// ```
+    pub fn set_row(
+        &mut self,
+        row_index: usize,
+        row: impl IntoIterator<Item = T, IntoIter = impl ExactSizeIterator<Item = T>>,
+    ) {
+        assert!(
+            row_index < self.num_rows(),
+            "Row index {} is out of bounds",
+            row_index
+        );
+        let row = row.into_iter();
+        assert!(
+            row.len() == self.num_cols,
+            "Row must have {} elements, got {}",
+            self.num_cols,
+            row.len()
+        );
+        let start = row_index * self.num_cols;
+        for (dst, elt) in self.data[start..start + self.num_cols].iter_mut().zip(row) {
+            *dst = Some(elt);
+        }
+        self.is_row_set[row_index] = true;
+    }
+
+    /// Returns `true` if the row at the given index has been set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::PartialArray2;
+    /// let mut partial = PartialArray2::new(2, 2);
+    /// partial.set_row(1, ['a', 'b']);
+    /// assert!(!partial.is_row_set(0));
+    /// assert!(partial.is_row_set(1));
+    /// assert!(!partial.is_row_set(2));
+    /// ```
+    pub fn is_row_set(&self, row_index: usize) -> bool {
+        self.is_row_set.get(row_index).copied().unwrap_or(false)
+    }
+
+    /// Returns an iterator over indexes of rows that have not been set, in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::PartialArray2;
+    /// let mut partial = PartialArray2::new(1, 4);
+    /// partial.set_row(2, [0]);
+    /// partial.set_row(0, [0]);
+    /// assert_eq!(partial.missing_rows().collect::<Vec<_>>(), vec![1, 3]);
+    /// ```
+    pub fn missing_rows(&self) -> impl Iterator<Item = usize> + '_ {
+        self.is_row_set
+            .iter()
+            .enumerate()
+            .filter(|(_, &is_set)| !is_set)
+            .map(|(i, _)| i)
+    }
+
+    /// Returns `true` if all rows have been set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::PartialArray2;
+    /// let mut partial = PartialArray2::new(1, 2);
+    /// partial.set_row(0, [0]);
+    /// assert!(!partial.is_complete());
+    /// partial.set_row(1, [1]);
+    /// assert!(partial.is_complete());
+    /// ```
+    pub fn is_complete(&self) -> bool {
+        self.is_row_set.iter().all(|&is_set| is_set)
+    }
+
+    /// Returns the complete [`Array2`] if all rows have been set. Otherwise, returns an error
+    /// from which the [`PartialArray2`] can be recovered to set the missing rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, PartialArray2};
+    /// let mut partial = PartialArray2::new(2, 2);
+    /// partial.set_row(1, [3, 4]);
+    /// let err = partial.finish().unwrap_err();
+    /// assert_eq!(err.to_string(), "1 of 2 rows are missing, starting with row 0");
+    ///
+    /// let mut partial = err.into_partial();
+    /// partial.set_row(0, [1, 2]);
+    /// assert_eq!(partial.finish().unwrap(), Array2::new_from_rows([[1, 2], [3, 4]]));
+    /// ```
+    pub fn finish(self) -> Result<Array2<T>, MissingRows<T>> {
+        if !self.is_complete() {
+            return Err(MissingRows(self));
+        }
+        let data = self.data.into_vec().into_iter().flatten().collect();
+        Ok(Array2 {
+            data,
+            num_cols: self.num_cols,
+        })
+    }
+}
+
+/// Error returned by [`PartialArray2::finish`] when some rows have not been set.
+#[derive(Debug, Clone)]
+pub struct MissingRows<T>(PartialArray2<T>);
+
+impl<T> MissingRows<T> {
+    /// Returns an iterator over indexes of rows that have not been set, in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::PartialArray2;
+    /// let partial = PartialArray2::<u8>::new(3, 2);
+    /// let err = partial.finish().unwrap_err();
+    /// assert_eq!(err.missing_rows().collect::<Vec<_>>(), vec![0, 1]);
+    /// ```
+    pub fn missing_rows(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0.missing_rows()
+    }
+
+    /// Returns the [`PartialArray2`] that could not be finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::PartialArray2;
+    /// let mut partial = PartialArray2::new(1, 2);
+    /// partial.set_row(0, ["a"]);
+    /// let mut partial = partial.finish().unwrap_err().into_partial();
+    /// assert!(partial.is_row_set(0));
+    /// partial.set_row(1, ["b"]);
+    /// assert!(partial.finish().is_ok());
+    /// ```
+    pub fn into_partial(self) -> PartialArray2<T> {
+        self.0
+    }
+}
+
+impl<T> std::fmt::Display for MissingRows<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut missing_rows = self.missing_rows();
+        let first = missing_rows.next().unwrap_or_default();
+        write!(
+            f,
+            "{} of {} rows are missing, starting with row {}",
+            missing_rows.count() + 1,
+            self.0.num_rows(),
+            first
+        )
+    }
+}
+
+impl<T: std::fmt::Debug> std::error::Error for MissingRows<T> {}
@@ -0,0 +1,402 @@
+use std::{
+    collections::VecDeque,
+    iter::FusedIterator,
+    ops::{Index, IndexMut},
+};
+
+/// Fixed-capacity circular buffer stored in a single allocation, which evicts the oldest item
+/// when an item is pushed into a full buffer.
+///
+/// The buffer is allocated once with the given capacity and never grows,
+/// unlike [`VecDeque`], which it wraps. Because the items wrap around the end of the allocation,
+/// they are exposed as two slices by [`as_slices`](struct.RingArray.html#method.as_slices),
+/// or as one slice after [`make_contiguous`](struct.RingArray.html#method.make_contiguous).
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::RingArray;
+/// // Keep the last 3 samples
+/// let mut samples = RingArray::new(3);
+/// for sample in [10, 20, 30] {
+///     assert_eq!(samples.push_back(sample), None);
+/// }
+/// assert_eq!(samples.push_back(40), Some(10));
+/// assert_eq!(samples.iter().sum::<i32>(), 90);
+/// assert_eq!(samples.make_contiguous(), [20, 30, 40]);
+/// assert_eq!(samples.pop_front(), Some(20));
+/// assert_eq!(samples.len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RingArray<T> {
+    items: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<T> RingArray<T> {
+    /// Creates an empty [`RingArray`] that holds at most `capacity` items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(0);
+    /// assert!(ring.is_empty() && ring.is_full());
+    /// // A buffer with zero capacity evicts every pushed item immediately
+    /// assert_eq!(ring.push_back(1), Some(1));
+    /// assert_eq!(ring.try_push_back(2), Err(2));
+    /// assert!(ring.is_empty());
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        RingArray {
+            items: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Returns the maximum number of items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(2);
+    /// ring.extend([1, 2, 3]);
+    /// assert_eq!(ring.capacity(), 2);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(3);
+    /// ring.extend([1, 2]);
+    /// assert_eq!(ring.len(), 2);
+    /// ring.extend([3, 4]);
+    /// assert_eq!(ring.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if there are no items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(3);
+    /// assert!(ring.is_empty());
+    /// ring.push_back(1);
+    /// assert!(!ring.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns true if the number of items is equal to the capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(2);
+    /// ring.push_back(1);
+    /// assert!(!ring.is_full());
+    /// ring.push_back(2);
+    /// assert!(ring.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.items.len() == self.capacity
+    }
+
+    /// Appends an item to the back, evicting the front item if the buffer is full.
+    ///
+    /// Returns the evicted item, which is the pushed item itself if the capacity is zero,
+    /// or None if the buffer was not full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(2);
+    /// assert_eq!(ring.push_back('a'), None);
+    /// assert_eq!(ring.push_back('b'), None);
+    /// // Items are evicted oldest first
+    /// assert_eq!(ring.push_back('c'), Some('a'));
+    /// assert_eq!(ring.push_back('d'), Some('b'));
+    /// assert_eq!(ring.iter().collect::<String>(), "cd");
+    /// ```
+    pub fn push_back(&mut self, item: T) -> Option<T> {
+        if self.capacity == 0 {
+            return Some(item);
+        }
+        let evicted = if self.is_full() {
+            self.items.pop_front()
+        } else {
+            None
+        };
+        self.items.push_back(item);
+        evicted
+    }
+
+    /// Appends an item to the back, or returns it as an error if the buffer is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(1);
+    /// assert_eq!(ring.try_push_back(1), Ok(()));
+    /// assert_eq!(ring.try_push_back(2), Err(2));
+    /// assert_eq!(ring.front(), Some(&1));
+    /// ```
+    pub fn try_push_back(&mut self, item: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(item);
+        }
+        self.items.push_back(item);
+        Ok(())
+    }
+
+    /// Removes the front (oldest) item and returns it, or None if the buffer is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(2);
+    /// ring.extend([1, 2, 3]);
+    /// assert_eq!(ring.pop_front(), Some(2));
+    /// assert_eq!(ring.pop_front(), Some(3));
+    /// assert_eq!(ring.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.items.pop_front()
+    }
+
+    /// Removes the back (newest) item and returns it, or None if the buffer is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(2);
+    /// ring.extend([1, 2, 3]);
+    /// assert_eq!(ring.pop_back(), Some(3));
+    /// assert_eq!(ring.pop_back(), Some(2));
+    /// assert_eq!(ring.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.items.pop_back()
+    }
+
+    /// Returns a reference to the front (oldest) item, or None if the buffer is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(2);
+    /// assert_eq!(ring.front(), None);
+    /// ring.extend([1, 2, 3]);
+    /// assert_eq!(ring.front(), Some(&2));
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        self.items.front()
+    }
+
+    /// Returns a reference to the back (newest) item, or None if the buffer is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(2);
+    /// assert_eq!(ring.back(), None);
+    /// ring.extend([1, 2, 3]);
+    /// assert_eq!(ring.back(), Some(&3));
+    /// ```
+    pub fn back(&self) -> Option<&T> {
+        self.items.back()
+    }
+
+    /// Returns a reference to the item at the given index, counting from the front,
+    /// or None if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(3);
+    /// ring.extend([1, 2, 3, 4]);
+    /// assert_eq!(ring.get(0), Some(&2));
+    /// assert_eq!(ring.get(2), Some(&4));
+    /// assert_eq!(ring.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.items.get(index)
+    }
+
+    /// Returns a mutable reference to the item at the given index, counting from the front,
+    /// or None if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(2);
+    /// ring.extend([1, 2, 3]);
+    /// *ring.get_mut(1).unwrap() = 30;
+    /// assert_eq!(ring[1], 30);
+    /// assert_eq!(ring.get_mut(2), None);
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.items.get_mut(index)
+    }
+
+    /// Returns an iterator over the items from front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(3);
+    /// ring.extend([1, 2, 3, 4]);
+    /// assert_eq!(ring.iter().rev().copied().collect::<Vec<_>>(), vec![4, 3, 2]);
+    /// ```
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &T> + DoubleEndedIterator + FusedIterator {
+        self.items.iter()
+    }
+
+    /// Returns an iterator over mutable references to the items from front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(3);
+    /// ring.extend([1, 2, 3, 4]);
+    /// ring.iter_mut().for_each(|item| *item *= 10);
+    /// assert_eq!(ring.make_contiguous(), [20, 30, 40]);
+    /// ```
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl ExactSizeIterator<Item = &mut T> + DoubleEndedIterator + FusedIterator {
+        self.items.iter_mut()
+    }
+
+    /// Returns the items from front to back as two slices of the underlying buffer.
+    /// The second slice is empty if the items do not wrap around the end of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(3);
+    /// ring.extend([1, 2, 3, 4]);
+    /// let (first, second) = ring.as_slices();
+    /// assert_eq!([first, second].concat(), [2, 3, 4]);
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        self.items.as_slices()
+    }
+
+    /// Returns the items from front to back as two mutable slices of the underlying buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(3);
+    /// ring.extend([1, 2, 3, 4]);
+    /// let (first, second) = ring.as_mut_slices();
+    /// first.iter_mut().chain(second.iter_mut()).for_each(|item| *item = -*item);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<_>>(), vec![-2, -3, -4]);
+    /// ```
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        self.items.as_mut_slices()
+    }
+
+    /// Rotates the underlying buffer so that the items do not wrap around its end,
+    /// and returns them as a single mutable slice from front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(3);
+    /// ring.extend([3, 1, 4, 2]);
+    /// ring.make_contiguous().sort();
+    /// assert_eq!(ring.as_slices(), (&[1, 2, 4][..], &[][..]));
+    /// ```
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        self.items.make_contiguous()
+    }
+
+    /// Removes all items, keeping the allocated memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::RingArray;
+    /// let mut ring = RingArray::new(2);
+    /// ring.extend([1, 2]);
+    /// ring.clear();
+    /// assert!(ring.is_empty());
+    /// assert_eq!(ring.capacity(), 2);
+    /// ```
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+}
+
+/// Pushes each item to the back, evicting front items when the buffer is full.
+impl<T> Extend<T> for RingArray<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
+
+impl<T> Index<usize> for RingArray<T> {
+    type Output = T;
+
+    /// Returns a reference to the item at the given index, counting from the front.
+    ///
+    /// Panics if the index is out of bounds. See [`get`](struct.RingArray.html#method.get) for a non-panicking version.
+    fn index(&self, index: usize) -> &T {
+        &self.items[index]
+    }
+}
+
+impl<T> IndexMut<usize> for RingArray<T> {
+    /// Returns a mutable reference to the item at the given index, counting from the front.
+    ///
+    /// Panics if the index is out of bounds. See [`get_mut`](struct.RingArray.html#method.get_mut) for a non-panicking version.
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.items[index]
+    }
+}
+
+impl<T> IntoIterator for RingArray<T> {
+    type Item = T;
+    type IntoIter = std::collections::vec_deque::IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RingArray<T> {
+    type Item = &'a T;
+    type IntoIter = std::collections::vec_deque::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
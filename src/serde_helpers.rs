@@ -0,0 +1,207 @@
+//! Alternative serde representations of collections, for use with `#[serde(with = "...")]`.
+
+/// Represents an [`Array2`](crate::Array2) as a flat sequence of elements in row-major order
+/// with the number of columns: `{"data": [1, 2, 3, 4, 5, 6], "num_cols": 3}` in JSON.
+///
+/// This is the representation used by `contiguous_collections` 0.2.0 and earlier,
+/// so it can be used to read previously serialized data. Deserialization fails if
+/// the number of elements is not a multiple of the number of columns.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::Array2;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Image {
+///     #[serde(with = "contiguous_collections::serde_helpers::array2_flat")]
+///     pixels: Array2<u8>,
+/// }
+///
+/// let image = Image { pixels: Array2::new_from_rows([[1, 2, 3], [4, 5, 6]]) };
+/// let json = serde_json::to_string(&image).unwrap();
+/// assert_eq!(json, r#"{"pixels":{"data":[1,2,3,4,5,6],"num_cols":3}}"#);
+/// assert_eq!(serde_json::from_str::<Image>(&json).unwrap().pixels, image.pixels);
+///
+/// let corrupt = r#"{"pixels":{"data":[1,2,3,4],"num_cols":3}}"#;
+/// assert!(serde_json::from_str::<Image>(corrupt).is_err());
+/// ```
+pub mod array2_flat {
+    use crate::Array2;
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    #[serde(rename = "Array2")]
+    struct FlatRef<'a, T> {
+        data: &'a [T],
+        num_cols: usize,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "Array2")]
+    struct Flat<T> {
+        data: Box<[T]>,
+        num_cols: usize,
+    }
+
+    /// Serializes an [`Array2`] as a flat sequence of elements with the number of columns.
+    pub fn serialize<T: Serialize, S: Serializer>(
+        array: &Array2<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        FlatRef {
+            data: array.elements(),
+            num_cols: array.num_cols(),
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserializes an [`Array2`] from a flat sequence of elements with the number of columns.
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Array2<T>, D::Error> {
+        let Flat { data, num_cols } = Flat::deserialize(deserializer)?;
+        let is_valid = match num_cols {
+            0 => data.is_empty(),
+            _ => data.len() % num_cols == 0,
+        };
+        if !is_valid {
+            return Err(D::Error::custom(format_args!(
+                "{} elements cannot be split into rows of {} columns",
+                data.len(),
+                num_cols
+            )));
+        }
+        Ok(Array2 { data, num_cols })
+    }
+}
+
+/// Represents an [`OrdVec`](crate::OrdVec) as a sequence of items, like its default representation,
+/// but requires the items to be in strictly ascending order of keys when deserializing.
+///
+/// The default [`Deserialize`](serde::Deserialize) implementation sorts the items, which hides
+/// corrupted or hand-edited data. This representation checks the order in a single pass instead,
+/// failing with the position of the first out-of-order item, and avoids the cost of sorting.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Index {
+///     #[serde(with = "contiguous_collections::serde_helpers::ordvec_sorted")]
+///     entries: OrdVec<(u32, String), OrdVecKeyFst>,
+/// }
+///
+/// let json = r#"{"entries":[[1,"a"],[2,"b"]]}"#;
+/// let index: Index = serde_json::from_str(json).unwrap();
+/// assert_eq!(serde_json::to_string(&index).unwrap(), json);
+///
+/// let unsorted = r#"{"entries":[[2,"b"],[1,"a"]]}"#;
+/// let err = serde_json::from_str::<Index>(unsorted).err().unwrap();
+/// assert!(err.to_string().starts_with("Items at indexes 0 and 1 are not in strictly ascending order of keys"));
+/// ```
+pub mod ordvec_sorted {
+    use crate::{OrdVec, OrdVecKey};
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes an [`OrdVec`] as a sequence of items.
+    pub fn serialize<T: Serialize, K: OrdVecKey<T>, S: Serializer>(
+        ordvec: &OrdVec<T, K>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        ordvec.serialize(serializer)
+    }
+
+    /// Deserializes an [`OrdVec`] from a sequence of items in strictly ascending order of keys.
+    pub fn deserialize<'de, T: Deserialize<'de>, K: OrdVecKey<T>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<OrdVec<T, K>, D::Error> {
+        let vec = Vec::deserialize(deserializer)?;
+        OrdVec::from_sorted_vec(vec).map_err(D::Error::custom)
+    }
+}
+
+/// Represents an [`OrdVec`](crate::OrdVec) as a sequence of items, like its default representation,
+/// but keeps only the first of several items with the same key when deserializing
+/// instead of failing.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Settings {
+///     #[serde(with = "contiguous_collections::serde_helpers::ordvec_keep_first")]
+///     values: OrdVec<(String, u32), OrdVecKeyFst>,
+/// }
+///
+/// let json = r#"{"values":[["b",1],["a",2],["b",3]]}"#;
+/// let settings: Settings = serde_json::from_str(json).unwrap();
+/// assert_eq!(settings.values.get_by_key(&"b".to_string()).map(|(_, v)| *v), Some(1));
+/// ```
+pub mod ordvec_keep_first {
+    use crate::{OrdVec, OrdVecKey};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes an [`OrdVec`] as a sequence of items.
+    pub fn serialize<T: Serialize, K: OrdVecKey<T>, S: Serializer>(
+        ordvec: &OrdVec<T, K>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        ordvec.serialize(serializer)
+    }
+
+    /// Deserializes an [`OrdVec`] from a sequence of items in any order,
+    /// keeping the first of several items with the same key.
+    pub fn deserialize<'de, T: Deserialize<'de>, K: OrdVecKey<T>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<OrdVec<T, K>, D::Error> {
+        let vec = Vec::deserialize(deserializer)?;
+        Ok(OrdVec::new_from_unsorted_dedup(vec))
+    }
+}
+
+/// Represents an [`OrdVec`](crate::OrdVec) as a sequence of items, like its default representation,
+/// but keeps only the last of several items with the same key when deserializing
+/// instead of failing, so later entries override earlier ones.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKeyFst};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Settings {
+///     #[serde(with = "contiguous_collections::serde_helpers::ordvec_keep_last")]
+///     values: OrdVec<(String, u32), OrdVecKeyFst>,
+/// }
+///
+/// let json = r#"{"values":[["b",1],["a",2],["b",3]]}"#;
+/// let settings: Settings = serde_json::from_str(json).unwrap();
+/// assert_eq!(settings.values.get_by_key(&"b".to_string()).map(|(_, v)| *v), Some(3));
+/// ```
+pub mod ordvec_keep_last {
+    use crate::{OrdVec, OrdVecKey};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes an [`OrdVec`] as a sequence of items.
+    pub fn serialize<T: Serialize, K: OrdVecKey<T>, S: Serializer>(
+        ordvec: &OrdVec<T, K>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        ordvec.serialize(serializer)
+    }
+
+    /// Deserializes an [`OrdVec`] from a sequence of items in any order,
+    /// keeping the last of several items with the same key.
+    pub fn deserialize<'de, T: Deserialize<'de>, K: OrdVecKey<T>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<OrdVec<T, K>, D::Error> {
+        let mut vec = Vec::deserialize(deserializer)?;
+        vec.reverse();
+        Ok(OrdVec::new_from_unsorted_dedup(vec))
+    }
+}
@@ -0,0 +1,449 @@
+use std::{
+    iter::FusedIterator,
+    ops::{Index, IndexMut},
+};
+
+/// Key of an item in a [`SlotVec`], returned by [`SlotVec::insert`].
+///
+/// A key consists of the index of the item's slot and the generation of the slot.
+/// The generation is incremented whenever an item is removed, so keys of removed items
+/// do not refer to items inserted into the same slot later. A slot whose generation
+/// would wrap around is retired instead of being reused, so a key never becomes valid again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SlotKey {
+    index: u32,
+    generation: u32,
+}
+
+impl SlotKey {
+    /// Returns the index of the item's slot in the underlying array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::SlotVec;
+    /// let mut sv = SlotVec::new();
+    /// let a = sv.insert('a');
+    /// let b = sv.insert('b');
+    /// assert_eq!((a.index(), b.index()), (0, 1));
+    /// ```
+    pub const fn index(&self) -> usize {
+        self.index as usize
+    }
+
+    /// Returns the generation of the slot at the time the item was inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::SlotVec;
+    /// let mut sv = SlotVec::new();
+    /// let a = sv.insert('a');
+    /// sv.remove(a);
+    /// let b = sv.insert('b');
+    /// assert_eq!(b.index(), a.index());
+    /// assert_eq!((a.generation(), b.generation()), (0, 1));
+    /// ```
+    pub const fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Slot {
+    generation: u32,
+    /// Position of the slot's item in `SlotVec::values`, or None if the slot is free.
+    dense_index: Option<u32>,
+}
+
+/// Flat array of slots that hands out stable keys for inserted items, also known as a slot map
+/// or a generational arena.
+///
+/// Items are stored densely in a single [`Vec`], so iterating over them does not visit empty slots.
+/// A separate array of slots maps each [`SlotKey`] to the position of its item, and the slots
+/// of removed items are reused by later inserts. Looking up an item is two index operations
+/// plus a generation check, which detects keys of removed items. Removing an item moves
+/// the last item into its place, so the order of iteration is not the order of insertion.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::SlotVec;
+/// let mut entities = SlotVec::new();
+/// let player = entities.insert("player");
+/// let enemy = entities.insert("enemy");
+/// assert_eq!(entities.get(player), Some(&"player"));
+///
+/// assert_eq!(entities.remove(enemy), Some("enemy"));
+/// let projectile = entities.insert("projectile");
+/// // The slot of the removed item is reused, but its old key stays invalid
+/// assert_eq!(projectile.index(), enemy.index());
+/// assert_eq!(entities.get(enemy), None);
+/// assert_eq!(entities[projectile], "projectile");
+///
+/// assert_eq!(entities.values().copied().collect::<Vec<_>>(), vec!["player", "projectile"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SlotVec<T> {
+    slots: Vec<Slot>,
+    values: Vec<T>,
+    /// Index of the slot of each item in `values`.
+    slot_indexes: Vec<u32>,
+    free: Vec<u32>,
+}
+
+impl<T> SlotVec<T> {
+    /// Creates an empty [`SlotVec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::SlotVec;
+    /// let sv: SlotVec<u32> = SlotVec::new();
+    /// assert!(sv.is_empty());
+    /// ```
+    pub const fn new() -> Self {
+        SlotVec {
+            slots: Vec::new(),
+            values: Vec::new(),
+            slot_indexes: Vec::new(),
+            free: Vec::new(),
+        }
+    }
+
+    /// Creates an empty [`SlotVec`] with space for at least `capacity` items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::SlotVec;
+    /// let mut sv = SlotVec::with_capacity(10);
+    /// assert!(sv.is_empty());
+    /// sv.insert(1);
+    /// assert_eq!(sv.len(), 1);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        SlotVec {
+            slots: Vec::with_capacity(capacity),
+            values: Vec::with_capacity(capacity),
+            slot_indexes: Vec::with_capacity(capacity),
+            free: Vec::new(),
+        }
+    }
+
+    /// Returns the number of items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::SlotVec;
+    /// let mut sv = SlotVec::new();
+    /// let a = sv.insert(1);
+    /// sv.insert(2);
+    /// sv.remove(a);
+    /// assert_eq!(sv.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if there are no items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::SlotVec;
+    /// let mut sv = SlotVec::new();
+    /// let a = sv.insert(1);
+    /// assert!(!sv.is_empty());
+    /// sv.remove(a);
+    /// assert!(sv.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Inserts an item into a free slot, or into a new slot if there are none, and returns its key.
+    ///
+    /// Panics if the number of slots exceeds [`u32::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::SlotVec;
+    /// let mut sv = SlotVec::new();
+    /// let a = sv.insert("a");
+    /// assert_eq!(sv.remove(a), Some("a"));
+    /// let b = sv.insert("b");
+    /// // The new item reuses the slot, but the key of the removed one does not refer to it
+    /// assert_eq!(b.index(), a.index());
+    /// assert_eq!(sv.get(a), None);
+    /// assert_eq!(sv.get(b), Some(&"b"));
+    /// ```
+    pub fn insert(&mut self, value: T) -> SlotKey {
+        // There are never more items than slots, so the dense index fits if the slot index does
+        let dense_index = self.values.len() as u32;
+        let key = match self.free.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index as usize];
+                slot.dense_index = Some(dense_index);
+                SlotKey {
+                    index,
+                    generation: slot.generation,
+                }
+            }
+            None => {
+                let index =
+                    u32::try_from(self.slots.len()).expect("Number of slots exceeds u32::MAX");
+                self.slots.push(Slot {
+                    generation: 0,
+                    dense_index: Some(dense_index),
+                });
+                SlotKey {
+                    index,
+                    generation: 0,
+                }
+            }
+        };
+        self.values.push(value);
+        self.slot_indexes.push(key.index);
+        key
+    }
+
+    /// Removes the item with the given key and returns it, or None if the key is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::SlotVec;
+    /// let mut sv = SlotVec::new();
+    /// let a = sv.insert("a");
+    /// assert_eq!(sv.remove(a), Some("a"));
+    /// assert_eq!(sv.remove(a), None);
+    /// sv.insert("b");
+    /// assert_eq!(sv.remove(a), None);
+    /// assert_eq!(sv.len(), 1);
+    /// ```
+    pub fn remove(&mut self, key: SlotKey) -> Option<T> {
+        let dense_index = self.dense_index(key)?;
+        self.vacate(key.index);
+        let value = self.values.swap_remove(dense_index);
+        self.slot_indexes.swap_remove(dense_index);
+        if let Some(&moved) = self.slot_indexes.get(dense_index) {
+            self.slots[moved as usize].dense_index = Some(dense_index as u32);
+        }
+        Some(value)
+    }
+
+    /// Returns true if the key refers to an item in the [`SlotVec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::SlotVec;
+    /// let mut sv = SlotVec::new();
+    /// let a = sv.insert(1);
+    /// assert!(sv.contains_key(a));
+    /// sv.remove(a);
+    /// assert!(!sv.contains_key(a));
+    /// ```
+    pub fn contains_key(&self, key: SlotKey) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns a reference to the item with the given key, or None if the key is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::SlotVec;
+    /// let mut sv = SlotVec::new();
+    /// let a = sv.insert(1);
+    /// assert_eq!(sv.get(a), Some(&1));
+    /// sv.clear();
+    /// assert_eq!(sv.get(a), None);
+    /// ```
+    pub fn get(&self, key: SlotKey) -> Option<&T> {
+        self.dense_index(key).map(|i| &self.values[i])
+    }
+
+    /// Returns a mutable reference to the item with the given key, or None if the key is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::SlotVec;
+    /// let mut sv = SlotVec::new();
+    /// let a = sv.insert(1);
+    /// *sv.get_mut(a).unwrap() += 10;
+    /// assert_eq!(sv[a], 11);
+    /// sv.remove(a);
+    /// assert_eq!(sv.get_mut(a), None);
+    /// ```
+    pub fn get_mut(&mut self, key: SlotKey) -> Option<&mut T> {
+        self.dense_index(key).map(|i| &mut self.values[i])
+    }
+
+    /// Removes all items, invalidating their keys but keeping the allocated memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::SlotVec;
+    /// let mut sv = SlotVec::new();
+    /// let a = sv.insert("a");
+    /// let b = sv.insert("b");
+    /// sv.clear();
+    /// assert!(sv.is_empty());
+    /// assert!(!sv.contains_key(a) && !sv.contains_key(b));
+    /// // Slots are reused, with new generations
+    /// let c = sv.insert("c");
+    /// assert!(c.index() < 2);
+    /// assert_eq!(c.generation(), 1);
+    /// ```
+    pub fn clear(&mut self) {
+        for index in std::mem::take(&mut self.slot_indexes) {
+            self.vacate(index);
+        }
+        self.values.clear();
+    }
+
+    /// Returns an iterator over the keys and references to the items, in the order in which they are stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::SlotVec;
+    /// let mut sv = SlotVec::new();
+    /// let a = sv.insert('a');
+    /// let b = sv.insert('b');
+    /// let c = sv.insert('c');
+    /// sv.remove(b);
+    /// assert_eq!(sv.iter().collect::<Vec<_>>(), vec![(a, &'a'), (c, &'c')]);
+    /// ```
+    pub fn iter(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (SlotKey, &T)> + DoubleEndedIterator + FusedIterator {
+        let slots = &self.slots;
+        self.slot_indexes
+            .iter()
+            .zip(&self.values)
+            .map(move |(&index, value)| (slot_key(slots, index), value))
+    }
+
+    /// Returns an iterator over the keys and mutable references to the items, in the order in which they are stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::SlotVec;
+    /// let mut sv = SlotVec::new();
+    /// let a = sv.insert(1);
+    /// let b = sv.insert(2);
+    /// for (key, value) in sv.iter_mut() {
+    ///     if key == b {
+    ///         *value *= 10;
+    ///     }
+    /// }
+    /// assert_eq!((sv[a], sv[b]), (1, 20));
+    /// ```
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl ExactSizeIterator<Item = (SlotKey, &mut T)> + DoubleEndedIterator + FusedIterator
+    {
+        let slots = &self.slots;
+        self.slot_indexes
+            .iter()
+            .zip(&mut self.values)
+            .map(move |(&index, value)| (slot_key(slots, index), value))
+    }
+
+    /// Returns an iterator over references to the items, in the order in which they are stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::SlotVec;
+    /// let mut sv = SlotVec::new();
+    /// let a = sv.insert(1);
+    /// sv.insert(2);
+    /// sv.insert(3);
+    /// sv.remove(a);
+    /// assert_eq!(sv.values().sum::<i32>(), 5);
+    /// ```
+    pub fn values(&self) -> std::slice::Iter<'_, T> {
+        self.values.iter()
+    }
+
+    /// Returns an iterator over mutable references to the items, in the order in which they are stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::SlotVec;
+    /// let mut sv = SlotVec::new();
+    /// let a = sv.insert(1);
+    /// let b = sv.insert(2);
+    /// sv.values_mut().for_each(|value| *value = -*value);
+    /// assert_eq!((sv[a], sv[b]), (-1, -2));
+    /// ```
+    pub fn values_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.values.iter_mut()
+    }
+
+    /// Returns the position of the item with the given key in `values`, or None if the key is not valid.
+    fn dense_index(&self, key: SlotKey) -> Option<usize> {
+        let slot = self
+            .slots
+            .get(key.index())
+            .filter(|slot| slot.generation == key.generation)?;
+        slot.dense_index.map(|i| i as usize)
+    }
+
+    /// Marks the occupied slot at the given index as free and advances its generation,
+    /// or retires it if the generation cannot be advanced without wrapping around.
+    fn vacate(&mut self, index: u32) {
+        let slot = &mut self.slots[index as usize];
+        slot.dense_index = None;
+        if let Some(generation) = slot.generation.checked_add(1) {
+            slot.generation = generation;
+            self.free.push(index);
+        }
+    }
+}
+
+/// Returns the key of the occupied slot at the given index.
+fn slot_key(slots: &[Slot], index: u32) -> SlotKey {
+    SlotKey {
+        index,
+        generation: slots[index as usize].generation,
+    }
+}
+
+impl<T> Default for SlotVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Index<SlotKey> for SlotVec<T> {
+    type Output = T;
+
+    /// Returns a reference to the item with the given key.
+    ///
+    /// Panics if the key is not valid. See [`get`](struct.SlotVec.html#method.get) for a non-panicking version.
+    fn index(&self, key: SlotKey) -> &T {
+        self.get(key)
+            .unwrap_or_else(|| panic!("Key {:?} is not valid", key))
+    }
+}
+
+impl<T> IndexMut<SlotKey> for SlotVec<T> {
+    /// Returns a mutable reference to the item with the given key.
+    ///
+    /// Panics if the key is not valid. See [`get_mut`](struct.SlotVec.html#method.get_mut) for a non-panicking version.
+    fn index_mut(&mut self, key: SlotKey) -> &mut T {
+        self.get_mut(key)
+            .unwrap_or_else(|| panic!("Key {:?} is not valid", key))
+    }
+}
@@ -0,0 +1,248 @@
+use std::marker::PhantomData;
+
+use smallvec::SmallVec;
+
+use crate::{ordvec_index::Generation, DuplicateKeyError, OrdVec, OrdVecKey};
+
+/// Variant of [`OrdVec`] that stores up to `N` items inline, without a heap allocation,
+/// and moves them to the heap only when it grows beyond that.
+///
+/// This is useful for large numbers of small maps, where a heap allocation per map
+/// would dominate both memory usage and construction time. Requires the `smallvec` feature.
+///
+/// Keys are extracted with the same [`OrdVecKey`] types as in [`OrdVec`],
+/// and the items can be accessed as a slice through [`Deref`](std::ops::Deref).
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdSmallVec, OrdVecKeyFst};
+/// let mut osv: OrdSmallVec<(u32, &str), OrdVecKeyFst, 4> = OrdSmallVec::new();
+/// osv.insert((2, "C"));
+/// osv.insert((0, "A"));
+/// osv.insert((1, "B"));
+/// assert_eq!(osv[..], [(0, "A"), (1, "B"), (2, "C")]);
+/// assert_eq!(osv.get_by_key(&1), Some(&(1, "B")));
+/// assert!(!osv.spilled());
+/// ```
+pub struct OrdSmallVec<T, K: OrdVecKey<T>, const N: usize>(SmallVec<[T; N]>, PhantomData<K>);
+
+impl<T, K: OrdVecKey<T>, const N: usize> OrdSmallVec<T, K, N> {
+    /// Creates an empty [`OrdSmallVec`].
+    pub fn new() -> Self {
+        OrdSmallVec(SmallVec::new(), PhantomData)
+    }
+
+    /// Creates an [`OrdSmallVec`] from the given items, sorting them according to the key extraction function.
+    ///
+    /// Panics if multiple items have the same key.
+    /// See [`try_new_from_unsorted`](struct.OrdSmallVec.html#method.try_new_from_unsorted) for a non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdSmallVec, OrdVecKeyFst};
+    /// let osv = OrdSmallVec::<_, OrdVecKeyFst, 4>::new_from_unsorted([(1, "B"), (0, "A")]);
+    /// assert_eq!(osv[..], [(0, "A"), (1, "B")]);
+    /// ```
+    pub fn new_from_unsorted(items: impl IntoIterator<Item = T>) -> Self {
+        match Self::try_new_from_unsorted(items) {
+            Ok(osv) => osv,
+            Err(_) => panic!("Duplicate keys are not allowed"),
+        }
+    }
+
+    /// Creates an [`OrdSmallVec`] from the given items, sorting them according to the key extraction function,
+    /// or returns an error with one of the items that have a duplicate key.
+    pub fn try_new_from_unsorted(
+        items: impl IntoIterator<Item = T>,
+    ) -> Result<Self, DuplicateKeyError<T, K>> {
+        let mut items: SmallVec<[T; N]> = items.into_iter().collect();
+        items.sort_unstable_by(|a, b| K::cmp_keys(K::get_key(a), K::get_key(b)));
+        match items
+            .windows(2)
+            .position(|pair| K::cmp_keys(K::get_key(&pair[0]), K::get_key(&pair[1])).is_eq())
+        {
+            Some(i) => Err(DuplicateKeyError::new(items.swap_remove(i + 1))),
+            None => Ok(OrdSmallVec(items, PhantomData)),
+        }
+    }
+
+    /// Returns true if the items have been moved to the heap because there are more than `N` of them.
+    pub fn spilled(&self) -> bool {
+        self.0.spilled()
+    }
+
+    /// Inserts an item, keeping the items sorted by key.
+    ///
+    /// Panics if an item with the same key is already present.
+    /// See [`try_insert`](struct.OrdSmallVec.html#method.try_insert) for a non-panicking version.
+    pub fn insert(&mut self, item: T) {
+        if self.try_insert(item).is_err() {
+            panic!("Cannot insert an item with a duplicate key");
+        }
+    }
+
+    /// Inserts an item, keeping the items sorted by key,
+    /// or returns it in an error if an item with the same key is already present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdSmallVec, OrdVecKeyFst};
+    /// let mut osv: OrdSmallVec<(u32, &str), OrdVecKeyFst, 2> = OrdSmallVec::new();
+    /// assert!(osv.try_insert((5, "B")).is_ok());
+    /// assert_eq!(osv.try_insert((5, "A")).unwrap_err().into_item(), (5, "A"));
+    /// ```
+    pub fn try_insert(&mut self, item: T) -> Result<(), DuplicateKeyError<T, K>> {
+        match self
+            .0
+            .binary_search_by(|other| K::cmp_keys(K::get_key(other), K::get_key(&item)))
+        {
+            Ok(_) => Err(DuplicateKeyError::new(item)),
+            Err(i) => {
+                self.0.insert(i, item);
+                Ok(())
+            }
+        }
+    }
+
+    /// Looks up an item by key.
+    pub fn get_by_key(&self, k: &<K as OrdVecKey<T>>::Key) -> Option<&T> {
+        self.get_index_by_key(k).map(|i| &self.0[i])
+    }
+
+    /// Returns a mutable reference to an item looked up by key.
+    ///
+    /// Warning: the behavior of the collection is undefined if the item's key
+    /// is changed in a way that affects its ordering relative to other items.
+    pub fn get_mut_by_key(&mut self, k: &<K as OrdVecKey<T>>::Key) -> Option<&mut T> {
+        self.get_index_by_key(k).map(|i| &mut self.0[i])
+    }
+
+    /// Returns the index of the item with the given key.
+    pub fn get_index_by_key(&self, k: &<K as OrdVecKey<T>>::Key) -> Option<usize> {
+        self.0
+            .binary_search_by(|item| K::cmp_keys(K::get_key(item), k))
+            .ok()
+    }
+
+    /// Removes an item with the given key and returns it, or None if such an item is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdSmallVec, OrdVecKeyFst};
+    /// let mut osv = OrdSmallVec::<_, OrdVecKeyFst, 4>::new_from_unsorted([(20, "B"), (10, "A")]);
+    /// assert_eq!(osv.remove_by_key(&10), Some((10, "A")));
+    /// assert_eq!(osv.remove_by_key(&10), None);
+    /// ```
+    pub fn remove_by_key(&mut self, k: &<K as OrdVecKey<T>>::Key) -> Option<T> {
+        self.get_index_by_key(k).map(|i| self.0.remove(i))
+    }
+
+    /// Removes all items, keeping the allocated capacity if the items have been moved to the heap.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+impl<T, K: OrdVecKey<T>, const N: usize> Default for OrdSmallVec<T, K, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, K: OrdVecKey<T>, const N: usize> std::ops::Deref for OrdSmallVec<T, K, N> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T: Clone, K: OrdVecKey<T>, const N: usize> Clone for OrdSmallVec<T, K, N> {
+    fn clone(&self) -> Self {
+        OrdSmallVec(self.0.clone(), PhantomData)
+    }
+}
+
+impl<T: PartialEq, K: OrdVecKey<T>, const N: usize> PartialEq for OrdSmallVec<T, K, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Eq, K: OrdVecKey<T>, const N: usize> Eq for OrdSmallVec<T, K, N> {}
+
+impl<T: std::fmt::Debug, K: OrdVecKey<T>, const N: usize> std::fmt::Debug for OrdSmallVec<T, K, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_list().entries(self.0.iter()).finish()
+    }
+}
+
+/// Sorts the items according to the key extraction function.
+///
+/// Panics if multiple items have the same key.
+impl<T, K: OrdVecKey<T>, const N: usize> FromIterator<T> for OrdSmallVec<T, K, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new_from_unsorted(iter)
+    }
+}
+
+impl<T, K: OrdVecKey<T>, const N: usize> IntoIterator for OrdSmallVec<T, K, N> {
+    type Item = T;
+    type IntoIter = smallvec::IntoIter<[T; N]>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, K: OrdVecKey<T>, const N: usize> IntoIterator for &'a OrdSmallVec<T, K, N> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Converts an [`OrdSmallVec`] into an [`OrdVec`], reusing the heap allocation if the items have been moved to the heap.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdSmallVec, OrdVec, OrdVecKeyFst};
+/// let osv = OrdSmallVec::<_, OrdVecKeyFst, 2>::new_from_unsorted([(1, "B"), (0, "A")]);
+/// let ov: OrdVec<_, OrdVecKeyFst> = osv.into();
+/// assert_eq!(ov[..], [(0, "A"), (1, "B")]);
+/// ```
+impl<T, K: OrdVecKey<T>, const N: usize> From<OrdSmallVec<T, K, N>> for OrdVec<T, K> {
+    fn from(value: OrdSmallVec<T, K, N>) -> Self {
+        OrdVec(value.0.into_vec(), PhantomData, Generation::new())
+    }
+}
+
+/// Converts an [`OrdVec`] into an [`OrdSmallVec`], moving the items inline if there are at most `N` of them,
+/// regardless of the capacity of the [`OrdVec`]. Otherwise, the heap buffer of the [`OrdVec`] is reused.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdSmallVec, OrdVec, OrdVecKeyIdentity};
+/// let mut items = Vec::with_capacity(16);
+/// items.extend([1, 2]);
+/// let ov: OrdVec<_, OrdVecKeyIdentity> = OrdVec::from_sorted_vec(items).unwrap();
+/// let osv: OrdSmallVec<_, OrdVecKeyIdentity, 4> = ov.into();
+/// assert!(!osv.spilled());
+/// assert_eq!(osv[..], [1, 2]);
+/// ```
+impl<T, K: OrdVecKey<T>, const N: usize> From<OrdVec<T, K>> for OrdSmallVec<T, K, N> {
+    fn from(value: OrdVec<T, K>) -> Self {
+        // `SmallVec::from_vec` only moves the items inline if the capacity of the vector fits.
+        let items = if value.len() <= N {
+            value.0.into_iter().collect()
+        } else {
+            SmallVec::from_vec(value.0)
+        };
+        OrdSmallVec(items, PhantomData)
+    }
+}
@@ -0,0 +1,287 @@
+use std::io::{Read, Write};
+
+use crate::{ordvec::find_unsorted, NotSortedError, OrdVec, OrdVecKey};
+
+const MAGIC: [u8; 4] = *b"OVSN";
+const FORMAT_VERSION: u16 = 1;
+/// Length of the header fields known to this version: flags, schema version and item count.
+const HEADER_LEN: u16 = 16;
+/// The items are in ascending order of keys.
+const FLAG_SORTED: u32 = 1;
+/// The items have unique keys.
+const FLAG_UNIQUE: u32 = 2;
+/// Upper bound on the number of items to preallocate space for, so that a corrupted count
+/// does not cause a huge allocation before any item is read.
+const MAX_PREALLOCATED_ITEMS: u64 = 1 << 16;
+
+/// Encoding of [`OrdVec`] items in a snapshot, see [`OrdVec::write_snapshot`].
+///
+/// Each item is stored as a separate length-prefixed record, so the codec does not need to
+/// delimit items itself. This also allows the schema to evolve by appending fields to records:
+/// [`decode`](SnapshotCodec::decode) should ignore any trailing bytes it does not understand,
+/// so that snapshots written by a newer version of the application can still be read by an older one.
+/// Snapshots written by an older version can be recognized by the schema version passed to `decode`.
+///
+/// Like [`OrdVecKey`], codecs are types without values that are only used as type parameters.
+pub trait SnapshotCodec<T> {
+    /// Version of the record layout written by [`encode`](SnapshotCodec::encode).
+    /// Should be increased whenever the layout changes.
+    const SCHEMA_VERSION: u32;
+
+    /// Appends the record of the item to `out`.
+    fn encode(item: &T, out: &mut Vec<u8>);
+
+    /// Decodes an item from a record written with the given schema version.
+    fn decode(
+        record: &[u8],
+        schema_version: u32,
+    ) -> Result<T, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+impl<T, K: OrdVecKey<T>> OrdVec<T, K> {
+    /// Writes a versioned binary snapshot of the [`OrdVec`], encoding each item with the codec `C`.
+    ///
+    /// The snapshot consists of a header with the format version, flags marking the items as sorted
+    /// and unique, the codec's [schema version](SnapshotCodec::SCHEMA_VERSION) and the number of items,
+    /// followed by a length-prefixed record for each item. All integers are little-endian.
+    /// Unlike the serde representation, the format is independent of the crate's internals
+    /// and stays readable by future versions of the crate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst, SnapshotCodec};
+    /// type DecodeResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
+    ///
+    /// fn decode_id_and_name(record: &[u8]) -> DecodeResult<(u32, String, &[u8])> {
+    ///     let id = u32::from_le_bytes(record.get(..4).ok_or("truncated")?.try_into()?);
+    ///     let len = u32::from_le_bytes(record.get(4..8).ok_or("truncated")?.try_into()?) as usize;
+    ///     let name = String::from_utf8(record.get(8..8 + len).ok_or("truncated")?.to_vec())?;
+    ///     Ok((id, name, &record[8 + len..]))
+    /// }
+    ///
+    /// // Version 1 of the application stores an id and a name
+    /// struct CodecV1;
+    /// impl SnapshotCodec<(u32, String)> for CodecV1 {
+    ///     const SCHEMA_VERSION: u32 = 1;
+    ///     fn encode(item: &(u32, String), out: &mut Vec<u8>) {
+    ///         out.extend_from_slice(&item.0.to_le_bytes());
+    ///         out.extend_from_slice(&(item.1.len() as u32).to_le_bytes());
+    ///         out.extend_from_slice(item.1.as_bytes());
+    ///     }
+    ///     fn decode(record: &[u8], _: u32) -> DecodeResult<(u32, String)> {
+    ///         // Trailing fields added by later versions are ignored
+    ///         let (id, name, _) = decode_id_and_name(record)?;
+    ///         Ok((id, name))
+    ///     }
+    /// }
+    ///
+    /// // Version 2 appends a score
+    /// struct CodecV2;
+    /// impl SnapshotCodec<(u32, (String, u8))> for CodecV2 {
+    ///     const SCHEMA_VERSION: u32 = 2;
+    ///     fn encode(item: &(u32, (String, u8)), out: &mut Vec<u8>) {
+    ///         CodecV1::encode(&(item.0, item.1 .0.clone()), out);
+    ///         out.push(item.1 .1);
+    ///     }
+    ///     fn decode(record: &[u8], schema_version: u32) -> DecodeResult<(u32, (String, u8))> {
+    ///         let (id, name, rest) = decode_id_and_name(record)?;
+    ///         let score = if schema_version >= 2 { *rest.first().ok_or("truncated")? } else { 0 };
+    ///         Ok((id, (name, score)))
+    ///     }
+    /// }
+    ///
+    /// let v1: OrdVec<_, OrdVecKeyFst> = vec![(2, "Maya".to_string()), (1, "Ben".to_string())].into();
+    /// let mut snapshot = Vec::new();
+    /// v1.write_snapshot::<CodecV1>(&mut snapshot).unwrap();
+    ///
+    /// // Version 2 reads snapshots of version 1...
+    /// let mut v2 = OrdVec::<_, OrdVecKeyFst>::read_snapshot::<CodecV2>(&mut &snapshot[..]).unwrap();
+    /// assert_eq!(v2[0], (1, ("Ben".to_string(), 0)));
+    ///
+    /// // ...and version 1 reads snapshots of version 2, skipping the fields it does not know
+    /// v2.get_mut_by_key(&1).unwrap().1 .1 = 90;
+    /// let mut snapshot = Vec::new();
+    /// v2.write_snapshot::<CodecV2>(&mut snapshot).unwrap();
+    /// let v1_again = OrdVec::<_, OrdVecKeyFst>::read_snapshot::<CodecV1>(&mut &snapshot[..]).unwrap();
+    /// assert_eq!(v1_again, v1);
+    /// ```
+    pub fn write_snapshot<C: SnapshotCodec<T>>(&self, w: &mut impl Write) -> std::io::Result<()> {
+        w.write_all(&MAGIC)?;
+        w.write_all(&FORMAT_VERSION.to_le_bytes())?;
+        w.write_all(&HEADER_LEN.to_le_bytes())?;
+        w.write_all(&(FLAG_SORTED | FLAG_UNIQUE).to_le_bytes())?;
+        w.write_all(&C::SCHEMA_VERSION.to_le_bytes())?;
+        w.write_all(&(self.0.len() as u64).to_le_bytes())?;
+        let mut record = Vec::new();
+        for item in &self.0 {
+            record.clear();
+            C::encode(item, &mut record);
+            let len = u32::try_from(record.len()).map_err(|_| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "Snapshot record must not exceed u32::MAX bytes",
+                )
+            })?;
+            w.write_all(&len.to_le_bytes())?;
+            w.write_all(&record)?;
+        }
+        Ok(())
+    }
+
+    /// Reads a snapshot written by [`write_snapshot`](struct.OrdVec.html#method.write_snapshot),
+    /// decoding each item with the codec `C`.
+    ///
+    /// Header fields added by future versions of the format are skipped.
+    /// The order of the items is verified, so a snapshot that was written with a different key function
+    /// or whose key ordering has changed since is rejected rather than producing an invalid [`OrdVec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyIdentity, SnapshotCodec, SnapshotError};
+    /// struct U8Codec;
+    /// impl SnapshotCodec<u8> for U8Codec {
+    ///     const SCHEMA_VERSION: u32 = 1;
+    ///     fn encode(item: &u8, out: &mut Vec<u8>) { out.push(*item) }
+    ///     fn decode(record: &[u8], _: u32) -> Result<u8, Box<dyn std::error::Error + Send + Sync>> {
+    ///         Ok(*record.first().ok_or("empty record")?)
+    ///     }
+    /// }
+    ///
+    /// let ov: OrdVec<u8, OrdVecKeyIdentity> = vec![3, 1, 2].into();
+    /// let mut snapshot = Vec::new();
+    /// ov.write_snapshot::<U8Codec>(&mut snapshot).unwrap();
+    /// assert_eq!(OrdVec::read_snapshot::<U8Codec>(&mut &snapshot[..]).unwrap(), ov);
+    ///
+    /// let truncated = OrdVec::<u8, OrdVecKeyIdentity>::read_snapshot::<U8Codec>(&mut &snapshot[..30]);
+    /// assert!(matches!(truncated, Err(SnapshotError::Io(_))));
+    /// let garbage = OrdVec::<u8, OrdVecKeyIdentity>::read_snapshot::<U8Codec>(&mut &b"not a snapshot"[..]);
+    /// assert!(matches!(garbage, Err(SnapshotError::InvalidMagic)));
+    /// ```
+    pub fn read_snapshot<C: SnapshotCodec<T>>(r: &mut impl Read) -> Result<Self, SnapshotError> {
+        let mut magic = [0; 4];
+        r.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(SnapshotError::InvalidMagic);
+        }
+        let version = u16::from_le_bytes(read_array(r)?);
+        if version != FORMAT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion { version });
+        }
+        let header_len = u16::from_le_bytes(read_array(r)?);
+        if header_len < HEADER_LEN {
+            return Err(SnapshotError::InvalidHeader);
+        }
+        let flags = u32::from_le_bytes(read_array(r)?);
+        let schema_version = u32::from_le_bytes(read_array(r)?);
+        let count = u64::from_le_bytes(read_array(r)?);
+        std::io::copy(
+            &mut r.take(u64::from(header_len - HEADER_LEN)),
+            &mut std::io::sink(),
+        )?;
+        if flags & FLAG_UNIQUE == 0 || flags & !(FLAG_SORTED | FLAG_UNIQUE) != 0 {
+            return Err(SnapshotError::UnsupportedFlags { flags });
+        }
+
+        let mut items = Vec::with_capacity(count.min(MAX_PREALLOCATED_ITEMS) as usize);
+        let mut record = Vec::new();
+        for index in 0..count {
+            let len = u32::from_le_bytes(read_array(r)?);
+            record.clear();
+            r.take(u64::from(len)).read_to_end(&mut record)?;
+            if record.len() != len as usize {
+                return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+            }
+            let item =
+                C::decode(&record, schema_version).map_err(|source| SnapshotError::Decode {
+                    index: index as usize,
+                    source,
+                })?;
+            items.push(item);
+        }
+        if flags & FLAG_SORTED == 0 {
+            items.sort_unstable_by(|a, b| K::cmp_keys(K::get_key(a), K::get_key(b)));
+        }
+        match find_unsorted::<T, K>(&items) {
+            Some(index) => Err(SnapshotError::NotSorted(NotSortedError { index })),
+            None => Ok(OrdVec::from_sorted_vec_unchecked(items)),
+        }
+    }
+}
+
+fn read_array<const N: usize>(r: &mut impl Read) -> std::io::Result<[u8; N]> {
+    let mut bytes = [0; N];
+    r.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Error returned by [`OrdVec::read_snapshot`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SnapshotError {
+    /// Reading from the underlying reader failed, including when the snapshot is truncated.
+    Io(std::io::Error),
+    /// The input does not start with the snapshot signature.
+    InvalidMagic,
+    /// The snapshot was written in a format version that is not supported by this version of the crate.
+    UnsupportedVersion {
+        /// Format version of the snapshot.
+        version: u16,
+    },
+    /// The snapshot header is shorter than required.
+    InvalidHeader,
+    /// The snapshot has flags that are not supported by this version of the crate,
+    /// or is not marked as having unique keys.
+    UnsupportedFlags {
+        /// Flags of the snapshot.
+        flags: u32,
+    },
+    /// The codec failed to decode the item at `index`.
+    Decode {
+        /// Index of the item in the snapshot.
+        index: usize,
+        /// Error returned by [`SnapshotCodec::decode`].
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    /// The decoded items are not in strictly ascending order of keys,
+    /// which means that they have duplicate keys or were written with a different key function.
+    NotSorted(NotSortedError),
+}
+
+impl From<std::io::Error> for SnapshotError {
+    fn from(e: std::io::Error) -> Self {
+        SnapshotError::Io(e)
+    }
+}
+
+impl std::fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SnapshotError::Io(e) => write!(f, "Failed to read snapshot: {}", e),
+            SnapshotError::InvalidMagic => write!(f, "Input is not an OrdVec snapshot"),
+            SnapshotError::UnsupportedVersion { version } => {
+                write!(f, "Snapshot format version {} is not supported", version)
+            }
+            SnapshotError::InvalidHeader => write!(f, "Snapshot header is too short"),
+            SnapshotError::UnsupportedFlags { flags } => {
+                write!(f, "Snapshot flags {:#x} are not supported", flags)
+            }
+            SnapshotError::Decode { index, source } => {
+                write!(f, "Failed to decode item {}: {}", index, source)
+            }
+            SnapshotError::NotSorted(e) => write!(f, "Invalid snapshot: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SnapshotError::Io(e) => Some(e),
+            SnapshotError::Decode { source, .. } => Some(source.as_ref()),
+            SnapshotError::NotSorted(e) => Some(e),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use std::{
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{Array2, Array2View, NotSortedError, OrdVecKey};
+
+/// Borrowed [`Array2`] whose rows are sorted in strictly ascending order of keys extracted
+/// by the key function `K`, with [`OrdVec`](crate::OrdVec)-like lookups of rows by key.
+///
+/// Lookups use binary search directly over the rows of the array, without copying the keys
+/// into a separate collection. Key functions such as [`OrdVecKeyCol`](crate::OrdVecKeyCol)
+/// take the key from a column of each row.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{Array2, OrdVecKeyCol, SortedRows};
+/// let mut table = Array2::new_from_rows([[3, 30, 300], [1, 10, 100], [2, 20, 200]]);
+/// let rows = SortedRows::<_, OrdVecKeyCol<0>>::sort(&mut table);
+/// assert_eq!(rows.get_row_by_key(&2), Some(&[2, 20, 200][..]));
+/// assert_eq!(rows.get_row_by_key(&4), None);
+/// assert_eq!(rows.range_by_key(2..).num_rows(), 2);
+///
+/// let by_second = SortedRows::<_, OrdVecKeyCol<1>>::new(&table).unwrap();
+/// assert_eq!(by_second.get_index_by_key(&30), Some(2));
+/// ```
+pub struct SortedRows<'a, T, K: OrdVecKey<[T]>> {
+    array: &'a Array2<T>,
+    key: PhantomData<K>,
+}
+
+impl<'a, T, K: OrdVecKey<[T]>> SortedRows<'a, T, K> {
+    /// Wraps the given array, whose rows must be sorted in strictly ascending order of keys.
+    ///
+    /// The rows are checked in a single linear pass. If the check fails,
+    /// the error contains the index of the first row whose key is not smaller than the key of the next row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, OrdVecKeyCol, SortedRows};
+    /// let table = Array2::new_from_rows([[1, 20], [2, 10], [2, 30]]);
+    /// assert!(SortedRows::<_, OrdVecKeyCol<1>>::new(&table).is_err());
+    /// assert_eq!(SortedRows::<_, OrdVecKeyCol<0>>::new(&table).unwrap_err().index, 1);
+    /// ```
+    pub fn new(array: &'a Array2<T>) -> Result<Self, NotSortedError> {
+        match find_unsorted_row::<T, K>(array) {
+            Some(index) => Err(NotSortedError { index }),
+            None => Ok(SortedRows {
+                array,
+                key: PhantomData,
+            }),
+        }
+    }
+
+    /// Wraps the given array without checking that its rows are sorted
+    /// in strictly ascending order of keys.
+    ///
+    /// The order is verified in debug builds only. If the rows are not sorted,
+    /// lookups may fail to find existing rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, OrdVecKeyCol, SortedRows};
+    /// let table = Array2::new_from_rows([[1, 100], [4, 400], [9, 900]]);
+    /// let rows = SortedRows::<_, OrdVecKeyCol<0>>::new_unchecked(&table);
+    /// assert_eq!(rows.get_row_by_key(&4), Some(&[4, 400][..]));
+    /// ```
+    pub fn new_unchecked(array: &'a Array2<T>) -> Self {
+        debug_assert!(
+            find_unsorted_row::<T, K>(array).is_none(),
+            "Rows must be sorted in strictly ascending order of keys"
+        );
+        SortedRows {
+            array,
+            key: PhantomData,
+        }
+    }
+
+    /// Sorts the rows of the given array by key and wraps it.
+    /// See [`Array2::sort_rows_by_key`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, OrdVecKeyCol, SortedRows};
+    /// let mut table = Array2::new_from_rows([["b", "2"], ["a", "1"]]);
+    /// let rows = SortedRows::<_, OrdVecKeyCol<0>>::sort(&mut table);
+    /// assert_eq!(rows.keys().collect::<Vec<_>>(), vec![&"a", &"b"]);
+    /// assert_eq!(table, Array2::new_from_rows([["a", "1"], ["b", "2"]]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if several rows have the same key.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::{Array2, OrdVecKeyCol, SortedRows};
+    /// let mut table = Array2::new_from_rows([[1, 10], [1, 20]]);
+    /// SortedRows::<_, OrdVecKeyCol<0>>::sort(&mut table);
+    /// ```
+    pub fn sort(array: &'a mut Array2<T>) -> Self {
+        array.sort_rows_by_key::<K>();
+        let array = &*array;
+        if let Some(index) = find_unsorted_row::<T, K>(array) {
+            panic!("Rows {} and {} have the same key", index, index + 1);
+        }
+        SortedRows {
+            array,
+            key: PhantomData,
+        }
+    }
+
+    /// Returns the underlying array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, OrdVecKeyCol, SortedRows};
+    /// let table = Array2::new_from_rows([[1, 10], [2, 20]]);
+    /// let rows = SortedRows::<_, OrdVecKeyCol<0>>::new(&table).unwrap();
+    /// assert_eq!(rows.as_array2(), &table);
+    /// ```
+    pub fn as_array2(&self) -> &'a Array2<T> {
+        self.array
+    }
+
+    /// Returns the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, OrdVecKeyCol, SortedRows};
+    /// let table = Array2::new_from_rows([[1, 10], [2, 20], [3, 30]]);
+    /// let rows = SortedRows::<_, OrdVecKeyCol<1>>::new(&table).unwrap();
+    /// assert_eq!(rows.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.array.num_rows()
+    }
+
+    /// Returns true if there are no rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, OrdVecKeyCol, SortedRows};
+    /// let empty: Array2<u32> = Array2::new(2, 0, 0);
+    /// assert!(SortedRows::<_, OrdVecKeyCol<0>>::new(&empty).unwrap().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.array.num_rows() == 0
+    }
+
+    /// Returns an iterator over the keys of the rows in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, OrdVecKeyCol, SortedRows};
+    /// let table = Array2::new_from_rows([[1, 30], [2, 20], [3, 10]]);
+    /// let rows = SortedRows::<_, OrdVecKeyCol<0>>::new(&table).unwrap();
+    /// assert_eq!(rows.keys().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// assert_eq!(rows.keys().next_back(), Some(&3));
+    /// ```
+    pub fn keys(&self) -> impl ExactSizeIterator<Item = &'a K::Key> + DoubleEndedIterator
+    where
+        K::Key: 'a,
+    {
+        let array = self.array;
+        (0..array.num_rows()).map(move |i| K::get_key(&array[i]))
+    }
+
+    /// Returns the index of the row with the given key, or None if such a row is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, OrdVecKeyCol, SortedRows};
+    /// let table = Array2::new_from_rows([[5, 0], [7, 0], [9, 0]]);
+    /// let rows = SortedRows::<_, OrdVecKeyCol<0>>::new(&table).unwrap();
+    /// assert_eq!(rows.get_index_by_key(&7), Some(1));
+    /// assert_eq!(rows.get_index_by_key(&8), None);
+    /// ```
+    pub fn get_index_by_key(&self, k: &K::Key) -> Option<usize> {
+        let index = self.partition_point(|key| K::cmp_keys(key, k).is_lt());
+        (index < self.len() && K::cmp_keys(K::get_key(&self.array[index]), k).is_eq())
+            .then_some(index)
+    }
+
+    /// Looks up a row by key using binary search.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, OrdVecKeyCol, SortedRows};
+    /// let table = Array2::new_from_rows([["apple", "red"], ["lime", "green"]]);
+    /// let rows = SortedRows::<_, OrdVecKeyCol<0>>::new(&table).unwrap();
+    /// assert_eq!(rows.get_row_by_key(&"lime"), Some(&["lime", "green"][..]));
+    /// assert_eq!(rows.get_row_by_key(&"plum"), None);
+    /// ```
+    pub fn get_row_by_key(&self, k: &K::Key) -> Option<&'a [T]> {
+        let array = self.array;
+        self.get_index_by_key(k).map(|i| &array[i])
+    }
+
+    /// Returns true if a row with the given key is present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, OrdVecKeyCol, SortedRows};
+    /// let table = Array2::new_from_rows([[1, 10], [3, 30]]);
+    /// let rows = SortedRows::<_, OrdVecKeyCol<0>>::new(&table).unwrap();
+    /// assert!(rows.contains_key(&3));
+    /// assert!(!rows.contains_key(&2));
+    /// ```
+    pub fn contains_key(&self, k: &K::Key) -> bool {
+        self.get_index_by_key(k).is_some()
+    }
+
+    /// Returns a view of the rows with keys in the given range.
+    /// See [`OrdVec::range_by_key`](crate::OrdVec::range_by_key).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, OrdVecKeyCol, SortedRows};
+    /// let table = Array2::new_from_rows([[1, 10], [3, 30], [5, 50], [7, 70]]);
+    /// let rows = SortedRows::<_, OrdVecKeyCol<0>>::new(&table).unwrap();
+    /// let view = rows.range_by_key(2..6);
+    /// assert_eq!(view.rows().collect::<Vec<_>>(), vec![&[3, 30], &[5, 50]]);
+    /// assert_eq!(rows.range_by_key(8..).num_rows(), 0);
+    /// ```
+    pub fn range_by_key(&self, range: impl RangeBounds<K::Key>) -> Array2View<'a, T> {
+        let start = match range.start_bound() {
+            Bound::Included(k) => self.partition_point(|key| K::cmp_keys(key, k).is_lt()),
+            Bound::Excluded(k) => self.partition_point(|key| K::cmp_keys(key, k).is_le()),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(k) => self.partition_point(|key| K::cmp_keys(key, k).is_le()),
+            Bound::Excluded(k) => self.partition_point(|key| K::cmp_keys(key, k).is_lt()),
+            Bound::Unbounded => self.len(),
+        };
+        self.array
+            .row_range(start..end.max(start))
+            .expect("range_by_key() must not use out of bounds row indexes")
+    }
+
+    /// Returns the index of the first row for which `pred` returns false, assuming that
+    /// it returns true for all keys before that row and false for all keys after it.
+    fn partition_point(&self, mut pred: impl FnMut(&K::Key) -> bool) -> usize {
+        let (mut lo, mut hi) = (0, self.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if pred(K::get_key(&self.array[mid])) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+}
+
+impl<T, K: OrdVecKey<[T]>> Clone for SortedRows<'_, T, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, K: OrdVecKey<[T]>> Copy for SortedRows<'_, T, K> {}
+
+impl<T: std::fmt::Debug, K: OrdVecKey<[T]>> std::fmt::Debug for SortedRows<'_, T, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_tuple("SortedRows").field(self.array).finish()
+    }
+}
+
+/// Returns the index of the first row whose key is not smaller than the key of the next row.
+fn find_unsorted_row<T, K: OrdVecKey<[T]>>(array: &Array2<T>) -> Option<usize> {
+    (1..array.num_rows())
+        .find(|&i| K::cmp_keys(K::get_key(&array[i - 1]), K::get_key(&array[i])).is_ge())
+        .map(|i| i - 1)
+}
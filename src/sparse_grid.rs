@@ -0,0 +1,393 @@
+use std::{
+    iter::FusedIterator,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{Array2, Array2Error, Coord, OrdVec, OrdVecKeyFst};
+
+/// Sparse two-dimensional map that stores only the occupied cells, keyed by [`Coord`],
+/// in an [`OrdVec`] sorted in row-major order.
+///
+/// Cells are kept in a single contiguous buffer, so iterating over them or over the cells of a band
+/// of rows touches consecutive memory, while lookups use binary search. Unlike an [`Array2`],
+/// memory use depends on the number of occupied cells rather than on the dimensions.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{Coord, SparseGrid};
+/// let mut map = SparseGrid::new();
+/// map.set(Coord::new(1_000, 20), 'x');
+/// map.set(Coord::new(3, 7_000), 'y');
+/// assert_eq!(map.get(Coord::new(1_000, 20)), Some(&'x'));
+/// assert_eq!(map.get(Coord::new(0, 0)), None);
+/// assert_eq!(map.set(Coord::new(3, 7_000), 'z'), Some('y'));
+///
+/// let cells: Vec<_> = map.iter().collect();
+/// assert_eq!(cells, vec![(Coord::new(3, 7_000), &'z'), (Coord::new(1_000, 20), &'x')]);
+/// assert_eq!(map.bounding_box(), Some((Coord::new(3, 20), Coord::new(1_000, 7_000))));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SparseGrid<T> {
+    cells: OrdVec<(Coord, T), OrdVecKeyFst>,
+}
+
+impl<T> SparseGrid<T> {
+    /// Creates an empty [`SparseGrid`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::SparseGrid;
+    /// let map: SparseGrid<char> = SparseGrid::new();
+    /// assert!(map.is_empty());
+    /// ```
+    pub const fn new() -> Self {
+        SparseGrid {
+            cells: OrdVec::new(),
+        }
+    }
+
+    /// Creates an empty [`SparseGrid`] with space for at least `capacity` cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Coord, SparseGrid};
+    /// let mut map = SparseGrid::with_capacity(2);
+    /// map.set(Coord::new(0, 0), 1);
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        SparseGrid {
+            cells: OrdVec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of occupied cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Coord, SparseGrid};
+    /// let mut map = SparseGrid::new();
+    /// map.set(Coord::new(4, 1), 'a');
+    /// map.set(Coord::new(4, 1), 'b');
+    /// map.set(Coord::new(0, 9), 'c');
+    /// assert_eq!(map.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns true if there are no occupied cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Coord, SparseGrid};
+    /// let mut map = SparseGrid::new();
+    /// assert!(map.is_empty());
+    /// map.set(Coord::new(1, 1), ());
+    /// assert!(!map.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns a reference to the value at the given position, or None if the cell is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Coord, SparseGrid};
+    /// let mut map = SparseGrid::new();
+    /// map.set(Coord::new(2, 3), 'x');
+    /// assert_eq!(map.get(Coord::new(2, 3)), Some(&'x'));
+    /// assert_eq!(map.get(Coord::new(3, 2)), None);
+    /// ```
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.cells.get_by_key(&coord).map(|(_, v)| v)
+    }
+
+    /// Returns a mutable reference to the value at the given position, or None if the cell is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Coord, SparseGrid};
+    /// let mut map = SparseGrid::new();
+    /// map.set(Coord::new(0, 1), 10);
+    /// *map.get_mut(Coord::new(0, 1)).unwrap() += 5;
+    /// assert_eq!(map.get(Coord::new(0, 1)), Some(&15));
+    /// assert_eq!(map.get_mut(Coord::new(1, 0)), None);
+    /// ```
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.cells.get_mut_by_key(&coord).map(|(_, v)| v)
+    }
+
+    /// Returns true if the cell at the given position is occupied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Coord, SparseGrid};
+    /// let mut map = SparseGrid::new();
+    /// map.set(Coord::new(5, 5), 'o');
+    /// assert!(map.contains(Coord::new(5, 5)));
+    /// assert!(!map.contains(Coord::new(5, 6)));
+    /// ```
+    pub fn contains(&self, coord: Coord) -> bool {
+        self.cells.contains_key(&coord)
+    }
+
+    /// Sets the value at the given position, returning the previous value if the cell was occupied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Coord, SparseGrid};
+    /// let mut map = SparseGrid::new();
+    /// assert_eq!(map.set(Coord::new(1, 2), 'a'), None);
+    /// assert_eq!(map.set(Coord::new(1, 2), 'b'), Some('a'));
+    /// assert_eq!(map.get(Coord::new(1, 2)), Some(&'b'));
+    /// ```
+    pub fn set(&mut self, coord: Coord, value: T) -> Option<T> {
+        match self.get_mut(coord) {
+            Some(v) => Some(std::mem::replace(v, value)),
+            None => {
+                self.cells.insert_or_panic((coord, value));
+                None
+            }
+        }
+    }
+
+    /// Removes the value at the given position and returns it, or None if the cell is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Coord, SparseGrid};
+    /// let mut map = SparseGrid::new();
+    /// map.set(Coord::new(7, 0), 'a');
+    /// assert_eq!(map.remove(Coord::new(7, 0)), Some('a'));
+    /// assert_eq!(map.remove(Coord::new(7, 0)), None);
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn remove(&mut self, coord: Coord) -> Option<T> {
+        self.cells.remove_by_key(&coord).map(|(_, v)| v)
+    }
+
+    /// Returns an iterator over the positions and values of the occupied cells in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Coord, SparseGrid};
+    /// let mut map = SparseGrid::new();
+    /// map.set(Coord::new(1, 0), 'b');
+    /// map.set(Coord::new(0, 8), 'a');
+    /// let cells: Vec<_> = map.iter().collect();
+    /// assert_eq!(cells, vec![(Coord::new(0, 8), &'a'), (Coord::new(1, 0), &'b')]);
+    /// ```
+    pub fn iter(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (Coord, &T)> + DoubleEndedIterator + FusedIterator {
+        self.cells.iter().map(|(pos, v)| (*pos, v))
+    }
+
+    /// Returns an iterator over the column indexes and values of the occupied cells in the given row,
+    /// in ascending order of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Coord, SparseGrid};
+    /// let map: SparseGrid<_> = [((2, 9), 'b'), ((2, 4), 'a'), ((3, 0), 'c')]
+    ///     .map(|(pos, v)| (Coord::from(pos), v))
+    ///     .into_iter()
+    ///     .collect();
+    /// assert_eq!(map.row(2).collect::<Vec<_>>(), vec![(4, &'a'), (9, &'b')]);
+    /// assert_eq!(map.row(5).count(), 0);
+    /// ```
+    pub fn row(
+        &self,
+        row: usize,
+    ) -> impl ExactSizeIterator<Item = (usize, &T)> + DoubleEndedIterator + FusedIterator {
+        self.cells
+            .range_by_key(Coord::new(row, 0)..=Coord::new(row, usize::MAX))
+            .iter()
+            .map(|(coord, v)| (coord.col, v))
+    }
+
+    /// Returns an iterator over the positions and values of the occupied cells within
+    /// the given rows and columns, in row-major order.
+    ///
+    /// The band of rows is found with binary search, and the cells in it are filtered by column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Coord, SparseGrid};
+    /// let map: SparseGrid<_> = [((0, 5), 1), ((2, 1), 2), ((2, 6), 3), ((3, 5), 4), ((9, 5), 5)]
+    ///     .map(|(pos, v)| (Coord::from(pos), v))
+    ///     .into_iter()
+    ///     .collect();
+    /// let cells: Vec<_> = map.region(1..=3, 4..).collect();
+    /// assert_eq!(cells, vec![(Coord::new(2, 6), &3), (Coord::new(3, 5), &4)]);
+    /// ```
+    pub fn region(
+        &self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> impl DoubleEndedIterator<Item = (Coord, &T)> + FusedIterator {
+        let start = match rows.start_bound() {
+            Bound::Included(&row) => Bound::Included(Coord::new(row, 0)),
+            Bound::Excluded(&row) => Bound::Excluded(Coord::new(row, usize::MAX)),
+            Bound::Unbounded => Bound::Unbounded,
+        };
+        let end = match rows.end_bound() {
+            Bound::Included(&row) => Bound::Included(Coord::new(row, usize::MAX)),
+            Bound::Excluded(&row) => Bound::Excluded(Coord::new(row, 0)),
+            Bound::Unbounded => Bound::Unbounded,
+        };
+        self.cells
+            .range_by_key((start, end))
+            .iter()
+            .filter(move |(coord, _)| cols.contains(&coord.col))
+            .map(|(pos, v)| (*pos, v))
+    }
+
+    /// Returns the top-left and the bottom-right corners of the smallest rectangle that contains
+    /// all occupied cells, or None if there are none. Both corners are inclusive,
+    /// so cells at `usize::MAX` can be described.
+    ///
+    /// The rows are found from the first and the last cell,
+    /// while the columns require a pass over all cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Coord, SparseGrid};
+    /// let mut map = SparseGrid::new();
+    /// assert_eq!(map.bounding_box(), None);
+    /// map.set(Coord::new(2, 9), 'a');
+    /// map.set(Coord::new(6, 4), 'b');
+    /// map.set(Coord::new(usize::MAX, usize::MAX), 'c');
+    /// assert_eq!(map.bounding_box(), Some((Coord::new(2, 4), Coord::new(usize::MAX, usize::MAX))));
+    /// ```
+    pub fn bounding_box(&self) -> Option<(Coord, Coord)> {
+        let (first, _) = self.cells.first()?;
+        let (last, _) = self.cells.last()?;
+        let (min_col, max_col) = self
+            .cells
+            .iter()
+            .fold((usize::MAX, 0), |(min, max), (coord, _)| {
+                (min.min(coord.col), max.max(coord.col))
+            });
+        Some((
+            Coord::new(first.row, min_col),
+            Coord::new(last.row, max_col),
+        ))
+    }
+
+    /// Removes all cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Coord, SparseGrid};
+    /// let mut map = SparseGrid::new();
+    /// map.set(Coord::new(3, 3), 1);
+    /// map.clear();
+    /// assert!(map.is_empty());
+    /// assert_eq!(map.get(Coord::new(3, 3)), None);
+    /// ```
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    /// Creates a [`SparseGrid`] from the elements of a dense array that are not equal to `default`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Coord, SparseGrid};
+    /// let dense = Array2::new_from_rows([[0, 0, 7], [0, 3, 0]]);
+    /// let map = SparseGrid::from_array2(&dense, &0);
+    /// assert_eq!(map.iter().collect::<Vec<_>>(), vec![(Coord::new(0, 2), &7), (Coord::new(1, 1), &3)]);
+    /// assert_eq!(map.to_array2(3, 2, 0), Ok(dense));
+    /// ```
+    pub fn from_array2(array: &Array2<T>, default: &T) -> Self
+    where
+        T: Clone + PartialEq,
+    {
+        let num_cols = array.num_cols();
+        let cells = array
+            .elements()
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| *v != default)
+            .map(|(i, v)| (Coord::new(i / num_cols, i % num_cols), v.clone()))
+            .collect();
+        SparseGrid {
+            cells: OrdVec::from_sorted_vec_unchecked(cells),
+        }
+    }
+
+    /// Creates a dense [`Array2`] of the given dimensions with the values of the occupied cells
+    /// and all other elements set to `default`, or returns an error if the dimensions are invalid
+    /// or an occupied cell is out of bounds. See [`Array2::try_from_sparse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{Array2, Array2Error, Coord, SparseGrid};
+    /// let map: SparseGrid<_> = [(Coord::new(1, 0), '#'), (Coord::new(0, 2), '@')].into_iter().collect();
+    /// assert_eq!(map.to_array2(3, 2, '.'), Ok(Array2::new_from_rows([['.', '.', '@'], ['#', '.', '.']])));
+    /// assert_eq!(map.to_array2(2, 2, '.'), Err(Array2Error::PositionOutOfBounds { row: 0, col: 2 }));
+    /// ```
+    pub fn to_array2(
+        &self,
+        num_cols: usize,
+        num_rows: usize,
+        default: T,
+    ) -> Result<Array2<T>, Array2Error>
+    where
+        T: Clone,
+    {
+        Array2::try_from_sparse(
+            num_cols,
+            num_rows,
+            default,
+            self.iter().map(|(coord, v)| (coord.into(), v.clone())),
+        )
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+/// Collects the cells into a [`SparseGrid`]. If a position is given more than once, the last value is kept.
+impl<T> FromIterator<(Coord, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord, T)>>(iter: I) -> Self {
+        let mut cells: Vec<_> = iter.into_iter().collect();
+        cells.reverse();
+        cells.sort_by_key(|(pos, _)| *pos);
+        cells.dedup_by_key(|(pos, _)| *pos);
+        SparseGrid {
+            cells: OrdVec::from_sorted_vec_unchecked(cells),
+        }
+    }
+}
+
+impl<T> IntoIterator for SparseGrid<T> {
+    type Item = (Coord, T);
+    type IntoIter = std::vec::IntoIter<(Coord, T)>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.into_iter()
+    }
+}
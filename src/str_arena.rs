@@ -0,0 +1,325 @@
+use std::{iter::FusedIterator, ops::Index};
+
+/// Handle of a string in a [`StrArena`], returned by [`StrArena::intern`].
+///
+/// Handles are assigned sequentially in the order the strings are first interned,
+/// and remain valid until the arena is cleared. Using a handle with a different arena
+/// is only detected if it is out of bounds there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StrId(u32);
+
+impl StrId {
+    /// Returns the position of the string in the order of interning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::StrArena;
+    /// let mut arena = StrArena::new();
+    /// let b = arena.intern("b");
+    /// let a = arena.intern("a");
+    /// assert_eq!((b.index(), a.index()), (0, 1));
+    /// ```
+    pub const fn index(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// String interner that stores all strings in one contiguous buffer,
+/// handing out a compact [`StrId`] for each distinct string.
+///
+/// Interning a string appends its bytes to a single [`String`] and records where it ends
+/// in a flat table of offsets, so no allocation is made per string. Handles of interned strings
+/// are also kept in a [`Vec`] sorted by string, which is used to find existing strings
+/// with binary search: interning a string that is already present returns its existing handle.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::StrArena;
+/// let mut idents = StrArena::new();
+/// let x = idents.intern("x");
+/// let len = idents.intern("len");
+/// assert_eq!(idents.intern("x"), x);
+/// assert_eq!(idents.resolve(len), "len");
+/// assert_eq!(idents.get_id("len"), Some(len));
+/// assert_eq!(idents.get_id("y"), None);
+///
+/// assert_eq!(idents.len(), 2);
+/// assert_eq!(idents.as_str(), "xlen");
+/// assert_eq!(idents.iter_sorted().map(|(_, s)| s).collect::<Vec<_>>(), vec!["len", "x"]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StrArena {
+    buffer: String,
+    ends: Vec<usize>,
+    sorted: Vec<StrId>,
+}
+
+impl StrArena {
+    /// Creates an empty [`StrArena`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::StrArena;
+    /// let arena = StrArena::new();
+    /// assert!(arena.is_empty());
+    /// assert_eq!(arena.as_str(), "");
+    /// ```
+    pub const fn new() -> Self {
+        StrArena {
+            buffer: String::new(),
+            ends: Vec::new(),
+            sorted: Vec::new(),
+        }
+    }
+
+    /// Creates an empty [`StrArena`] with space for at least `num_strings` strings
+    /// with `num_bytes` bytes in total.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::StrArena;
+    /// let mut arena = StrArena::with_capacity(2, 8);
+    /// arena.extend(["red", "green"]);
+    /// assert_eq!(arena.len(), 2);
+    /// ```
+    pub fn with_capacity(num_strings: usize, num_bytes: usize) -> Self {
+        StrArena {
+            buffer: String::with_capacity(num_bytes),
+            ends: Vec::with_capacity(num_strings),
+            sorted: Vec::with_capacity(num_strings),
+        }
+    }
+
+    /// Returns the number of distinct strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::StrArena;
+    /// let arena: StrArena = ["a", "b", "a"].into_iter().collect();
+    /// assert_eq!(arena.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns true if there are no strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::StrArena;
+    /// let mut arena = StrArena::new();
+    /// assert!(arena.is_empty());
+    /// arena.intern("");
+    /// assert!(!arena.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Returns the handle of the given string, adding the string to the arena if it is not present.
+    ///
+    /// Panics if the number of strings exceeds [`u32::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::StrArena;
+    /// let mut arena = StrArena::new();
+    /// let a = arena.intern("apple");
+    /// let b = arena.intern("banana");
+    /// // Equal strings are stored once and share a handle
+    /// assert_eq!(arena.intern("apple"), a);
+    /// assert_ne!(a, b);
+    /// assert_eq!(arena.as_str(), "applebanana");
+    /// ```
+    pub fn intern(&mut self, s: &str) -> StrId {
+        match self.search(s) {
+            Ok(pos) => self.sorted[pos],
+            Err(pos) => {
+                let id = StrId(
+                    u32::try_from(self.ends.len()).expect("Number of strings exceeds u32::MAX"),
+                );
+                self.buffer.push_str(s);
+                self.ends.push(self.buffer.len());
+                self.sorted.insert(pos, id);
+                id
+            }
+        }
+    }
+
+    /// Returns the handle of the given string, or None if it is not present, using binary search.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::StrArena;
+    /// let mut arena = StrArena::new();
+    /// let id = arena.intern("key");
+    /// assert_eq!(arena.get_id("key"), Some(id));
+    /// assert_eq!(arena.get_id("ke"), None);
+    /// assert_eq!(arena.get_id("keys"), None);
+    /// assert_eq!(arena.len(), 1);
+    /// ```
+    pub fn get_id(&self, s: &str) -> Option<StrId> {
+        self.search(s).ok().map(|pos| self.sorted[pos])
+    }
+
+    /// Returns the string with the given handle, or None if the handle belongs to a different arena
+    /// with more strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::StrArena;
+    /// let mut big = StrArena::new();
+    /// big.intern("a");
+    /// let b = big.intern("b");
+    /// assert_eq!(big.get(b), Some("b"));
+    /// let small: StrArena = ["a"].into_iter().collect();
+    /// assert_eq!(small.get(b), None);
+    /// ```
+    pub fn get(&self, id: StrId) -> Option<&str> {
+        let end = *self.ends.get(id.index())?;
+        let start = match id.index() {
+            0 => 0,
+            i => self.ends[i - 1],
+        };
+        Some(&self.buffer[start..end])
+    }
+
+    /// Returns the string with the given handle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::StrArena;
+    /// let mut arena = StrArena::new();
+    /// let id = arena.intern("hello");
+    /// assert_eq!(arena.resolve(id), "hello");
+    /// assert_eq!(&arena[id], "hello");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle belongs to a different arena with more strings.
+    /// See [`get`](struct.StrArena.html#method.get) for a non-panicking version.
+    ///
+    /// ```should_panic
+    /// # use contiguous_collections::StrArena;
+    /// let mut big = StrArena::new();
+    /// big.intern("a");
+    /// let b = big.intern("b");
+    /// let small: StrArena = ["a"].into_iter().collect();
+    /// small.resolve(b);
+    /// ```
+    pub fn resolve(&self, id: StrId) -> &str {
+        self.get(id)
+            .unwrap_or_else(|| panic!("String handle {:?} is out of bounds", id))
+    }
+
+    /// Returns all strings concatenated in the order they were interned,
+    /// which is the underlying buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::StrArena;
+    /// let arena: StrArena = ["ab", "c", "ab"].into_iter().collect();
+    /// assert_eq!(arena.as_str(), "abc");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Returns an iterator over the handles and strings in the order they were interned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::StrArena;
+    /// let arena: StrArena = ["b", "a", "c"].into_iter().collect();
+    /// let strings: Vec<&str> = arena.iter().map(|(_, s)| s).collect();
+    /// assert_eq!(strings, vec!["b", "a", "c"]);
+    /// ```
+    pub fn iter(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (StrId, &str)> + DoubleEndedIterator + FusedIterator {
+        (0..self.ends.len()).map(|i| {
+            let id = StrId(i as u32);
+            (id, self.resolve(id))
+        })
+    }
+
+    /// Returns an iterator over the handles and strings in ascending order of strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::StrArena;
+    /// let arena: StrArena = ["b", "a", "c"].into_iter().collect();
+    /// let sorted: Vec<(usize, &str)> = arena.iter_sorted().map(|(id, s)| (id.index(), s)).collect();
+    /// assert_eq!(sorted, vec![(1, "a"), (0, "b"), (2, "c")]);
+    /// ```
+    pub fn iter_sorted(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (StrId, &str)> + DoubleEndedIterator + FusedIterator {
+        self.sorted.iter().map(|&id| (id, self.resolve(id)))
+    }
+
+    /// Removes all strings, keeping the allocated memory.
+    /// Handles returned before clearing must not be used afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::StrArena;
+    /// let mut arena: StrArena = ["a", "b"].into_iter().collect();
+    /// arena.clear();
+    /// assert!(arena.is_empty());
+    /// assert_eq!(arena.get_id("a"), None);
+    /// assert_eq!(arena.intern("c").index(), 0);
+    /// ```
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+        self.ends.clear();
+        self.sorted.clear();
+    }
+
+    fn search(&self, s: &str) -> Result<usize, usize> {
+        self.sorted.binary_search_by(|&id| self.resolve(id).cmp(s))
+    }
+}
+
+impl Index<StrId> for StrArena {
+    type Output = str;
+
+    /// Returns the string with the given handle.
+    ///
+    /// Panics if the handle is out of bounds. See [`get`](struct.StrArena.html#method.get) for a non-panicking version.
+    fn index(&self, id: StrId) -> &Self::Output {
+        self.resolve(id)
+    }
+}
+
+/// Interns each string, skipping strings that are already present.
+impl<'a> Extend<&'a str> for StrArena {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            self.intern(s);
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a str> for StrArena {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut arena = StrArena::new();
+        arena.extend(iter);
+        arena
+    }
+}
@@ -0,0 +1,246 @@
+use crate::{OrdVec, OrdVecKey};
+
+/// Inserts and removals staged for a single [`OrdVec`], to be applied as part of an [`OrdVecTransaction`].
+///
+/// When applied, all removals are performed before any inserts,
+/// so an item can be replaced by removing its key and inserting a new item with the same key.
+/// Removing a key that is not present is not an error.
+pub struct OrdVecChanges<'a, T, K: OrdVecKey<T>> {
+    target: &'a mut OrdVec<T, K>,
+    removals: Vec<&'a K::Key>,
+    inserts: Vec<T>,
+}
+
+impl<'a, T, K: OrdVecKey<T>> OrdVecChanges<'a, T, K> {
+    /// Creates an empty set of changes for the given [`OrdVec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst, OrdVecChanges, OrdVecTransaction};
+    /// let mut ov: OrdVec<(u32, &str), OrdVecKeyFst> = OrdVec::new();
+    /// let mut tx = OrdVecTransaction::new();
+    /// tx.add(OrdVecChanges::new(&mut ov));
+    /// assert!(tx.commit().is_ok());
+    /// assert!(ov.is_empty());
+    /// ```
+    pub fn new(target: &'a mut OrdVec<T, K>) -> Self {
+        OrdVecChanges {
+            target,
+            removals: Vec::new(),
+            inserts: Vec::new(),
+        }
+    }
+
+    /// Stages an insert of the given item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst, OrdVecChanges, OrdVecTransaction};
+    /// let mut ov: OrdVec<(u32, &str), OrdVecKeyFst> = vec![(2, "B")].into();
+    /// let mut tx = OrdVecTransaction::new();
+    /// tx.add(OrdVecChanges::new(&mut ov).insert((3, "C")).insert((1, "A")));
+    /// assert!(tx.commit().is_ok());
+    /// assert_eq!(ov[..], [(1, "A"), (2, "B"), (3, "C")]);
+    /// ```
+    pub fn insert(mut self, item: T) -> Self {
+        self.inserts.push(item);
+        self
+    }
+
+    /// Stages a removal of the item with the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst, OrdVecChanges, OrdVecTransaction};
+    /// let mut ov: OrdVec<(u32, &str), OrdVecKeyFst> = vec![(1, "A"), (2, "B")].into();
+    /// let mut tx = OrdVecTransaction::new();
+    /// tx.add(OrdVecChanges::new(&mut ov).remove(&1).remove(&5));
+    /// assert!(tx.commit().is_ok());
+    /// assert_eq!(ov[..], [(2, "B")]);
+    /// ```
+    pub fn remove(mut self, key: &'a K::Key) -> Self {
+        self.removals.push(key);
+        self
+    }
+
+    /// Returns the index of the first staged insert that would result in a duplicate key.
+    fn find_duplicate_insert(&self) -> Option<usize> {
+        let mut removals = self.removals.clone();
+        removals.sort_unstable_by(|a, b| K::cmp_keys(a, b));
+        let existing_dup = self.inserts.iter().position(|item| {
+            let k = K::get_key(item);
+            self.target.get_index_by_key(k).is_some()
+                && removals.binary_search_by(|r| K::cmp_keys(r, k)).is_err()
+        });
+
+        let mut insert_order: Vec<usize> = (0..self.inserts.len()).collect();
+        insert_order.sort_by(|&a, &b| {
+            K::cmp_keys(K::get_key(&self.inserts[a]), K::get_key(&self.inserts[b]))
+        });
+        let staged_dup = insert_order
+            .windows(2)
+            .filter(|pair| {
+                K::cmp_keys(
+                    K::get_key(&self.inserts[pair[0]]),
+                    K::get_key(&self.inserts[pair[1]]),
+                )
+                .is_eq()
+            })
+            .map(|pair| pair[0].max(pair[1]))
+            .min();
+
+        existing_dup.into_iter().chain(staged_dup).min()
+    }
+}
+
+trait StagedChanges {
+    fn find_duplicate_insert(&self) -> Option<usize>;
+    fn apply(self: Box<Self>);
+}
+
+impl<T, K: OrdVecKey<T>> StagedChanges for OrdVecChanges<'_, T, K> {
+    fn find_duplicate_insert(&self) -> Option<usize> {
+        OrdVecChanges::find_duplicate_insert(self)
+    }
+
+    fn apply(self: Box<Self>) {
+        let OrdVecChanges {
+            target,
+            removals,
+            inserts,
+        } = *self;
+        for k in removals {
+            target.remove_by_key(k);
+        }
+        for item in inserts {
+            target.insert_or_panic(item);
+        }
+    }
+}
+
+/// Applies [`OrdVecChanges`] to several [`OrdVec`]s at once, e.g. to keep a primary collection
+/// and its secondary indexes consistent.
+///
+/// All changes are validated before any of them are applied: if any staged insert
+/// would result in a duplicate key, no collection is modified.
+///
+/// # Examples
+///
+/// ```
+/// # use contiguous_collections::{OrdVec, OrdVecKey, OrdVecKeyFst, OrdVecChanges, OrdVecTransaction};
+/// let mut by_id: OrdVec<(u32, &str), OrdVecKeyFst> = vec![(1, "Maya"), (2, "Ben")].into();
+/// let mut by_name: OrdVec<(&str, u32), OrdVecKeyFst> = vec![("Maya", 1), ("Ben", 2)].into();
+///
+/// // Rename user 2
+/// let mut tx = OrdVecTransaction::new();
+/// tx.add(OrdVecChanges::new(&mut by_id).remove(&2).insert((2, "Ariel")));
+/// tx.add(OrdVecChanges::new(&mut by_name).remove(&"Ben").insert(("Ariel", 2)));
+/// assert!(tx.commit().is_ok());
+/// assert_eq!(by_id[..], [(1, "Maya"), (2, "Ariel")]);
+/// assert_eq!(by_name[..], [("Ariel", 2), ("Maya", 1)]);
+///
+/// // Adding user 3 with a taken name fails without modifying by_id
+/// let mut tx = OrdVecTransaction::new();
+/// tx.add(OrdVecChanges::new(&mut by_id).insert((3, "Maya")));
+/// tx.add(OrdVecChanges::new(&mut by_name).insert(("Maya", 3)));
+/// let err = tx.commit().unwrap_err();
+/// assert_eq!((err.changes_index, err.insert_index), (1, 0));
+/// assert_eq!(by_id[..], [(1, "Maya"), (2, "Ariel")]);
+/// ```
+#[derive(Default)]
+pub struct OrdVecTransaction<'a> {
+    changes: Vec<Box<dyn StagedChanges + 'a>>,
+}
+
+impl<'a> OrdVecTransaction<'a> {
+    /// Creates an empty transaction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::OrdVecTransaction;
+    /// let tx = OrdVecTransaction::new();
+    /// assert!(tx.commit().is_ok());
+    /// ```
+    pub fn new() -> Self {
+        OrdVecTransaction {
+            changes: Vec::new(),
+        }
+    }
+
+    /// Adds changes for an [`OrdVec`] to the transaction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst, OrdVecKeyIdentity, OrdVecChanges, OrdVecTransaction};
+    /// let mut ids: OrdVec<u32, OrdVecKeyIdentity> = vec![1].into();
+    /// let mut names: OrdVec<(&str, u32), OrdVecKeyFst> = vec![("A", 1)].into();
+    /// let mut tx = OrdVecTransaction::new();
+    /// tx.add(OrdVecChanges::new(&mut ids).insert(2));
+    /// tx.add(OrdVecChanges::new(&mut names).insert(("B", 2)));
+    /// assert!(tx.commit().is_ok());
+    /// assert_eq!((ids.len(), names.len()), (2, 2));
+    /// ```
+    pub fn add<T: 'a, K: OrdVecKey<T> + 'a>(&mut self, changes: OrdVecChanges<'a, T, K>) {
+        self.changes.push(Box::new(changes));
+    }
+
+    /// Validates all staged changes and applies them if none of the inserts would result in a duplicate key.
+    /// Otherwise, returns an error pointing to the first offending insert and leaves all collections unmodified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use contiguous_collections::{OrdVec, OrdVecKeyFst, OrdVecChanges, OrdVecTransaction, TransactionError};
+    /// let mut ov: OrdVec<(u32, &str), OrdVecKeyFst> = vec![(1, "A")].into();
+    /// let mut tx = OrdVecTransaction::new();
+    /// tx.add(OrdVecChanges::new(&mut ov).insert((2, "B")).insert((2, "C")));
+    /// assert_eq!(tx.commit(), Err(TransactionError { changes_index: 0, insert_index: 1 }));
+    /// assert_eq!(ov[..], [(1, "A")]);
+    ///
+    /// // Removing the existing key first makes room for the insert
+    /// let mut tx = OrdVecTransaction::new();
+    /// tx.add(OrdVecChanges::new(&mut ov).insert((1, "Z")).remove(&1));
+    /// assert_eq!(tx.commit(), Ok(()));
+    /// assert_eq!(ov[..], [(1, "Z")]);
+    /// ```
+    pub fn commit(self) -> Result<(), TransactionError> {
+        for (changes_index, changes) in self.changes.iter().enumerate() {
+            if let Some(insert_index) = changes.find_duplicate_insert() {
+                return Err(TransactionError {
+                    changes_index,
+                    insert_index,
+                });
+            }
+        }
+        for changes in self.changes {
+            changes.apply();
+        }
+        Ok(())
+    }
+}
+
+/// Error returned by [`OrdVecTransaction::commit`] when a staged insert would result in a duplicate key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionError {
+    /// Index of the offending [`OrdVecChanges`] in the order they were added to the transaction.
+    pub changes_index: usize,
+    /// Index of the offending insert in the order it was staged.
+    pub insert_index: usize,
+}
+
+impl std::fmt::Display for TransactionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Insert {} of change set {} has a duplicate key",
+            self.insert_index, self.changes_index
+        )
+    }
+}
+
+impl std::error::Error for TransactionError {}